@@ -1,17 +1,26 @@
+use std::collections::{BTreeSet, HashSet};
 use std::path::PathBuf;
 use eframe::egui::{self, Color32, FontFamily, FontId, Margin, Rounding, Stroke, TextureHandle, Vec2};
-use chrono::Local;
+use chrono::{Local, NaiveDate};
 
-use crate::models::{AppConfig, Budget, Expense, ExpensePreset, ProfileData, ProfileMeta, SharedData, Template};
+use uuid::Uuid;
+
+use crate::models::{
+    apply_rules, category, normalize_payee, payee_totals, tag_totals, AppConfig, Budget, CategoryColor, CategoryLimit, Expense, ExpensePreset, ProfileBundle, ProfileData, Payee, ProfileMeta, ProfileSummary, Rule, Scope, SharedData,
+    ShortcutAction, SuggestionIndex, Template, period_key, DEBT_CATEGORY, DEFAULT_CATEGORY_COLOR, SAVINGS_CATEGORY,
+};
 use crate::storage::{
-    delete_profile_file, duplicate_profile, load_config, load_profile, load_shared_data,
-    migrate_legacy_budget, save_config, save_profile, save_shared_data,
+    default_bundle_path, delete_profile_file, duplicate_profile, export_profile_bundle, get_attachment_path, import_attachment, load_config, load_profile, load_profile_bundle, load_shared_data,
+    migrate_legacy_budget, remove_orphaned_attachments, save_config, save_profile, save_shared_data, try_load_profile,
 };
 use crate::ui::{
-    render_balance_bar, render_dashboard, render_expenses, render_expenses_header, Calculator,
-    CategoryAction, CategoryManager, ExpenseForm, HistoryAction, IncomeForm, PresetAction,
-    PresetPanel, ProfileAction, ProfileManager, ProfileSelector, ProfileSelectorAction,
-    TemplateAction, TemplateManager,
+    AccountAction, AccountsPanel, ArchivedView, BundleAction, BundleDialog,
+    is_binding_pressed, render_balance_bar, render_dashboard, render_expenses,
+    render_expenses_header, Calculator, CalculatorAction, CalendarAction, CalendarView, CategoryAction, CategoryManager, CommandPalette,
+    DebtAction, DebtsPanel, EnvelopeAction, EnvelopePanel, ExpenseForm, GoalAction, GoalsPanel, HistoryAction, HouseholdView, IncomeForm, KeymapAction, MoveExpensesAction, MoveExpensesDialog, PaletteCommand, PaletteEntry,
+    PayeeAction, PayeesPanel, PresetAction, PresetPanel, ProfileAction, ProfileCompare, ProfileManager, ProfileSelector,
+    ProfileSelectorAction, RuleAction, RulesPanel, ShortcutEditor, TagReport, TemplateAction, TemplateManager,
+    TemplatePreview, TemplatePreviewAction,
 };
 
 /// Get the path to a resource file, checking both development and bundle paths
@@ -63,9 +72,33 @@ pub struct BudgetApp {
     category_manager: CategoryManager,
     calculator: Calculator,
     template_manager: TemplateManager,
+    template_preview: TemplatePreview,
     preset_panel: PresetPanel,
     profile_selector: ProfileSelector,
     profile_manager: ProfileManager,
+    command_palette: CommandPalette,
+    shortcut_editor: ShortcutEditor,
+    tag_report: TagReport,
+    goals_panel: GoalsPanel,
+    debts_panel: DebtsPanel,
+    accounts_panel: AccountsPanel,
+    envelope_panel: EnvelopePanel,
+    rules_panel: RulesPanel,
+    payees_panel: PayeesPanel,
+    profile_compare: ProfileCompare,
+    household_view: HouseholdView,
+    archived_view: ArchivedView,
+    move_expenses: MoveExpensesDialog,
+    bundle_dialog: BundleDialog,
+
+    // Expense list filter and tag autocomplete source
+    tag_filter: Option<String>,
+    /// Expenses picked with Ctrl/Cmd+click for moving between profiles
+    selected_expenses: HashSet<Uuid>,
+    /// Show the month calendar instead of the expense list
+    show_calendar: bool,
+    calendar_view: CalendarView,
+    known_tags: Vec<String>,
 
     logo_texture: Option<TextureHandle>,
 }
@@ -93,7 +126,7 @@ impl BudgetApp {
         // Load the logo image
         let logo_texture = load_logo(&cc.egui_ctx);
 
-        Self {
+        let app = Self {
             config,
             current_profile_id,
             profile_data,
@@ -104,15 +137,43 @@ impl BudgetApp {
             category_manager: CategoryManager::new(),
             calculator: Calculator::new(),
             template_manager: TemplateManager::new(),
+            template_preview: TemplatePreview::new(),
             preset_panel: PresetPanel::new(),
             profile_selector: ProfileSelector::new(),
             profile_manager: ProfileManager::new(),
+            command_palette: CommandPalette::new(),
+            shortcut_editor: ShortcutEditor::new(),
+            tag_report: TagReport::new(),
+            goals_panel: GoalsPanel::new(),
+            debts_panel: DebtsPanel::new(),
+            accounts_panel: AccountsPanel::new(),
+            envelope_panel: EnvelopePanel::new(),
+            rules_panel: RulesPanel::new(),
+            payees_panel: PayeesPanel::new(),
+            profile_compare: ProfileCompare::new(),
+            household_view: HouseholdView::new(),
+            archived_view: ArchivedView::new(),
+            move_expenses: MoveExpensesDialog::new(),
+            bundle_dialog: BundleDialog::new(),
+            tag_filter: None,
+            selected_expenses: HashSet::new(),
+            show_calendar: false,
+            calendar_view: CalendarView::new(),
+            known_tags: Vec::new(),
             logo_texture,
-        }
+        };
+        // Sweep up receipts left behind by a crash or a cancelled form
+        app.remove_orphaned_attachments();
+        app
     }
 
     /// Switch to a different profile
     fn switch_profile(&mut self, profile_id: &str) {
+        // Archived profiles are read-only; they open in the archived view instead
+        if self.config.profiles.iter().any(|p| p.id == profile_id && p.is_archived()) {
+            return;
+        }
+
         // Save current profile first
         self.save_profile();
 
@@ -124,25 +185,30 @@ impl BudgetApp {
         self.config.active_profile_id = profile_id.to_string();
         let _ = save_config(&self.config);
 
+        // Tag filter and selection belong to the previous profile's list
+        self.tag_filter = None;
+        self.selected_expenses.clear();
+
         // Recompose budget view
         self.recompose_budget();
     }
 
-    /// Cycle to the next profile in the list
+    /// Cycle to the next profile in the list, skipping archived ones
     fn cycle_to_next_profile(&mut self) {
-        if self.config.profiles.len() <= 1 {
+        let profiles: Vec<&ProfileMeta> = self.config.visible_profiles().collect();
+        if profiles.len() <= 1 {
             return; // Nothing to cycle through
         }
 
         // Find current profile index
-        let current_index = self.config.profiles
+        let current_index = profiles
             .iter()
             .position(|p| p.id == self.current_profile_id)
             .unwrap_or(0);
 
         // Get next profile (wrapping around)
-        let next_index = (current_index + 1) % self.config.profiles.len();
-        let next_profile_id = self.config.profiles[next_index].id.clone();
+        let next_index = (current_index + 1) % profiles.len();
+        let next_profile_id = profiles[next_index].id.clone();
 
         self.switch_profile(&next_profile_id);
     }
@@ -160,6 +226,12 @@ impl BudgetApp {
     /// Recompose the budget view after changes
     fn recompose_budget(&mut self) {
         self.budget = compose_budget(&self.profile_data, &self.shared_data);
+        // Drop the tag filter once no expense carries the tag
+        if let Some(tag) = &self.tag_filter
+            && !self.budget.all_tags().contains(tag)
+        {
+            self.tag_filter = None;
+        }
     }
 
     /// Save both profile and shared data (legacy compatibility)
@@ -196,25 +268,800 @@ impl BudgetApp {
                 if self.config.remove_profile(&id) {
                     let _ = delete_profile_file(&id);
                     let _ = save_config(&self.config);
+                    self.remove_orphaned_attachments();
+                }
+            }
+            ProfileAction::Archive(id) => {
+                if self.config.archive_profile(&id) {
+                    let _ = save_config(&self.config);
                 }
             }
+            ProfileAction::Restore(id) => {
+                self.config.restore_profile(&id);
+                let _ = save_config(&self.config);
+            }
             ProfileAction::Switch(id) => {
                 self.switch_profile(&id);
                 self.profile_manager.close();
             }
+            ProfileAction::View(id) => {
+                self.profile_manager.close();
+                self.open_archived_view(&id);
+            }
+            ProfileAction::Compare => {
+                self.profile_manager.close();
+                self.open_profile_compare();
+            }
+            ProfileAction::Household => {
+                self.profile_manager.close();
+                self.open_household_view();
+            }
+            ProfileAction::Merge(source_id, target_id) => {
+                self.merge_profiles(&source_id, &target_id);
+            }
+            ProfileAction::Export(id) => {
+                self.profile_manager.close();
+                self.open_bundle_export(&id);
+            }
+            ProfileAction::Import => {
+                self.profile_manager.close();
+                self.bundle_dialog.open_import();
+            }
+        }
+    }
+
+    /// Show an archived profile read-only, without switching to it
+    fn open_archived_view(&mut self, profile_id: &str) {
+        let Some(meta) = self.config.profiles.iter().find(|p| p.id == profile_id) else {
+            return;
+        };
+        let budget = try_load_profile(profile_id).map(|data| compose_budget(&data, &self.shared_data));
+        self.archived_view.open(meta.name.clone(), budget);
+    }
+
+    fn open_profile_compare(&mut self) {
+        self.profile_compare.open(&self.current_profile_id);
+        self.refresh_profile_compare();
+    }
+
+    /// Summaries of the given profiles, loaded from disk (the active one from memory) without switching
+    fn profile_summaries(&self, ids: &[String]) -> Vec<ProfileSummary> {
+        ids.iter()
+            .filter_map(|id| {
+                let meta = self.config.profiles.iter().find(|p| p.id == *id)?;
+                let budget = if *id == self.current_profile_id {
+                    self.budget.clone()
+                } else {
+                    compose_budget(&load_profile(id), &self.shared_data)
+                };
+                Some(ProfileSummary::new(meta.name.clone(), &budget))
+            })
+            .collect()
+    }
+
+    fn refresh_profile_compare(&mut self) {
+        let summaries = self.profile_summaries(self.profile_compare.selected());
+        self.profile_compare.set_summaries(summaries);
+    }
+
+    /// Open the household view with the saved members, or every profile the first time
+    fn open_household_view(&mut self) {
+        let selected = if self.config.household_profile_ids.is_empty() {
+            self.config.visible_profiles().map(|p| p.id.clone()).collect()
+        } else {
+            self.config.household_profile_ids.clone()
+        };
+        self.household_view.open(selected);
+        self.refresh_household_view();
+    }
+
+    fn refresh_household_view(&mut self) {
+        let members = self.profile_summaries(self.household_view.selected());
+        self.household_view.set_members(members);
+    }
+
+    /// Handle actions from the expense list
+    fn handle_history_action(&mut self, ctx: &egui::Context, action: HistoryAction) {
+        match action {
+            HistoryAction::DeleteExpense(id) => {
+                self.profile_data.expenses.retain(|e| e.id != id);
+                self.selected_expenses.remove(&id);
+                self.save_profile();
+                self.recompose_budget();
+                self.remove_orphaned_attachments();
+            }
+            HistoryAction::ToggleExpense(id) => {
+                if let Some(exp) = self.profile_data.expenses.iter_mut().find(|e| e.id == id) {
+                    exp.active = !exp.active;
+                }
+                self.save_profile();
+                self.recompose_budget();
+            }
+            HistoryAction::SaveAsPreset(id) => {
+                // Open preset panel with expense data pre-filled
+                if let Some(expense) = self.profile_data.expenses.iter().find(|e| e.id == id) {
+                    let name = if expense.description.is_empty() {
+                        expense.category.clone()
+                    } else {
+                        expense.description.clone()
+                    };
+                    self.preset_panel.init_from_expense(
+                        name,
+                        expense.amount,
+                        expense.category.clone(),
+                        expense.description.clone(),
+                    );
+                }
+            }
+            HistoryAction::FilterByTag(tag) => {
+                self.tag_filter = tag;
+            }
+            HistoryAction::OpenTagReport => {
+                self.open_tag_report();
+            }
+            // Drops go to the expense form while it's open
+            HistoryAction::AttachFiles(_, _) if self.expense_form.is_open => {}
+            HistoryAction::AttachFiles(id, paths) => {
+                let attachments: Vec<_> = paths.iter().filter_map(|p| import_attachment(p).ok()).collect();
+                if let Some(exp) = self.profile_data.expenses.iter_mut().find(|e| e.id == id) {
+                    for attachment in attachments {
+                        if !exp.attachments.contains(&attachment) {
+                            exp.attachments.push(attachment);
+                        }
+                    }
+                }
+                self.save_profile();
+                self.recompose_budget();
+            }
+            HistoryAction::OpenAttachment(attachment) => {
+                // Opens with the system's default app for the file type
+                if let Some(path) = get_attachment_path(&attachment).filter(|p| p.exists())
+                    && let Ok(url) = url::Url::from_file_path(&path)
+                {
+                    ctx.open_url(egui::OpenUrl::same_tab(url.to_string()));
+                }
+            }
+            HistoryAction::RemoveAttachment(id, hash) => {
+                if let Some(exp) = self.profile_data.expenses.iter_mut().find(|e| e.id == id) {
+                    exp.attachments.retain(|a| a.hash != hash);
+                }
+                self.save_profile();
+                self.recompose_budget();
+                self.remove_orphaned_attachments();
+            }
+            HistoryAction::ShowCalendar(show) => {
+                self.show_calendar = show;
+            }
+            HistoryAction::ToggleSelected(id) => {
+                if !self.selected_expenses.remove(&id) {
+                    self.selected_expenses.insert(id);
+                }
+            }
+            HistoryAction::ClearSelection => {
+                self.selected_expenses.clear();
+            }
+            HistoryAction::MoveToProfile(ids) => {
+                self.move_expenses.open(ids);
+            }
+            HistoryAction::CreateRule(id) => {
+                if let Some(expense) = self.profile_data.expenses.iter().find(|e| e.id == id) {
+                    self.rules_panel.open_with(&Rule::from_expense(expense));
+                }
+            }
+            HistoryAction::LinkDebt(id, debt_id) => {
+                if let Some(exp) = self.profile_data.expenses.iter_mut().find(|e| e.id == id) {
+                    exp.debt_id = debt_id;
+                }
+                self.save_profile();
+                self.recompose_budget();
+            }
+        }
+    }
+
+    /// Move or copy expenses from the current profile into another one
+    fn handle_move_expenses_action(&mut self, action: MoveExpensesAction) {
+        let (ids, target_id, copy) = match action {
+            MoveExpensesAction::Move(ids, target_id) => (ids, target_id, false),
+            MoveExpensesAction::Copy(ids, target_id) => (ids, target_id, true),
+        };
+        let target_archived = self.config.profiles.iter().any(|p| p.id == target_id && p.is_archived());
+        if target_id == self.current_profile_id || target_archived {
+            return;
+        }
+
+        let expenses: Vec<Expense> = self.profile_data.expenses
+            .iter()
+            .filter(|e| ids.contains(&e.id))
+            .map(|e| if copy { Expense { id: Uuid::new_v4(), ..e.clone() } } else { e.clone() })
+            .collect();
+
+        let target_name = self.config.profiles
+            .iter()
+            .find(|p| p.id == target_id)
+            .map_or(target_id.clone(), |p| p.name.clone());
+
+        // Saving a target that failed to load would overwrite it
+        let mut target = match try_load_profile(&target_id) {
+            Ok(target) => target,
+            Err(e) => {
+                self.move_expenses.show_result(Err(format!("Couldn't open {}: {}", target_name, e)));
+                return;
+            }
+        };
+        let outcome = target.add_expenses(expenses);
+        if let Err(e) = save_profile(&target_id, &target) {
+            self.move_expenses.show_result(Err(format!("Couldn't save {}: {}", target_name, e)));
+            return;
+        }
+
+        // Expenses the target already had stay here too, so nothing is lost
+        if !copy {
+            self.profile_data.expenses.retain(|e| !outcome.added.contains(&e.id));
+            self.save_profile();
+            self.recompose_budget();
+        }
+        self.selected_expenses.clear();
+        self.move_expenses.show_result(Ok(format!("{} to {}.", outcome.describe(), target_name)));
+    }
+
+    fn open_bundle_export(&mut self, profile_id: &str) {
+        if let Some(meta) = self.config.profiles.iter().find(|p| p.id == profile_id) {
+            self.bundle_dialog.open_export(meta.id.clone(), meta.name.clone(), default_bundle_path(&meta.id));
+        }
+    }
+
+    /// Handle actions from the bundle export/import dialog
+    fn handle_bundle_action(&mut self, action: BundleAction) {
+        match action {
+            BundleAction::Export { profile_id, path, include_presets, include_templates } => {
+                let Some(meta) = self.config.profiles.iter().find(|p| p.id == profile_id) else {
+                    return;
+                };
+                let data = if profile_id == self.current_profile_id {
+                    self.profile_data.clone()
+                } else {
+                    load_profile(&profile_id)
+                };
+                let bundle = ProfileBundle::new(meta.name.clone(), &data, &self.shared_data, include_presets, include_templates);
+                let result = export_profile_bundle(&path, &bundle)
+                    .map(|_| format!("Saved to {}", path.display()));
+                self.bundle_dialog.set_message(result);
+            }
+            BundleAction::Load(path) => match load_profile_bundle(&path) {
+                Ok(bundle) => {
+                    let conflicts = bundle.conflicts(&self.shared_data);
+                    self.bundle_dialog.set_loaded(bundle, conflicts);
+                }
+                Err(e) => self.bundle_dialog.set_message(Err(e)),
+            },
+            BundleAction::Import(bundle, conflicts) => {
+                let mut name = bundle.name.clone();
+                if self.config.profiles.iter().any(|p| p.name == name) {
+                    name = format!("{} (imported)", name);
+                }
+                let mut data = match bundle.import_into(&mut self.shared_data, &conflicts) {
+                    Ok(data) => data,
+                    Err(e) => {
+                        self.bundle_dialog.set_message(Err(e));
+                        return;
+                    }
+                };
+                self.save_shared();
+                self.apply_rules_to_imported(&mut data.expenses);
+
+                let id = self.config.generate_profile_id(&name);
+                let result = save_profile(&id, &data).map(|_| {
+                    self.config.add_profile(ProfileMeta::new(id, name.clone()));
+                    let _ = save_config(&self.config);
+                    format!("Imported as \"{}\"", name)
+                });
+                self.bundle_dialog.set_message(result);
+                self.recompose_budget();
+            }
+        }
+    }
+
+    /// Merge a profile into another, then delete it
+    fn merge_profiles(&mut self, source_id: &str, target_id: &str) {
+        let is_archived = |id: &str| self.config.profiles.iter().any(|p| p.id == id && p.is_archived());
+        if source_id == self.current_profile_id || source_id == target_id || is_archived(source_id) || is_archived(target_id) {
+            return;
+        }
+        let name_of = |id: &str| {
+            self.config.profiles.iter().find(|p| p.id == id).map_or(id.to_string(), |p| p.name.clone())
+        };
+        let (source_name, target_name) = (name_of(source_id), name_of(target_id));
+
+        // The source is deleted afterwards, so both sides must load for real
+        let source = match try_load_profile(source_id) {
+            Ok(source) => source,
+            Err(e) => {
+                self.profile_manager.set_notice(format!("Couldn't open {}: {}", source_name, e));
+                return;
+            }
+        };
+        let outcome = if target_id == self.current_profile_id {
+            let outcome = self.profile_data.merge(source);
+            self.save_profile();
+            self.recompose_budget();
+            outcome
+        } else {
+            let mut target = match try_load_profile(target_id) {
+                Ok(target) => target,
+                Err(e) => {
+                    self.profile_manager.set_notice(format!("Couldn't open {}: {}", target_name, e));
+                    return;
+                }
+            };
+            let outcome = target.merge(source);
+            if let Err(e) = save_profile(target_id, &target) {
+                self.profile_manager.set_notice(format!("Couldn't save {}: {}", target_name, e));
+                return;
+            }
+            outcome
+        };
+
+        if self.config.remove_profile(source_id) {
+            let _ = delete_profile_file(source_id);
+            let _ = save_config(&self.config);
+        }
+        self.profile_manager.set_notice(format!(
+            "Merged {} into {}. {}.",
+            source_name,
+            target_name,
+            outcome.describe()
+        ));
+    }
+
+    /// Delete stored attachment files no longer referenced by any profile or template.
+    /// Does nothing if a profile can't be read, since its receipts would look unused.
+    fn remove_orphaned_attachments(&self) {
+        let hashes = |expenses: &[Expense]| -> Vec<String> {
+            expenses.iter().flat_map(|e| e.attachments.iter().map(|a| a.hash.clone())).collect()
+        };
+        let mut referenced: HashSet<String> = self.shared_data.templates
+            .iter()
+            .flat_map(|t| hashes(&t.expenses))
+            .collect();
+        for meta in &self.config.profiles {
+            let Ok(data) = try_load_profile(&meta.id) else {
+                return;
+            };
+            referenced.extend(hashes(&data.expenses));
+        }
+        referenced.extend(hashes(&self.profile_data.expenses));
+        // Files dropped on an expense that hasn't been added yet
+        if self.expense_form.is_open {
+            referenced.extend(self.expense_form.attachment_hashes());
+        }
+        let _ = remove_orphaned_attachments(&referenced);
+    }
+
+    /// Add an expense from a preset, dated today
+    fn add_expense_from_preset(&mut self, preset_id: Uuid) {
+        self.add_expense_from_preset_on(preset_id, Local::now().date_naive());
+    }
+
+    fn expense_from_preset(&self, preset_id: Uuid, date: NaiveDate) -> Option<Expense> {
+        self.budget.get_preset(preset_id).map(|preset| {
+            Expense::new(
+                preset.amount,
+                preset.category.clone(),
+                preset.description.clone(),
+                date,
+            )
+        })
+    }
+
+    fn add_expense_from_preset_on(&mut self, preset_id: Uuid, date: NaiveDate) {
+        if let Some(expense) = self.expense_from_preset(preset_id, date) {
+            self.profile_data.expenses.push(expense);
+            self.save_profile();
+            self.recompose_budget();
+        }
+    }
+
+    /// Add an expense from a preset, dated today, to another profile without switching to it
+    fn add_expense_from_preset_to_profile(&mut self, preset_id: Uuid, profile_id: &str) {
+        if profile_id == self.current_profile_id {
+            self.add_expense_from_preset(preset_id);
+            return;
+        }
+        // Saving a profile that failed to load would overwrite it with just this expense
+        if let Some(expense) = self.expense_from_preset(preset_id, Local::now().date_naive())
+            && let Ok(mut data) = try_load_profile(profile_id)
+        {
+            // A category only this profile has goes along with the expense
+            let own = &self.profile_data.category_overrides;
+            if own.contains(&expense.category) && !self.shared_data.categories.contains(&expense.category) {
+                let color = own.colors.get(&expense.category).copied().unwrap_or(DEFAULT_CATEGORY_COLOR);
+                data.category_overrides.add(expense.category.clone(), color);
+            }
+            data.expenses.push(expense);
+            let _ = save_profile(profile_id, &data);
+        }
+    }
+
+    /// Add a preset to a template as a new template expense
+    fn add_preset_to_template(&mut self, preset_id: Uuid, template_id: Uuid) {
+        if let Some(expense) = self.expense_from_preset(preset_id, Local::now().date_naive()) {
+            self.shared_data.add_template_expense(template_id, expense);
+            self.save_shared();
+            self.recompose_budget();
+        }
+    }
+
+    /// Preview a template's dates and amounts for this profile before applying it.
+    /// `replace` loads it in place of the current expenses instead of adding to them.
+    fn open_template_preview(&mut self, template_id: Uuid, replace: bool) {
+        if let Some(template) = self.shared_data.templates.iter().find(|t| t.id == template_id) {
+            self.template_preview.open(template.clone(), replace, self.profile_data.income);
+        }
+    }
+
+    /// Data for every profile as (name, data), using in-memory data for the active one
+    fn all_profiles_data(&self) -> Vec<(String, ProfileData)> {
+        self.config.profiles
+            .iter()
+            .map(|meta| {
+                let data = if meta.id == self.current_profile_id {
+                    self.profile_data.clone()
+                } else {
+                    load_profile(&meta.id)
+                };
+                (meta.name.clone(), data)
+            })
+            .collect()
+    }
+
+    /// Open the expense form with tag and description suggestions gathered from every profile and template
+    fn open_expense_form(&mut self) {
+        let profiles = self.all_profiles_data();
+        let mut tags: BTreeSet<String> = profiles
+            .iter()
+            .flat_map(|(_, data)| data.expenses.iter().flat_map(|e| e.tags.iter().cloned()))
+            .collect();
+        tags.extend(
+            self.shared_data.templates
+                .iter()
+                .flat_map(|t| t.expenses.iter().flat_map(|e| e.tags.iter().cloned())),
+        );
+        self.known_tags = tags.into_iter().collect();
+        self.expense_form.open();
+        self.expense_form.suggestions = SuggestionIndex::build(profiles.iter().flat_map(|(_, data)| data.expenses.iter()));
+        self.expense_form.payees = self.shared_data.payees.clone();
+    }
+
+    fn handle_goal_action(&mut self, action: GoalAction, today: NaiveDate) {
+        match action {
+            GoalAction::Create(goal) => {
+                self.profile_data.goals.push(goal);
+            }
+            GoalAction::Delete(id) => {
+                self.profile_data.goals.retain(|g| g.id != id);
+                // Keep the contributions as plain savings expenses
+                for expense in self.profile_data.expenses.iter_mut().filter(|e| e.goal_id == Some(id)) {
+                    expense.goal_id = None;
+                }
+            }
+            GoalAction::Contribute(id, amount) => {
+                let Some(goal) = self.profile_data.goals.iter().find(|g| g.id == id) else {
+                    return;
+                };
+                let expense = Expense::new(
+                    amount,
+                    SAVINGS_CATEGORY.to_string(),
+                    format!("Contribution: {}", goal.name),
+                    today,
+                )
+                .with_goal(id);
+                self.profile_data.expenses.push(expense);
+                self.ensure_category(SAVINGS_CATEGORY, [99, 102, 241]);
+            }
+        }
+        self.save_profile();
+        self.recompose_budget();
+    }
+
+    fn handle_debt_action(&mut self, action: DebtAction, today: NaiveDate) {
+        match action {
+            DebtAction::Create(debt) => {
+                self.profile_data.debts.push(debt);
+            }
+            DebtAction::Delete(id) => {
+                self.profile_data.debts.retain(|d| d.id != id);
+                for expense in self.profile_data.expenses.iter_mut().filter(|e| e.debt_id == Some(id)) {
+                    expense.debt_id = None;
+                }
+            }
+            DebtAction::RecordPayment(id, amount) => {
+                let Some(debt) = self.profile_data.debts.iter().find(|d| d.id == id) else {
+                    return;
+                };
+                let expense = Expense::new(
+                    amount,
+                    DEBT_CATEGORY.to_string(),
+                    format!("Payment: {}", debt.name),
+                    today,
+                )
+                .with_debt(id);
+                self.profile_data.expenses.push(expense);
+                self.ensure_category(DEBT_CATEGORY, [239, 68, 68]);
+            }
+        }
+        self.save_profile();
+        self.recompose_budget();
+    }
+
+    fn handle_account_action(&mut self, action: AccountAction) {
+        match action {
+            AccountAction::Create(account) => {
+                self.profile_data.accounts.push(account);
+            }
+            AccountAction::Delete(id) => {
+                self.profile_data.accounts.retain(|a| a.id != id);
+                self.profile_data.transfers.retain(|t| t.from != id && t.to != id);
+                for expense in self.profile_data.expenses.iter_mut().filter(|e| e.account_id == Some(id)) {
+                    expense.account_id = None;
+                }
+            }
+            AccountAction::Transfer(transfer) => {
+                self.profile_data.transfers.push(transfer);
+            }
+            AccountAction::Reconcile(id, reconciliation) => {
+                if let Some(account) = self.profile_data.accounts.iter_mut().find(|a| a.id == id) {
+                    account.reconciled = Some(reconciliation);
+                }
+            }
+        }
+        self.save_profile();
+        self.recompose_budget();
+    }
+
+    fn handle_envelope_action(&mut self, action: EnvelopeAction, period: &str) {
+        match action {
+            EnvelopeAction::SetMode(enabled) => {
+                self.profile_data.envelope_mode = enabled;
+            }
+            EnvelopeAction::Assign(category, amount) => {
+                self.profile_data.envelopes.assign(period, &category, amount);
+            }
+            EnvelopeAction::Move { from, to, amount } => {
+                self.profile_data.envelopes.move_amount(period, &from, &to, amount);
+            }
+        }
+        self.save_profile();
+        self.recompose_budget();
+    }
+
+    /// Run the enabled rules over expenses coming in from an import. Expenses entered
+    /// by hand keep what the user picked; rules only touch them through "Run rules".
+    fn apply_rules_to_imported(&self, expenses: &mut [Expense]) {
+        for expense in expenses {
+            apply_rules(&self.shared_data.rules, expense);
+        }
+    }
+
+    fn handle_rule_action(&mut self, action: RuleAction) {
+        match action {
+            RuleAction::Save(rule) => self.shared_data.save_rule(rule),
+            RuleAction::Delete(id) => self.shared_data.remove_rule(id),
+            RuleAction::SetEnabled(id, enabled) => {
+                if let Some(rule) = self.shared_data.rules.iter_mut().find(|r| r.id == id) {
+                    rule.enabled = enabled;
+                }
+            }
+            RuleAction::Move(id, up) => self.shared_data.move_rule(id, up),
+            RuleAction::ApplyAll => {
+                let rules = &self.shared_data.rules;
+                let changed = self.profile_data.expenses
+                    .iter_mut()
+                    .map(|e| apply_rules(rules, e))
+                    .filter(|changed| *changed)
+                    .count();
+                self.rules_panel.set_applied(changed);
+                self.save_profile();
+                self.recompose_budget();
+                return;
+            }
+        }
+        self.save_shared();
+    }
+
+    fn open_payees(&mut self) {
+        self.payees_panel.open(payee_totals(&self.all_profiles_data(), &self.shared_data.payees));
+    }
+
+    fn handle_payee_action(&mut self, action: PayeeAction) {
+        match action {
+            PayeeAction::Create(name) => self.shared_data.payees.push(Payee::new(name)),
+            PayeeAction::Delete(id) => self.shared_data.remove_payee(id),
+            PayeeAction::AddAlias(id, alias) => {
+                if let Some(payee) = self.shared_data.get_payee_mut(id) {
+                    payee.add_alias(&alias);
+                }
+            }
+            PayeeAction::RemoveAlias(id, alias) => {
+                if let Some(payee) = self.shared_data.get_payee_mut(id) {
+                    payee.aliases.retain(|a| *a != alias);
+                }
+            }
+            PayeeAction::SetDefaultCategory(id, category) => {
+                if let Some(payee) = self.shared_data.get_payee_mut(id) {
+                    payee.default_category = category;
+                }
+            }
+            PayeeAction::NormalizeExpenses => {
+                for expense in self.profile_data.expenses.iter_mut().filter(|e| !e.payee.is_empty()) {
+                    expense.payee = normalize_payee(&self.shared_data.payees, &expense.payee);
+                }
+                self.save_profile();
+                self.recompose_budget();
+            }
         }
+        self.save_shared();
+        self.payees_panel.set_totals(payee_totals(&self.all_profiles_data(), &self.shared_data.payees));
+    }
+
+    /// Add a built-in category (e.g. for goal contributions) the first time it's used
+    fn ensure_category(&mut self, name: &str, color: CategoryColor) {
+        if !self.shared_data.categories.iter().any(|c| c == name) {
+            self.shared_data.add_category_with_color(name.to_string(), color);
+            self.save_shared();
+        }
+    }
+
+    fn open_tag_report(&mut self) {
+        self.tag_report.open(tag_totals(&self.all_profiles_data()));
+    }
+
+    /// Display label for the key currently bound to an action
+    fn shortcut_label(&self, action: ShortcutAction) -> String {
+        self.config.keymap.binding(action).label()
+    }
+
+    /// Run the action bound to a keyboard shortcut
+    fn run_shortcut(&mut self, action: ShortcutAction) {
+        match action {
+            ShortcutAction::AddExpense => self.open_expense_form(),
+            ShortcutAction::OpenTemplates => self.template_manager.open(),
+            ShortcutAction::OpenCalculator => self.calculator.open(),
+            ShortcutAction::CycleProfile => self.cycle_to_next_profile(),
+            ShortcutAction::ManageProfiles => self.profile_manager.open(),
+            ShortcutAction::ToggleQuickAdd => self.preset_panel.toggle(),
+            ShortcutAction::EditIncome => self.income_form.open(self.budget.income, &self.profile_data.scheduled_income),
+            ShortcutAction::CommandPalette => self.command_palette.open(),
+        }
+    }
+
+    /// Run a command chosen from the command palette
+    fn run_palette_command(&mut self, command: PaletteCommand) {
+        match command {
+            PaletteCommand::Shortcut(action) => self.run_shortcut(action),
+            PaletteCommand::OpenCategories => self.category_manager.open(),
+            PaletteCommand::EditShortcuts => self.shortcut_editor.open(),
+            PaletteCommand::OpenTagReport => self.open_tag_report(),
+            PaletteCommand::OpenGoals => self.goals_panel.open(),
+            PaletteCommand::OpenDebts => self.debts_panel.open(),
+            PaletteCommand::OpenAccounts => self.accounts_panel.open(),
+            PaletteCommand::OpenEnvelopes => self.envelope_panel.open(),
+            PaletteCommand::OpenRules => self.rules_panel.open(),
+            PaletteCommand::OpenPayees => self.open_payees(),
+            PaletteCommand::CompareProfiles => self.open_profile_compare(),
+            PaletteCommand::OpenHousehold => self.open_household_view(),
+            PaletteCommand::ExportProfile => {
+                let id = self.current_profile_id.clone();
+                self.open_bundle_export(&id);
+            }
+            PaletteCommand::ImportProfile => self.bundle_dialog.open_import(),
+            PaletteCommand::SwitchProfile(id) => self.switch_profile(&id),
+            PaletteCommand::ApplyTemplate(id) => self.open_template_preview(id, false),
+            PaletteCommand::AddPreset(id) => self.add_expense_from_preset(id),
+        }
+    }
+
+    /// Build the searchable list of everything the command palette can do
+    fn palette_entries(&self) -> Vec<PaletteEntry> {
+        let mut entries: Vec<PaletteEntry> = ShortcutAction::ALL
+            .iter()
+            .filter(|a| **a != ShortcutAction::CommandPalette)
+            .map(|a| PaletteEntry::new(a.label(), self.shortcut_label(*a), PaletteCommand::Shortcut(*a)))
+            .collect();
+
+        entries.push(PaletteEntry::new("Manage Categories", "", PaletteCommand::OpenCategories));
+        entries.push(PaletteEntry::new("Edit Keyboard Shortcuts", "", PaletteCommand::EditShortcuts));
+        entries.push(PaletteEntry::new("Open Tag Report", "Report", PaletteCommand::OpenTagReport));
+        entries.push(PaletteEntry::new("Open Savings Goals", "", PaletteCommand::OpenGoals));
+        entries.push(PaletteEntry::new("Open Debts & Loans", "", PaletteCommand::OpenDebts));
+        entries.push(PaletteEntry::new("Open Accounts", "", PaletteCommand::OpenAccounts));
+        entries.push(PaletteEntry::new("Open Envelopes", "", PaletteCommand::OpenEnvelopes));
+        entries.push(PaletteEntry::new("Open Auto-Categorization Rules", "", PaletteCommand::OpenRules));
+        entries.push(PaletteEntry::new("Open Payees", "Report", PaletteCommand::OpenPayees));
+        entries.push(PaletteEntry::new("Export Profile to File", "Profile", PaletteCommand::ExportProfile));
+        entries.push(PaletteEntry::new("Import Profile from File", "Profile", PaletteCommand::ImportProfile));
+        if self.config.profiles.len() > 1 {
+            entries.push(PaletteEntry::new("Compare Profiles", "Profile", PaletteCommand::CompareProfiles));
+            entries.push(PaletteEntry::new("Open Household View", "Profile", PaletteCommand::OpenHousehold));
+        }
+
+        for profile in self.config.visible_profiles() {
+            if profile.id != self.current_profile_id {
+                entries.push(PaletteEntry::new(
+                    format!("Switch to profile: {}", profile.name),
+                    "Profile",
+                    PaletteCommand::SwitchProfile(profile.id.clone()),
+                ));
+            }
+        }
+        for template in &self.shared_data.templates {
+            entries.push(PaletteEntry::new(
+                format!("Apply template: {}", template.name),
+                "Template",
+                PaletteCommand::ApplyTemplate(template.id),
+            ));
+        }
+        for preset in &self.budget.presets {
+            entries.push(PaletteEntry::new(
+                format!("Add preset: {}", preset.name),
+                format!("${:.2}", preset.amount),
+                PaletteCommand::AddPreset(preset.id),
+            ));
+        }
+
+        entries
     }
 }
 
 /// Compose a Budget view from profile and shared data
 fn compose_budget(profile: &ProfileData, shared: &SharedData) -> Budget {
+    // Shared categories the profile doesn't hide, then its own
+    let overrides = &profile.category_overrides;
+    let visible: Vec<String> = shared
+        .categories
+        .iter()
+        .filter(|c| overrides.shows(c, &shared.category_parents))
+        .cloned()
+        .collect();
+    let mut categories = category::tree_order(&visible, &shared.category_parents);
+    categories.extend(overrides.categories.iter().filter(|c| !shared.categories.contains(c)).cloned());
+
+    // Hidden categories keep their colors so existing expenses still show them
+    let mut category_colors = category::resolved_colors(
+        &shared.categories,
+        &shared.category_colors,
+        &shared.category_parents,
+    );
+    for category in &overrides.categories {
+        let color = overrides.colors.get(category).copied().unwrap_or(DEFAULT_CATEGORY_COLOR);
+        category_colors.entry(category.clone()).or_insert(color);
+    }
+
+    let presets = shared
+        .presets
+        .iter()
+        .filter(|p| !profile.preset_overrides.hidden.contains(&p.id))
+        .chain(profile.preset_overrides.presets.iter())
+        .cloned()
+        .collect();
+
     Budget {
         income: profile.income,
         expenses: profile.expenses.clone(),
-        categories: shared.categories.clone(),
-        category_colors: shared.category_colors.clone(),
+        scheduled_income: profile.scheduled_income.clone(),
+        goals: profile.goals.clone(),
+        debts: profile.debts.clone(),
+        accounts: profile.accounts.clone(),
+        transfers: profile.transfers.clone(),
+        envelope_mode: profile.envelope_mode,
+        envelopes: profile.envelopes.clone(),
+        // Tree order and inherited colors, so pickers can show the hierarchy
+        // and look colors up directly
+        categories,
+        category_colors,
+        category_parents: shared.category_parents.clone(),
+        category_limits: shared.category_limits.clone(),
         templates: shared.templates.clone(),
-        presets: shared.presets.clone(),
+        presets,
     }
 }
 
@@ -297,8 +1144,21 @@ fn configure_styles(ctx: &egui::Context) {
 
 impl eframe::App for BudgetApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Render category manager popup window
-        let actions = self.category_manager.render(ctx, &self.shared_data.categories, &self.shared_data.category_colors);
+        // Render category manager popup window. It lists the shared categories this
+        // profile hides too, so they can be shown again.
+        let overrides = &self.profile_data.category_overrides;
+        let mut categories = category::tree_order(&self.shared_data.categories, &self.shared_data.category_parents);
+        categories.extend(overrides.categories.iter().filter(|c| !categories.contains(c)).cloned().collect::<Vec<_>>());
+        let mut category_colors = self.shared_data.category_colors.clone();
+        category_colors.extend(overrides.colors.iter().map(|(c, color)| (c.clone(), *color)));
+        let actions = self.category_manager.render(
+            ctx,
+            &categories,
+            &category_colors,
+            &self.shared_data.category_parents,
+            &self.shared_data.category_limits,
+            overrides,
+        );
         for action in actions {
             match action {
                 CategoryAction::Add(name, color) => {
@@ -306,13 +1166,65 @@ impl eframe::App for BudgetApp {
                     self.save_shared();
                     self.recompose_budget();
                 }
-                CategoryAction::Delete(name) => {
-                    self.shared_data.remove_category(&name);
+                CategoryAction::AddToProfile(name, color) => {
+                    // A shared category with the same name already covers it
+                    if !self.shared_data.categories.iter().any(|c| c.eq_ignore_ascii_case(name.trim())) {
+                        self.profile_data.category_overrides.add(name, color);
+                        self.save_profile();
+                        self.recompose_budget();
+                    }
+                }
+                CategoryAction::SetHidden(name, hidden) => {
+                    self.profile_data.category_overrides.set_hidden(&name, hidden);
+                    self.save_profile();
+                    self.recompose_budget();
+                }
+                CategoryAction::AddSubcategory(name, parent) => {
+                    self.shared_data.add_subcategory(name, &parent);
                     self.save_shared();
                     self.recompose_budget();
                 }
+                CategoryAction::Delete(name) => {
+                    if self.profile_data.category_overrides.contains(&name) {
+                        self.profile_data.category_overrides.remove(&name);
+                        self.save_profile();
+                    } else {
+                        self.shared_data.remove_category(&name);
+                        self.save_shared();
+                    }
+                    self.recompose_budget();
+                }
                 CategoryAction::UpdateColor(name, color) => {
-                    self.shared_data.set_category_color(&name, color);
+                    if self.profile_data.category_overrides.contains(&name) {
+                        self.profile_data.category_overrides.set_color(&name, color);
+                        self.save_profile();
+                    } else {
+                        self.shared_data.set_category_color(&name, color);
+                        self.save_shared();
+                    }
+                    self.recompose_budget();
+                }
+                CategoryAction::ResetColor(name) => {
+                    self.shared_data.reset_category_color(&name);
+                    self.save_shared();
+                    self.recompose_budget();
+                }
+                CategoryAction::SetParent(name, parent) => {
+                    self.shared_data.set_category_parent(&name, parent.as_deref());
+                    self.save_shared();
+                    self.recompose_budget();
+                }
+                CategoryAction::SetLimit(name, limit) => {
+                    // Keep the start month when only the amount or policy changes
+                    let today = Local::now().date_naive();
+                    let limit = limit.map(|(amount, rollover)| {
+                        let mut limit = CategoryLimit::new(amount, rollover, today);
+                        if let Some(existing) = self.shared_data.category_limits.get(&name) {
+                            limit.since = existing.since;
+                        }
+                        limit
+                    });
+                    self.shared_data.set_category_limit(&name, limit);
                     self.save_shared();
                     self.recompose_budget();
                 }
@@ -320,10 +1232,24 @@ impl eframe::App for BudgetApp {
         }
 
         // Render expense form popup window
+        // Files dropped while the expense form is open become its receipts
+        if self.expense_form.is_open {
+            let dropped: Vec<PathBuf> = ctx.input(|i| i.raw.dropped_files.iter().filter_map(|f| f.path.clone()).collect());
+            for path in dropped {
+                if let Ok(attachment) = import_attachment(&path) {
+                    self.expense_form.add_attachment(attachment);
+                }
+            }
+        }
+
+        let form_was_open = self.expense_form.is_open;
         let (expense, new_cat) = self.expense_form.render(
             ctx,
-            &self.shared_data.categories,
-            &self.shared_data.category_colors,
+            &self.budget.categories,
+            &self.budget.category_colors,
+            &self.budget.category_parents,
+            &self.known_tags,
+            &self.budget.accounts,
         );
         if let Some((cat_name, cat_color)) = new_cat {
             self.shared_data.add_category_with_color(cat_name, cat_color);
@@ -334,25 +1260,96 @@ impl eframe::App for BudgetApp {
             self.profile_data.expenses.push(exp);
             self.save_profile();
             self.recompose_budget();
+        } else if form_was_open && !self.expense_form.is_open {
+            // Cancelled - drop any receipts that were only attached to the form
+            self.remove_orphaned_attachments();
         }
 
         // Render income form popup window
-        if let Some(new_income) = self.income_form.render(ctx) {
+        if let Some((new_income, scheduled)) = self.income_form.render(ctx) {
             self.profile_data.income = new_income;
+            self.profile_data.scheduled_income = scheduled;
             self.save_profile();
             self.recompose_budget();
         }
 
         // Render calculator popup window
-        self.calculator.render(ctx);
+        match self.calculator.render(ctx, &self.budget) {
+            Some(CalculatorAction::UseAsExpense(amount)) => {
+                self.open_expense_form();
+                self.expense_form.amount = format!("{:.2}", amount);
+            }
+            Some(CalculatorAction::UseAsIncome(amount)) => {
+                self.income_form.open(amount, &self.profile_data.scheduled_income);
+            }
+            None => {}
+        }
+
+        // Render savings goals popup window
+        let today = Local::now().date_naive();
+        for action in self.goals_panel.render(ctx, &self.budget, today) {
+            self.handle_goal_action(action, today);
+        }
+
+        // Render debts popup window
+        for action in self.debts_panel.render(ctx, &self.budget, today) {
+            self.handle_debt_action(action, today);
+        }
+
+        // Render accounts popup window
+        for action in self.accounts_panel.render(ctx, &self.budget, today) {
+            self.handle_account_action(action);
+        }
+
+        // Render envelopes popup window
+        let period = period_key(today);
+        for action in self.envelope_panel.render(ctx, &self.budget, &period) {
+            self.handle_envelope_action(action, &period);
+        }
+
+        // Render rules popup window
+        for action in self.rules_panel.render(ctx, &self.shared_data.rules, &self.budget.categories) {
+            self.handle_rule_action(action);
+        }
+
+        // Render payees popup window
+        for action in self.payees_panel.render(ctx, &self.shared_data.payees, &self.budget.categories) {
+            self.handle_payee_action(action);
+        }
+
+        // Render profile comparison window
+        if self.profile_compare.render(ctx, &self.config.profiles) {
+            self.refresh_profile_compare();
+        }
+
+        // Render move/copy to profile dialog
+        if let Some(action) = self.move_expenses.render(ctx, &self.config.profiles, &self.current_profile_id) {
+            self.handle_move_expenses_action(action);
+        }
+
+        // Render profile export/import dialog
+        if let Some(action) = self.bundle_dialog.render(ctx, &self.shared_data.categories) {
+            self.handle_bundle_action(action);
+        }
+
+        // Render household rollup window
+        if self.household_view.render(ctx, &self.config.profiles) {
+            self.config.household_profile_ids = self.household_view.selected().to_vec();
+            let _ = save_config(&self.config);
+            self.refresh_household_view();
+        }
+
+        // Render archived profile window
+        self.archived_view.render(ctx);
 
         // Render template manager popup window
         let template_actions = self.template_manager.render(
             ctx,
             &self.shared_data.templates,
             self.profile_data.expenses.len(),
-            &self.shared_data.categories,
-            &self.shared_data.category_colors,
+            &self.budget.categories,
+            &self.budget.category_colors,
+            self.preset_panel.is_dragging(),
         );
         for action in template_actions {
             match action {
@@ -363,32 +1360,18 @@ impl eframe::App for BudgetApp {
                     self.recompose_budget();
                 }
                 TemplateAction::Load(id) => {
-                    // Load template expenses into profile (replaces)
-                    if let Some(template) = self.shared_data.templates.iter().find(|t| t.id == id) {
-                        self.profile_data.expenses = template.expenses.iter().map(|e| {
-                            Expense::new(e.amount, e.category.clone(), e.description.clone(), e.date)
-                        }).collect();
-                        self.save_profile();
-                        self.recompose_budget();
-                    }
+                    self.open_template_preview(id, true);
                     self.template_manager.close();
                 }
                 TemplateAction::Append(id) => {
-                    // Append template expenses to profile
-                    if let Some(template) = self.shared_data.templates.iter().find(|t| t.id == id) {
-                        for e in &template.expenses {
-                            let expense = Expense::new(e.amount, e.category.clone(), e.description.clone(), e.date);
-                            self.profile_data.expenses.push(expense);
-                        }
-                        self.save_profile();
-                        self.recompose_budget();
-                    }
+                    self.open_template_preview(id, false);
                     self.template_manager.close();
                 }
                 TemplateAction::Delete(id) => {
                     self.shared_data.delete_template(id);
                     self.save_shared();
                     self.recompose_budget();
+                    self.remove_orphaned_attachments();
                 }
                 TemplateAction::Rename(id, new_name) => {
                     self.shared_data.rename_template(id, new_name);
@@ -399,42 +1382,70 @@ impl eframe::App for BudgetApp {
                     self.shared_data.update_template_expenses(id, expenses);
                     self.save_shared();
                     self.recompose_budget();
+                    self.remove_orphaned_attachments();
+                }
+                TemplateAction::UpdateParams(id, params, variables) => {
+                    self.shared_data.update_template_params(id, params, variables);
+                    self.save_shared();
+                }
+            }
+        }
+
+        // Render template preview (dates and amounts filled in before applying)
+        if let Some(action) = self.template_preview.render(ctx) {
+            match action {
+                TemplatePreviewAction::Append(expenses) => {
+                    self.profile_data.expenses.extend(expenses);
+                    self.save_profile();
+                    self.recompose_budget();
+                }
+                TemplatePreviewAction::Replace(expenses) => {
+                    self.profile_data.expenses = expenses;
+                    self.save_profile();
+                    self.recompose_budget();
+                    self.remove_orphaned_attachments();
                 }
             }
+            self.template_preview.close();
         }
 
         // Render preset panel (slide-out on right)
         let preset_actions = self.preset_panel.render(
             ctx,
             &self.shared_data.presets,
-            &self.shared_data.categories,
-            &self.shared_data.category_colors,
+            &self.budget.categories,
+            &self.budget.category_colors,
+            &self.profile_data.preset_overrides,
         );
         for action in preset_actions {
             match action {
-                PresetAction::Create(preset) => {
+                PresetAction::Create(preset, Scope::Shared) => {
                     self.shared_data.add_preset(preset);
                     self.save_shared();
                     self.recompose_budget();
                 }
-                PresetAction::Delete(id) => {
-                    self.shared_data.remove_preset(id);
-                    self.save_shared();
+                PresetAction::Create(preset, Scope::Profile) => {
+                    self.profile_data.preset_overrides.presets.push(preset);
+                    self.save_profile();
                     self.recompose_budget();
                 }
-                PresetAction::AddToExpenses(id) => {
-                    // Create expense from preset
-                    if let Some(preset) = self.shared_data.get_preset(id).cloned() {
-                        let expense = Expense::new(
-                            preset.amount,
-                            preset.category,
-                            preset.description,
-                            Local::now().date_naive(),
-                        );
-                        self.profile_data.expenses.push(expense);
+                PresetAction::Delete(id) => {
+                    if self.profile_data.preset_overrides.contains(id) {
+                        self.profile_data.preset_overrides.remove(id);
                         self.save_profile();
-                        self.recompose_budget();
+                    } else {
+                        self.shared_data.remove_preset(id);
+                        self.save_shared();
                     }
+                    self.recompose_budget();
+                }
+                PresetAction::SetHidden(id, hidden) => {
+                    self.profile_data.preset_overrides.set_hidden(id, hidden);
+                    self.save_profile();
+                    self.recompose_budget();
+                }
+                PresetAction::AddToExpenses(id) => {
+                    self.add_expense_from_preset(id);
                 }
             }
         }
@@ -442,17 +1453,20 @@ impl eframe::App for BudgetApp {
         // Check if drag ended outside panel (for drag-to-add)
         if self.preset_panel.is_dragging() && !ctx.input(|i| i.pointer.any_down()) {
             if let Some(preset_id) = self.preset_panel.end_drag() {
-                // Drag released - add the expense
-                if let Some(preset) = self.shared_data.get_preset(preset_id).cloned() {
-                    let expense = Expense::new(
-                        preset.amount,
-                        preset.category,
-                        preset.description,
-                        Local::now().date_naive(),
-                    );
-                    self.profile_data.expenses.push(expense);
-                    self.save_profile();
-                    self.recompose_budget();
+                // Drag released - the drop target decides where the expense goes:
+                // a template, a profile chip, a calendar day, or today in this profile
+                let pointer = ctx.input(|i| i.pointer.latest_pos());
+                let template = pointer.and_then(|p| self.template_manager.template_at(p));
+                let profile = pointer.and_then(|p| self.profile_selector.profile_at(p)).map(str::to_string);
+                let day = pointer.filter(|_| self.show_calendar).and_then(|p| self.calendar_view.day_at(p));
+                if let Some(template_id) = template {
+                    self.add_preset_to_template(preset_id, template_id);
+                } else if let Some(profile_id) = profile {
+                    self.add_expense_from_preset_to_profile(preset_id, &profile_id);
+                } else if let Some(date) = day {
+                    self.add_expense_from_preset_on(preset_id, date);
+                } else {
+                    self.add_expense_from_preset(preset_id);
                 }
             }
         }
@@ -467,54 +1481,67 @@ impl eframe::App for BudgetApp {
             self.handle_profile_action(action);
         }
 
+        // Render keyboard shortcut editor
+        let keymap_actions = self.shortcut_editor.render(ctx, &self.config.keymap);
+        for action in keymap_actions {
+            match action {
+                KeymapAction::Rebind(shortcut, binding) => self.config.keymap.set_binding(shortcut, binding),
+                KeymapAction::ResetAll => self.config.keymap.reset(),
+            }
+            let _ = save_config(&self.config);
+        }
+
+        // Render tag report window
+        if let Some(tag) = self.tag_report.render(ctx) {
+            self.tag_filter = Some(tag);
+        }
+
+        // Render command palette
+        if self.command_palette.is_open {
+            let entries = self.palette_entries();
+            if let Some(command) = self.command_palette.render(ctx, &entries) {
+                self.run_palette_command(command);
+            }
+        }
+
         // Keyboard shortcuts (only when no modals are open and no text input is focused)
         let any_modal_open = self.expense_form.is_open
             || self.income_form.is_open
             || self.template_manager.is_open
+            || self.template_preview.is_open
             || self.category_manager.is_open
             || self.calculator.is_open
             || self.profile_manager.is_open
+            || self.command_palette.is_open
+            || self.shortcut_editor.is_open
+            || self.tag_report.is_open
+            || self.goals_panel.is_open
+            || self.debts_panel.is_open
+            || self.accounts_panel.is_open
+            || self.envelope_panel.is_open
+            || self.rules_panel.is_open
+            || self.payees_panel.is_open
+            || self.profile_compare.is_open
+            || self.household_view.is_open
+            || self.archived_view.is_open
+            || self.move_expenses.is_open
+            || self.bundle_dialog.is_open
             || self.profile_selector.is_popup_open();
 
         if !any_modal_open && !ctx.wants_keyboard_input() {
-            let e_pressed = ctx.input(|i| i.key_pressed(egui::Key::E));
-            let t_pressed = ctx.input(|i| i.key_pressed(egui::Key::T));
-            let c_pressed = ctx.input(|i| i.key_pressed(egui::Key::C));
-            let p_pressed = ctx.input(|i| i.key_pressed(egui::Key::P) && !i.modifiers.command);
-            let cmd_p_pressed = ctx.input(|i| i.key_pressed(egui::Key::P) && i.modifiers.command);
-            let q_pressed = ctx.input(|i| i.key_pressed(egui::Key::Q));
-            let i_pressed = ctx.input(|i| i.key_pressed(egui::Key::I));
-
-            // E - Add Expense
-            if e_pressed {
-                self.expense_form.open();
-            }
-            // T - Templates
-            if t_pressed {
-                self.template_manager.open();
-            }
-            // C - Calculator
-            if c_pressed {
-                self.calculator.open();
-            }
-            // P - Switch Profiles (cycle to next profile)
-            if p_pressed {
-                self.cycle_to_next_profile();
-            }
-            // Cmd+P - Open Profile Manager
-            if cmd_p_pressed {
-                self.profile_manager.open();
-            }
-            // Q - Quick Add
-            if q_pressed {
-                self.preset_panel.is_open = !self.preset_panel.is_open;
-            }
-            // I - Edit Income
-            if i_pressed {
-                self.income_form.open(self.budget.income);
+            let pressed = ctx.input(|i| {
+                ShortcutAction::ALL
+                    .iter()
+                    .copied()
+                    .find(|a| is_binding_pressed(i, &self.config.keymap.binding(*a)))
+            });
+            if let Some(action) = pressed {
+                self.run_shortcut(action);
             }
         }
 
+        let forecast = self.budget.forecast(Local::now().date_naive());
+
         // Bottom panel for balance bar - modern glassmorphism style
         egui::TopBottomPanel::bottom("balance_bar")
             .frame(egui::Frame::none()
@@ -528,7 +1555,7 @@ impl eframe::App for BudgetApp {
                     offset: [0.0, -4.0].into(),
                 }))
             .show(ctx, |ui| {
-                render_balance_bar(ui, &self.budget);
+                render_balance_bar(ui, &self.budget, &forecast);
             });
 
         // Use a light red background when not in the main profile
@@ -588,7 +1615,8 @@ impl eframe::App for BudgetApp {
                         .rounding(Rounding::same(12.0))
                         .min_size(Vec2::new(100.0, 36.0));
 
-                        if ui.add(preset_btn).on_hover_text("Keyboard shortcut: Q").clicked() {
+                        let hint = format!("Keyboard shortcut: {}", self.shortcut_label(ShortcutAction::ToggleQuickAdd));
+                        if ui.add(preset_btn).on_hover_text(hint).clicked() {
                             self.preset_panel.toggle();
                         }
 
@@ -611,6 +1639,108 @@ impl eframe::App for BudgetApp {
 
                         ui.add_space(8.0);
 
+                        // Envelopes button
+                        let envelopes_btn = egui::Button::new(
+                            egui::RichText::new("✉ Envelopes")
+                                .size(13.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(12.0))
+                        .min_size(Vec2::new(100.0, 36.0));
+
+                        if ui.add(envelopes_btn).on_hover_text("Assign income to category envelopes").clicked() {
+                            self.envelope_panel.open();
+                        }
+
+                        ui.add_space(8.0);
+
+                        // Accounts button
+                        let accounts_btn = egui::Button::new(
+                            egui::RichText::new("🏦 Accounts")
+                                .size(13.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(12.0))
+                        .min_size(Vec2::new(100.0, 36.0));
+
+                        if ui.add(accounts_btn).on_hover_text("Accounts, transfers and reconciliation").clicked() {
+                            self.accounts_panel.open();
+                        }
+
+                        ui.add_space(8.0);
+
+                        // Debts button
+                        let debts_btn = egui::Button::new(
+                            egui::RichText::new("💳 Debts")
+                                .size(13.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(12.0))
+                        .min_size(Vec2::new(90.0, 36.0));
+
+                        if ui.add(debts_btn).on_hover_text("Track debts and loans").clicked() {
+                            self.debts_panel.open();
+                        }
+
+                        ui.add_space(8.0);
+
+                        // Savings goals button
+                        let goals_btn = egui::Button::new(
+                            egui::RichText::new("🎯 Goals")
+                                .size(13.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(12.0))
+                        .min_size(Vec2::new(90.0, 36.0));
+
+                        if ui.add(goals_btn).on_hover_text("Track savings goals").clicked() {
+                            self.goals_panel.open();
+                        }
+
+                        ui.add_space(8.0);
+
+                        // Rules button
+                        let rules_btn = egui::Button::new(
+                            egui::RichText::new("⚑ Rules")
+                                .size(13.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(12.0))
+                        .min_size(Vec2::new(90.0, 36.0));
+
+                        if ui.add(rules_btn).on_hover_text("Auto-categorization rules").clicked() {
+                            self.rules_panel.open();
+                        }
+
+                        ui.add_space(8.0);
+
+                        // Payees button
+                        let payees_btn = egui::Button::new(
+                            egui::RichText::new("🏪 Payees")
+                                .size(13.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(12.0))
+                        .min_size(Vec2::new(90.0, 36.0));
+
+                        if ui.add(payees_btn).on_hover_text("Payee directory and spending by payee").clicked() {
+                            self.open_payees();
+                        }
+
+                        ui.add_space(8.0);
+
                         // Calculator button (leftmost)
                         let calc_btn = egui::Button::new(
                             egui::RichText::new("Calculator")
@@ -622,7 +1752,8 @@ impl eframe::App for BudgetApp {
                         .rounding(Rounding::same(12.0))
                         .min_size(Vec2::new(100.0, 36.0));
 
-                        if ui.add(calc_btn).on_hover_text("Keyboard shortcut: C").clicked() {
+                        let hint = format!("Keyboard shortcut: {}", self.shortcut_label(ShortcutAction::OpenCalculator));
+                        if ui.add(calc_btn).on_hover_text(hint).clicked() {
                             self.calculator.open();
                         }
                     });
@@ -656,8 +1787,9 @@ impl eframe::App for BudgetApp {
                                 offset: [0.0, 4.0].into(),
                             })
                             .show(ui, |ui| {
-                                if render_dashboard(ui, &self.budget) {
-                                    self.income_form.open(self.budget.income);
+                                let income_shortcut = self.shortcut_label(ShortcutAction::EditIncome);
+                                if render_dashboard(ui, &self.budget, &forecast, &income_shortcut) {
+                                    self.income_form.open(self.budget.income, &self.profile_data.scheduled_income);
                                 }
                             });
 
@@ -676,8 +1808,9 @@ impl eframe::App for BudgetApp {
                             .rounding(Rounding::same(14.0))
                             .min_size(Vec2::new(left_column_width - 8.0, 50.0));
 
-                            if ui.add(expense_btn).on_hover_text("Keyboard shortcut: E").clicked() {
-                                self.expense_form.open();
+                            let hint = format!("Keyboard shortcut: {}", self.shortcut_label(ShortcutAction::AddExpense));
+                            if ui.add(expense_btn).on_hover_text(hint).clicked() {
+                                self.open_expense_form();
                             }
                         });
 
@@ -696,7 +1829,8 @@ impl eframe::App for BudgetApp {
                             .rounding(Rounding::same(14.0))
                             .min_size(Vec2::new(left_column_width - 8.0, 50.0));
 
-                            if ui.add(template_btn).on_hover_text("Keyboard shortcut: T").clicked() {
+                            let hint = format!("Keyboard shortcut: {}", self.shortcut_label(ShortcutAction::OpenTemplates));
+                            if ui.add(template_btn).on_hover_text(hint).clicked() {
                                 self.template_manager.open();
                             }
                         });
@@ -705,11 +1839,18 @@ impl eframe::App for BudgetApp {
 
                         // Profile selector dropdown
                         ui.vertical_centered(|ui| {
+                            let hint = format!(
+                                "{}: Switch profile | {}: Manage profiles",
+                                self.shortcut_label(ShortcutAction::CycleProfile),
+                                self.shortcut_label(ShortcutAction::ManageProfiles),
+                            );
                             if let Some(action) = self.profile_selector.render(
                                 ui,
                                 &self.config.profiles,
                                 &self.current_profile_id,
                                 left_column_width - 8.0,
+                                &hint,
+                                self.preset_panel.is_dragging(),
                             ) {
                                 match action {
                                     ProfileSelectorAction::SwitchProfile(id) => {
@@ -743,47 +1884,49 @@ impl eframe::App for BudgetApp {
                             })
                             .show(ui, |ui| {
                                 // Header - always visible outside scroll area
-                                render_expenses_header(ui, self.budget.expenses.len());
+                                let tags = self.budget.all_tags();
+                                let visible_count = self.budget.expenses
+                                    .iter()
+                                    .filter(|e| self.tag_filter.as_ref().is_none_or(|tag| e.tags.contains(tag)))
+                                    .count();
+                                let mut history_action = render_expenses_header(
+                                    ui,
+                                    visible_count,
+                                    &tags,
+                                    self.tag_filter.as_deref(),
+                                    self.show_calendar,
+                                    &self.selected_expenses,
+                                );
 
                                 let scroll_height = available_height - 180.0; // Account for header and total line
 
-                                egui::ScrollArea::vertical()
-                                    .max_height(scroll_height.max(150.0))
-                                    .auto_shrink([false, false])
-                                    .show(ui, |ui| {
-                                        if let Some(action) = render_expenses(ui, &mut self.budget) {
-                                            match action {
-                                                HistoryAction::DeleteExpense(id) => {
-                                                    self.profile_data.expenses.retain(|e| e.id != id);
-                                                    self.save_profile();
-                                                    self.recompose_budget();
-                                                }
-                                                HistoryAction::ToggleExpense(id) => {
-                                                    if let Some(exp) = self.profile_data.expenses.iter_mut().find(|e| e.id == id) {
-                                                        exp.active = !exp.active;
-                                                    }
-                                                    self.save_profile();
-                                                    self.recompose_budget();
-                                                }
-                                                HistoryAction::SaveAsPreset(id) => {
-                                                    // Open preset panel with expense data pre-filled
-                                                    if let Some(expense) = self.profile_data.expenses.iter().find(|e| e.id == id) {
-                                                        let name = if expense.description.is_empty() {
-                                                            expense.category.clone()
-                                                        } else {
-                                                            expense.description.clone()
-                                                        };
-                                                        self.preset_panel.init_from_expense(
-                                                            name,
-                                                            expense.amount,
-                                                            expense.category.clone(),
-                                                            expense.description.clone(),
-                                                        );
-                                                    }
-                                                }
-                                            }
+                                if self.show_calendar {
+                                    let dragging = self.preset_panel.is_dragging();
+                                    let today = Local::now().date_naive();
+                                    match self.calendar_view.render(ui, &self.budget, today, dragging, scroll_height.max(300.0)) {
+                                        Some(CalendarAction::AddPreset(preset_id, date)) => {
+                                            self.add_expense_from_preset_on(preset_id, date);
                                         }
-                                    });
+                                        Some(CalendarAction::AddExpenseOn(date)) => {
+                                            self.open_expense_form();
+                                            self.expense_form.selected_date = date;
+                                        }
+                                        None => {}
+                                    }
+                                } else {
+                                    egui::ScrollArea::vertical()
+                                        .max_height(scroll_height.max(150.0))
+                                        .auto_shrink([false, false])
+                                        .show(ui, |ui| {
+                                            if let Some(action) = render_expenses(ui, &mut self.budget, self.tag_filter.as_deref(), &self.selected_expenses) {
+                                                history_action = Some(action);
+                                            }
+                                        });
+                                }
+
+                                if let Some(action) = history_action {
+                                    self.handle_history_action(ui.ctx(), action);
+                                }
 
                                 // Total line - always visible at bottom of card
                                 ui.add_space(12.0);
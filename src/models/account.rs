@@ -0,0 +1,168 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::budget::Budget;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AccountKind {
+    #[default]
+    Checking,
+    Savings,
+    CreditCard,
+    Cash,
+}
+
+impl AccountKind {
+    pub const ALL: [AccountKind; 4] = [
+        AccountKind::Checking,
+        AccountKind::Savings,
+        AccountKind::CreditCard,
+        AccountKind::Cash,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AccountKind::Checking => "Checking",
+            AccountKind::Savings => "Savings",
+            AccountKind::CreditCard => "Credit Card",
+            AccountKind::Cash => "Cash",
+        }
+    }
+}
+
+/// Statement balance confirmed by the user; later balances start from it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Reconciliation {
+    pub date: NaiveDate,
+    pub statement_balance: f64,
+}
+
+/// Where money is paid from. Balances are signed: a credit card that is owed
+/// money has a negative balance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Account {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default)]
+    pub kind: AccountKind,
+    pub opening_balance: f64,
+    /// Last reconciled statement, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reconciled: Option<Reconciliation>,
+}
+
+impl Account {
+    pub fn new(name: String, kind: AccountKind, opening_balance: f64) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            kind,
+            opening_balance,
+            reconciled: None,
+        }
+    }
+}
+
+/// Money moved between two accounts. Not spending, so kept apart from expenses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transfer {
+    pub id: Uuid,
+    pub from: Uuid,
+    pub to: Uuid,
+    pub amount: f64,
+    pub date: NaiveDate,
+    #[serde(default)]
+    pub note: String,
+}
+
+impl Transfer {
+    pub fn new(from: Uuid, to: Uuid, amount: f64, date: NaiveDate, note: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            from,
+            to,
+            amount,
+            date,
+            note,
+        }
+    }
+}
+
+/// A single movement on an account, with the balance after it
+#[derive(Debug, Clone)]
+pub struct AccountEntry {
+    pub date: NaiveDate,
+    pub description: String,
+    /// Positive for money in, negative for money out
+    pub amount: f64,
+    pub balance: f64,
+}
+
+impl Budget {
+    /// Expenses and transfers on an account after its last reconciliation, oldest
+    /// first, with a running balance starting from the reconciled (or opening) balance
+    pub fn account_register(&self, account: &Account) -> Vec<AccountEntry> {
+        let after = account.reconciled.map(|r| r.date);
+        let is_new = |date: NaiveDate| after.is_none_or(|after| date > after);
+
+        let mut entries: Vec<AccountEntry> = self
+            .expenses
+            .iter()
+            .filter(|e| e.active && e.account_id == Some(account.id) && is_new(e.date))
+            .map(|e| AccountEntry {
+                date: e.date,
+                description: if e.description.is_empty() { e.category.clone() } else { e.description.clone() },
+                amount: -e.amount,
+                balance: 0.0,
+            })
+            .collect();
+
+        for transfer in self.transfers.iter().filter(|t| is_new(t.date)) {
+            let (amount, other) = if transfer.from == account.id {
+                (-transfer.amount, transfer.to)
+            } else if transfer.to == account.id {
+                (transfer.amount, transfer.from)
+            } else {
+                continue;
+            };
+            let other_name = self
+                .accounts
+                .iter()
+                .find(|a| a.id == other)
+                .map(|a| a.name.as_str())
+                .unwrap_or("Unknown account");
+            let direction = if amount < 0.0 { "to" } else { "from" };
+            let mut description = format!("Transfer {} {}", direction, other_name);
+            if !transfer.note.is_empty() {
+                description = format!("{} · {}", description, transfer.note);
+            }
+            entries.push(AccountEntry { date: transfer.date, description, amount, balance: 0.0 });
+        }
+
+        entries.sort_by_key(|e| e.date);
+        let mut balance = account.reconciled.map_or(account.opening_balance, |r| r.statement_balance);
+        for entry in &mut entries {
+            balance += entry.amount;
+            entry.balance = balance;
+        }
+        entries
+    }
+
+    /// Current balance of an account
+    pub fn account_balance(&self, account: &Account) -> f64 {
+        self.account_register(account)
+            .last()
+            .map(|e| e.balance)
+            .unwrap_or_else(|| account.reconciled.map_or(account.opening_balance, |r| r.statement_balance))
+    }
+
+    /// Balance of an account at the end of `date`, for matching a statement
+    pub fn account_balance_on(&self, account: &Account, date: NaiveDate) -> f64 {
+        let start = account.reconciled.map_or(account.opening_balance, |r| r.statement_balance);
+        self.account_register(account)
+            .iter()
+            .rfind(|e| e.date <= date)
+            .map_or(start, |e| e.balance)
+    }
+}
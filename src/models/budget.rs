@@ -1,10 +1,17 @@
 use chrono::Local;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use uuid::Uuid;
 
+use super::account::{Account, Transfer};
+use super::category::{self, CategoryParents};
+use super::debt::Debt;
+use super::envelope::Envelopes;
+use super::limit::CategoryLimits;
 use super::expense::Expense;
+use super::goal::SavingsGoal;
 use super::preset::ExpensePreset;
+use super::profile::ScheduledIncome;
 use super::template::Template;
 
 /// RGB color stored as [r, g, b]
@@ -38,11 +45,29 @@ pub struct Budget {
     #[serde(default)]
     pub income: f64,
     pub expenses: Vec<Expense>,
+    #[serde(default)]
+    pub scheduled_income: Vec<ScheduledIncome>,
+    #[serde(default)]
+    pub goals: Vec<SavingsGoal>,
+    #[serde(default)]
+    pub debts: Vec<Debt>,
+    #[serde(default)]
+    pub accounts: Vec<Account>,
+    #[serde(default)]
+    pub transfers: Vec<Transfer>,
+    #[serde(default)]
+    pub envelope_mode: bool,
+    #[serde(default)]
+    pub envelopes: Envelopes,
     #[serde(default = "default_categories")]
     pub categories: Vec<String>,
     #[serde(default = "default_category_colors")]
     pub category_colors: HashMap<String, CategoryColor>,
     #[serde(default)]
+    pub category_parents: CategoryParents,
+    #[serde(default)]
+    pub category_limits: CategoryLimits,
+    #[serde(default)]
     pub templates: Vec<Template>,
     #[serde(default)]
     pub presets: Vec<ExpensePreset>,
@@ -53,8 +78,17 @@ impl Default for Budget {
         Self {
             income: 0.0,
             expenses: Vec::new(),
+            scheduled_income: Vec::new(),
+            goals: Vec::new(),
+            debts: Vec::new(),
+            accounts: Vec::new(),
+            transfers: Vec::new(),
+            envelope_mode: false,
+            envelopes: Envelopes::default(),
             categories: default_categories(),
             category_colors: default_category_colors(),
+            category_parents: CategoryParents::new(),
+            category_limits: CategoryLimits::new(),
             templates: Vec::new(),
             presets: Vec::new(),
         }
@@ -78,6 +112,49 @@ impl Budget {
         self.income - self.total_expenses()
     }
 
+    /// Spending per top-level category for active expenses, counting each split
+    /// line under its own category and rolling subcategories up into their parent.
+    /// Sorted largest first.
+    pub fn category_totals(&self) -> Vec<(String, f64)> {
+        self.totals_by(|category| Some(category::root_category(category, &self.category_parents)))
+    }
+
+    /// Spending per subcategory of `parent`, sorted largest first
+    pub fn subcategory_totals(&self, parent: &str) -> Vec<(String, f64)> {
+        self.totals_by(|category| {
+            (self.category_parents.get(category).map(String::as_str) == Some(parent)).then_some(category)
+        })
+    }
+
+    /// Sum active spending into the bucket each category maps to (None skips it)
+    fn totals_by<'a>(&'a self, bucket: impl Fn(&'a str) -> Option<&'a str>) -> Vec<(String, f64)> {
+        let mut totals: HashMap<String, f64> = HashMap::new();
+        for expense in self.expenses.iter().filter(|e| e.active) {
+            for (category, amount) in expense.category_amounts() {
+                if let Some(key) = bucket(category) {
+                    *totals.entry(key.to_string()).or_insert(0.0) += amount;
+                }
+            }
+        }
+        let mut totals: Vec<(String, f64)> = totals.into_iter().collect();
+        totals.sort_by(|a, b| b.1.total_cmp(&a.1));
+        totals
+    }
+
+    /// Amount contributed to a savings goal by active expenses
+    pub fn goal_saved(&self, goal_id: Uuid) -> f64 {
+        self.expenses
+            .iter()
+            .filter(|e| e.active && e.goal_id == Some(goal_id))
+            .map(|e| e.amount)
+            .sum()
+    }
+
+    /// All tags used by any expense, sorted
+    pub fn all_tags(&self) -> BTreeSet<String> {
+        self.expenses.iter().flat_map(|e| e.tags.iter().cloned()).collect()
+    }
+
     pub fn set_income(&mut self, amount: f64) {
         self.income = amount;
     }
@@ -107,13 +184,12 @@ impl Budget {
     pub fn remove_category(&mut self, category: &str) {
         self.categories.retain(|c| c != category);
         self.category_colors.remove(category);
+        self.category_parents.remove(category);
+        self.category_parents.retain(|_, parent| parent != category);
     }
 
     pub fn get_category_color(&self, category: &str) -> CategoryColor {
-        self.category_colors
-            .get(category)
-            .copied()
-            .unwrap_or([156, 163, 175]) // Default gray
+        category::category_color(category, &self.category_colors, &self.category_parents)
     }
 
     pub fn set_category_color(&mut self, category: &str, color: CategoryColor) {
@@ -131,9 +207,7 @@ impl Budget {
     pub fn load_template(&mut self, id: Uuid) {
         if let Some(template) = self.templates.iter().find(|t| t.id == id) {
             // Clone expenses from template, giving them new IDs (replaces existing)
-            self.expenses = template.expenses.iter().map(|e| {
-                Expense::new(e.amount, e.category.clone(), e.description.clone(), e.date)
-            }).collect();
+            self.expenses = template.expenses.iter().map(Expense::copy_as_new).collect();
         }
     }
 
@@ -142,8 +216,7 @@ impl Budget {
         if let Some(template) = self.templates.iter().find(|t| t.id == id) {
             // Clone expenses from template with new IDs and add to existing
             for e in &template.expenses {
-                let expense = Expense::new(e.amount, e.category.clone(), e.description.clone(), e.date);
-                self.expenses.push(expense);
+                self.expenses.push(e.copy_as_new());
             }
         }
     }
@@ -0,0 +1,281 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+use super::budget::CategoryColor;
+use super::expense::Expense;
+use super::limit::CategoryLimit;
+use super::preset::ExpensePreset;
+use super::profile::ProfileData;
+use super::shared::SharedData;
+use super::template::Template;
+
+/// Bumped when the bundle layout changes in a way older versions can't read
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// File extension for exported profile bundles (the contents are JSON)
+pub const BUNDLE_EXTENSION: &str = "budgetbot";
+
+/// A shared category as carried in a bundle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleCategory {
+    pub name: String,
+    pub color: CategoryColor,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<CategoryLimit>,
+}
+
+/// A profile together with the shared categories, presets and templates it
+/// uses, so it can be opened on another machine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileBundle {
+    pub version: u32,
+    pub name: String,
+    pub exported_at: DateTime<Utc>,
+    pub profile: ProfileData,
+    pub categories: Vec<BundleCategory>,
+    #[serde(default)]
+    pub presets: Vec<ExpensePreset>,
+    #[serde(default)]
+    pub templates: Vec<Template>,
+}
+
+/// How to bring in a bundle category whose name already exists locally
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConflictResolution {
+    /// Use the local category as it is
+    KeepLocal,
+    /// Use the local category, recolored to the bundle's color
+    UseBundleColor,
+    /// Import as a separate category under a new name
+    Rename(String),
+}
+
+/// A bundle category whose name matches a local one (ignoring case) but whose color differs
+#[derive(Debug, Clone)]
+pub struct CategoryConflict {
+    /// Name in the bundle
+    pub name: String,
+    pub local_name: String,
+    pub local_color: CategoryColor,
+    pub bundle_color: CategoryColor,
+    pub resolution: ConflictResolution,
+}
+
+impl ProfileBundle {
+    /// Bundle a profile with the categories it uses and, if asked, the shared presets
+    /// and templates its expenses came from. Receipts are files on this machine and
+    /// are left out.
+    pub fn new(
+        name: String,
+        profile: &ProfileData,
+        shared: &SharedData,
+        include_presets: bool,
+        include_templates: bool,
+    ) -> Self {
+        let mut profile = profile.clone();
+        for expense in &mut profile.expenses {
+            expense.attachments.clear();
+        }
+        let presets: Vec<ExpensePreset> = shared
+            .presets
+            .iter()
+            .filter(|_| include_presets)
+            .filter(|p| !profile.preset_overrides.hidden.contains(&p.id))
+            .filter(|p| profile.expenses.iter().any(|e| p.matches(e)))
+            .cloned()
+            .collect();
+        let mut templates: Vec<Template> = shared
+            .templates
+            .iter()
+            .filter(|_| include_templates)
+            .filter(|t| uses_template(&profile, t))
+            .cloned()
+            .collect();
+        for expense in templates.iter_mut().flat_map(|t| t.expenses.iter_mut()) {
+            expense.attachments.clear();
+        }
+
+        // Every category named anywhere in the bundle, plus the parents of subcategories
+        let mut used: BTreeSet<&str> = profile
+            .expenses
+            .iter()
+            .chain(templates.iter().flat_map(|t| t.expenses.iter()))
+            .flat_map(|e| e.category_amounts().into_iter().map(|(c, _)| c))
+            .chain(presets.iter().map(|p| p.category.as_str()))
+            .chain(profile.envelopes.assigned.values().flat_map(|p| p.keys().map(String::as_str)))
+            .collect();
+        let parents: Vec<&str> = used
+            .iter()
+            .filter_map(|c| shared.category_parents.get(*c).map(String::as_str))
+            .collect();
+        used.extend(parents);
+
+        let categories = shared
+            .categories
+            .iter()
+            .filter(|c| used.contains(c.as_str()))
+            .map(|c| BundleCategory {
+                name: c.clone(),
+                color: shared.get_category_color(c),
+                parent: shared.category_parents.get(c).cloned(),
+                limit: shared.category_limits.get(c).cloned(),
+            })
+            .collect();
+
+        Self {
+            version: BUNDLE_VERSION,
+            name,
+            exported_at: Utc::now(),
+            profile,
+            categories,
+            presets,
+            templates,
+        }
+    }
+
+    /// Bundle categories that exist locally with a different color, defaulting to the local one
+    pub fn conflicts(&self, shared: &SharedData) -> Vec<CategoryConflict> {
+        self.categories
+            .iter()
+            .filter_map(|category| {
+                let local_name = find_local(shared, &category.name)?;
+                let local_color = shared.get_category_color(local_name);
+                (local_color != category.color).then(|| CategoryConflict {
+                    name: category.name.clone(),
+                    local_name: local_name.to_string(),
+                    local_color,
+                    bundle_color: category.color,
+                    resolution: ConflictResolution::KeepLocal,
+                })
+            })
+            .collect()
+    }
+
+    /// Add the bundle's categories, presets and templates to `shared`, resolving
+    /// conflicts as chosen, and return the profile with category names rewritten
+    /// to match. Presets and templates already present (same id) are skipped.
+    /// Fails without changing anything if a rename lands on an existing category.
+    pub fn import_into(self, shared: &mut SharedData, conflicts: &[CategoryConflict]) -> Result<ProfileData, String> {
+        for conflict in conflicts {
+            if let ConflictResolution::Rename(new_name) = &conflict.resolution
+                && let Some(local_name) = find_local(shared, new_name.trim())
+            {
+                return Err(format!(
+                    "Can't import \"{}\" as \"{}\": that category already exists",
+                    conflict.name, local_name
+                ));
+            }
+        }
+
+
+        // Final local name for each bundle category
+        let mut renames: HashMap<String, String> = HashMap::new();
+        let mut new_categories: Vec<BundleCategory> = Vec::new();
+        for category in self.categories {
+            let conflict = conflicts.iter().find(|c| c.name == category.name);
+            match (find_local(shared, &category.name).map(str::to_string), conflict.map(|c| &c.resolution)) {
+                (_, Some(ConflictResolution::Rename(new_name))) if !new_name.trim().is_empty() => {
+                    let new_name = new_name.trim().to_string();
+                    renames.insert(category.name.clone(), new_name.clone());
+                    new_categories.push(BundleCategory { name: new_name, ..category });
+                }
+                (Some(local_name), Some(ConflictResolution::UseBundleColor)) => {
+                    shared.set_category_color(&local_name, category.color);
+                    renames.insert(category.name, local_name);
+                }
+                (Some(local_name), _) => {
+                    renames.insert(category.name, local_name);
+                }
+                (None, _) => new_categories.push(category),
+            }
+        }
+
+        // Top-level categories first so subcategories have a parent to join
+        new_categories.sort_by_key(|c| c.parent.is_some());
+        for category in new_categories {
+            let parent = category
+                .parent
+                .as_ref()
+                .map(|p| renames.get(p).cloned().unwrap_or_else(|| p.clone()));
+            match parent {
+                Some(parent) if shared.categories.contains(&parent) => {
+                    shared.add_subcategory(category.name.clone(), &parent);
+                    if shared.get_category_color(&category.name) != category.color {
+                        shared.set_category_color(&category.name, category.color);
+                    }
+                }
+                _ => shared.add_category_with_color(category.name.clone(), category.color),
+            }
+            if !shared.category_limits.contains_key(&category.name) {
+                shared.set_category_limit(&category.name, category.limit);
+            }
+        }
+
+        let mut profile = self.profile;
+        renames.retain(|from, to| from != to);
+        for expense in &mut profile.expenses {
+            rename_expense_categories(expense, &renames);
+        }
+        for period in profile.envelopes.assigned.values_mut() {
+            *period = period
+                .drain()
+                .map(|(category, amount)| (renames.get(&category).cloned().unwrap_or(category), amount))
+                .collect();
+        }
+
+        for mut preset in self.presets {
+            if shared.presets.iter().any(|p| p.id == preset.id) {
+                continue;
+            }
+            if let Some(name) = renames.get(&preset.category) {
+                preset.category = name.clone();
+            }
+            shared.add_preset(preset);
+        }
+        for mut template in self.templates {
+            if shared.templates.iter().any(|t| t.id == template.id) {
+                continue;
+            }
+            for expense in &mut template.expenses {
+                rename_expense_categories(expense, &renames);
+            }
+            shared.add_template(template);
+        }
+
+        Ok(profile)
+    }
+}
+
+/// True if the profile has an expense that looks like it came from the template.
+/// Amounts aren't compared since template amounts can be formulas.
+fn uses_template(profile: &ProfileData, template: &Template) -> bool {
+    template.expenses.iter().any(|t| {
+        profile
+            .expenses
+            .iter()
+            .any(|e| e.category == t.category && e.description == t.description)
+    })
+}
+
+/// Local category with this name, ignoring case
+fn find_local<'a>(shared: &'a SharedData, name: &str) -> Option<&'a str> {
+    shared
+        .categories
+        .iter()
+        .find(|c| c.eq_ignore_ascii_case(name))
+        .map(String::as_str)
+}
+
+fn rename_expense_categories(expense: &mut Expense, renames: &HashMap<String, String>) {
+    if let Some(name) = renames.get(&expense.category) {
+        expense.category = name.clone();
+    }
+    for line in &mut expense.splits {
+        if let Some(name) = renames.get(&line.category) {
+            line.category = name.clone();
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use super::budget::CategoryColor;
+
+/// Maps a subcategory to its parent category (Rent -> Housing).
+/// Only one level of nesting is allowed, so a parent is always top-level.
+pub type CategoryParents = HashMap<String, String>;
+
+/// Fallback color for categories without one
+pub const DEFAULT_CATEGORY_COLOR: CategoryColor = [156, 163, 175]; // Gray
+
+/// Top-level category that spending in `category` rolls up to
+pub fn root_category<'a>(category: &'a str, parents: &'a CategoryParents) -> &'a str {
+    parents.get(category).map(String::as_str).unwrap_or(category)
+}
+
+/// Categories in tree order: each top-level category followed by its subcategories,
+/// otherwise keeping the original order
+pub fn tree_order(categories: &[String], parents: &CategoryParents) -> Vec<String> {
+    let mut ordered = Vec::with_capacity(categories.len());
+    for category in categories.iter().filter(|c| !parents.contains_key(*c)) {
+        ordered.push(category.clone());
+        ordered.extend(
+            categories
+                .iter()
+                .filter(|c| parents.get(*c) == Some(category))
+                .cloned(),
+        );
+    }
+    // Subcategories whose parent no longer exists are shown at the end
+    ordered.extend(
+        categories
+            .iter()
+            .filter(|c| parents.get(*c).is_some_and(|p| !categories.contains(p)))
+            .cloned(),
+    );
+    ordered
+}
+
+/// Color for a category: its own if set, otherwise inherited from its parent
+pub fn category_color(
+    category: &str,
+    colors: &HashMap<String, CategoryColor>,
+    parents: &CategoryParents,
+) -> CategoryColor {
+    colors
+        .get(category)
+        .or_else(|| parents.get(category).and_then(|p| colors.get(p)))
+        .copied()
+        .unwrap_or(DEFAULT_CATEGORY_COLOR)
+}
+
+/// Color of every category with inheritance applied
+pub fn resolved_colors(
+    categories: &[String],
+    colors: &HashMap<String, CategoryColor>,
+    parents: &CategoryParents,
+) -> HashMap<String, CategoryColor> {
+    categories
+        .iter()
+        .map(|c| (c.clone(), category_color(c, colors, parents)))
+        .collect()
+}
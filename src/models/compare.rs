@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use super::budget::Budget;
+
+/// Income, spending and balance of one profile, for side-by-side views
+#[derive(Debug, Clone)]
+pub struct ProfileSummary {
+    pub name: String,
+    pub income: f64,
+    pub expenses: f64,
+    pub balance: f64,
+    /// Active spending per top-level category, largest first
+    pub categories: Vec<(String, f64)>,
+}
+
+impl ProfileSummary {
+    pub fn new(name: String, budget: &Budget) -> Self {
+        Self {
+            name,
+            income: budget.total_income(),
+            expenses: budget.total_expenses(),
+            balance: budget.remaining_balance(),
+            categories: budget.category_totals(),
+        }
+    }
+
+    pub fn category_total(&self, category: &str) -> f64 {
+        self.categories
+            .iter()
+            .find(|(c, _)| c == category)
+            .map_or(0.0, |(_, amount)| *amount)
+    }
+}
+
+/// Every category any of the profiles spent on, largest combined spending first
+pub fn category_union(summaries: &[ProfileSummary]) -> Vec<String> {
+    let mut combined: HashMap<&str, f64> = HashMap::new();
+    for summary in summaries {
+        for (category, amount) in &summary.categories {
+            *combined.entry(category.as_str()).or_insert(0.0) += amount;
+        }
+    }
+    let mut categories: Vec<(&str, f64)> = combined.into_iter().collect();
+    categories.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+    categories.into_iter().map(|(c, _)| c.to_string()).collect()
+}
+
+/// Several profiles added together, e.g. one per household member
+pub fn combine_summaries(name: String, summaries: &[ProfileSummary]) -> ProfileSummary {
+    let categories = category_union(summaries)
+        .into_iter()
+        .map(|category| {
+            let total = summaries.iter().map(|s| s.category_total(&category)).sum();
+            (category, total)
+        })
+        .collect();
+
+    ProfileSummary {
+        name,
+        income: summaries.iter().map(|s| s.income).sum(),
+        expenses: summaries.iter().map(|s| s.expenses).sum(),
+        balance: summaries.iter().map(|s| s.balance).sum(),
+        categories,
+    }
+}
@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::keymap::Keymap;
+
 /// Application configuration including profile management
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
@@ -8,6 +10,12 @@ pub struct AppConfig {
     pub active_profile_id: String,
     /// List of all profile metadata
     pub profiles: Vec<ProfileMeta>,
+    /// User-configured keyboard shortcuts
+    #[serde(default)]
+    pub keymap: Keymap,
+    /// Profiles added together in the household view
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub household_profile_ids: Vec<String>,
 }
 
 /// Metadata for a budget profile
@@ -19,6 +27,9 @@ pub struct ProfileMeta {
     pub name: String,
     /// When the profile was created
     pub created_at: DateTime<Utc>,
+    /// When the profile was archived; archived profiles are hidden and read-only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 impl Default for AppConfig {
@@ -29,7 +40,10 @@ impl Default for AppConfig {
                 id: "main".to_string(),
                 name: "Main Budget".to_string(),
                 created_at: Utc::now(),
+                archived_at: None,
             }],
+            keymap: Keymap::default(),
+            household_profile_ids: Vec::new(),
         }
     }
 }
@@ -40,8 +54,13 @@ impl ProfileMeta {
             id,
             name,
             created_at: Utc::now(),
+            archived_at: None,
         }
     }
+
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
 }
 
 impl AppConfig {
@@ -65,6 +84,32 @@ impl AppConfig {
         self.profiles.len() < initial_len
     }
 
+    /// Profiles that aren't archived, in list order
+    pub fn visible_profiles(&self) -> impl Iterator<Item = &ProfileMeta> {
+        self.profiles.iter().filter(|p| !p.is_archived())
+    }
+
+    /// Archive a profile (cannot archive the active profile)
+    pub fn archive_profile(&mut self, id: &str) -> bool {
+        if id == self.active_profile_id {
+            return false;
+        }
+        match self.profiles.iter_mut().find(|p| p.id == id) {
+            Some(profile) if !profile.is_archived() => {
+                profile.archived_at = Some(Utc::now());
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Bring an archived profile back into the selector
+    pub fn restore_profile(&mut self, id: &str) {
+        if let Some(profile) = self.profiles.iter_mut().find(|p| p.id == id) {
+            profile.archived_at = None;
+        }
+    }
+
     /// Rename a profile
     pub fn rename_profile(&mut self, id: &str, new_name: String) {
         if let Some(profile) = self.profiles.iter_mut().find(|p| p.id == id) {
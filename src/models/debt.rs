@@ -0,0 +1,236 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::budget::Budget;
+
+/// Category used for expenses that record a debt payment
+pub const DEBT_CATEGORY: &str = "Debt Payments";
+
+/// Give up on schedules that would run longer than this (50 years)
+const MAX_SCHEDULE_MONTHS: usize = 600;
+
+/// How often interest is added to the balance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Compounding {
+    #[default]
+    Monthly,
+    /// Typical for credit cards
+    Daily,
+}
+
+impl Compounding {
+    pub const ALL: [Compounding; 2] = [Compounding::Monthly, Compounding::Daily];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Compounding::Monthly => "Monthly",
+            Compounding::Daily => "Daily",
+        }
+    }
+}
+
+/// A mortgage, loan or card balance. Payments are expenses linked through
+/// `Expense::debt_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Debt {
+    pub id: Uuid,
+    pub name: String,
+    /// Balance owed on `start_date`
+    pub principal: f64,
+    /// Annual percentage rate, e.g. 19.99
+    pub apr: f64,
+    pub minimum_payment: f64,
+    #[serde(default)]
+    pub compounding: Compounding,
+    pub start_date: NaiveDate,
+}
+
+/// One month of an amortization schedule
+#[derive(Debug, Clone)]
+pub struct AmortizationRow {
+    pub month: u32,
+    pub payment: f64,
+    pub interest: f64,
+    pub principal: f64,
+    pub balance: f64,
+}
+
+/// Order in which extra money goes to debts once the minimums are paid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayoffStrategy {
+    /// Highest APR first - least interest overall
+    Avalanche,
+    /// Smallest balance first - fastest early wins
+    Snowball,
+}
+
+impl PayoffStrategy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PayoffStrategy::Avalanche => "Avalanche",
+            PayoffStrategy::Snowball => "Snowball",
+        }
+    }
+}
+
+/// Result of simulating a payoff strategy across all debts
+#[derive(Debug, Clone)]
+pub struct PayoffPlan {
+    pub strategy: PayoffStrategy,
+    /// Months until everything is paid, None if it never is
+    pub months: Option<u32>,
+    pub total_interest: f64,
+    /// Debts in the order they get paid off
+    pub order: Vec<Uuid>,
+}
+
+impl Debt {
+    pub fn new(
+        name: String,
+        principal: f64,
+        apr: f64,
+        minimum_payment: f64,
+        compounding: Compounding,
+        start_date: NaiveDate,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            principal,
+            apr,
+            minimum_payment,
+            compounding,
+            start_date,
+        }
+    }
+
+    /// Effective interest rate per month
+    pub fn monthly_rate(&self) -> f64 {
+        let annual = self.apr / 100.0;
+        match self.compounding {
+            Compounding::Monthly => annual / 12.0,
+            Compounding::Daily => (1.0 + annual / 365.0).powf(365.0 / 12.0) - 1.0,
+        }
+    }
+
+    /// Balance today: interest is added each month after `start_date`, and
+    /// linked payments are taken off in the month they were made.
+    pub fn current_balance(&self, payments: &[(NaiveDate, f64)], today: NaiveDate) -> f64 {
+        let rate = self.monthly_rate();
+        let month_index = |date: NaiveDate| date.year() * 12 + date.month0() as i32;
+        let start = month_index(self.start_date);
+
+        let mut balance = self.principal;
+        for month in start..=month_index(today) {
+            if month > start {
+                balance += balance * rate;
+            }
+            balance -= payments
+                .iter()
+                .filter(|(date, _)| month_index(*date) == month && *date >= self.start_date)
+                .map(|(_, amount)| amount)
+                .sum::<f64>();
+            balance = balance.max(0.0);
+        }
+        balance
+    }
+
+    /// Month-by-month schedule paying `payment` from `balance` until it reaches zero.
+    /// Empty if the payment doesn't cover the interest.
+    pub fn amortization_schedule(&self, balance: f64, payment: f64) -> Vec<AmortizationRow> {
+        let rate = self.monthly_rate();
+        let mut rows = Vec::new();
+        if payment <= balance * rate {
+            return rows;
+        }
+
+        let mut balance = balance;
+        let mut month = 1;
+        while balance > 0.005 && rows.len() < MAX_SCHEDULE_MONTHS {
+            let interest = balance * rate;
+            let payment = payment.min(balance + interest);
+            let principal = payment - interest;
+            balance -= principal;
+            rows.push(AmortizationRow {
+                month,
+                payment,
+                interest,
+                principal,
+                balance: balance.max(0.0),
+            });
+            month += 1;
+        }
+        rows
+    }
+}
+
+/// Simulate paying all debts: every month each gets its minimum, and `extra` plus
+/// minimums freed up by paid-off debts go to the target chosen by `strategy`.
+pub fn simulate_payoff(debts: &[(&Debt, f64)], extra: f64, strategy: PayoffStrategy) -> PayoffPlan {
+    let mut balances: Vec<f64> = debts.iter().map(|(_, balance)| *balance).collect();
+    let budget: f64 = debts.iter().map(|(d, _)| d.minimum_payment).sum::<f64>() + extra.max(0.0);
+    let mut total_interest = 0.0;
+    let mut order = Vec::new();
+    let mut months = 0;
+
+    while balances.iter().any(|b| *b > 0.005) {
+        if months as usize >= MAX_SCHEDULE_MONTHS {
+            return PayoffPlan { strategy, months: None, total_interest, order };
+        }
+        months += 1;
+
+        for (balance, (debt, _)) in balances.iter_mut().zip(debts) {
+            let interest = *balance * debt.monthly_rate();
+            total_interest += interest;
+            *balance += interest;
+        }
+
+        // Minimums first
+        let mut available = budget;
+        for (balance, (debt, _)) in balances.iter_mut().zip(debts) {
+            let payment = debt.minimum_payment.min(*balance);
+            *balance -= payment;
+            available -= payment;
+        }
+
+        // Then everything left to the target, moving on as each is cleared
+        while available > 0.005 {
+            let target = (0..balances.len())
+                .filter(|i| balances[*i] > 0.005)
+                .min_by(|a, b| match strategy {
+                    PayoffStrategy::Avalanche => debts[*b].0.apr.total_cmp(&debts[*a].0.apr),
+                    PayoffStrategy::Snowball => balances[*a].total_cmp(&balances[*b]),
+                });
+            let Some(target) = target else { break };
+            let payment = available.min(balances[target]);
+            balances[target] -= payment;
+            available -= payment;
+        }
+
+        for (i, balance) in balances.iter().enumerate() {
+            let id = debts[i].0.id;
+            if *balance <= 0.005 && !order.contains(&id) {
+                order.push(id);
+            }
+        }
+    }
+
+    PayoffPlan { strategy, months: Some(months), total_interest, order }
+}
+
+impl Budget {
+    /// Dates and amounts of active expenses linked to a debt
+    pub fn debt_payments(&self, debt_id: Uuid) -> Vec<(NaiveDate, f64)> {
+        self.expenses
+            .iter()
+            .filter(|e| e.active && e.debt_id == Some(debt_id))
+            .map(|e| (e.date, e.amount))
+            .collect()
+    }
+
+    /// Current balance of a debt after its linked payments
+    pub fn debt_balance(&self, debt: &Debt, today: NaiveDate) -> f64 {
+        debt.current_balance(&self.debt_payments(debt.id), today)
+    }
+}
@@ -0,0 +1,141 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use super::budget::Budget;
+use super::category;
+
+/// Budget period key ("2026-03") for the month containing `date`
+pub fn period_key(date: NaiveDate) -> String {
+    format!("{:04}-{:02}", date.year(), date.month())
+}
+
+/// Money assigned to each top-level category, per monthly period.
+/// Used when a profile is in envelope (zero-based) mode.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Envelopes {
+    #[serde(default)]
+    pub assigned: BTreeMap<String, HashMap<String, f64>>,
+}
+
+impl Envelopes {
+    pub fn is_empty(&self) -> bool {
+        self.assigned.values().all(HashMap::is_empty)
+    }
+
+    pub fn assigned(&self, period: &str, category: &str) -> f64 {
+        self.assigned
+            .get(period)
+            .and_then(|p| p.get(category))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn assign(&mut self, period: &str, category: &str, amount: f64) {
+        let entry = self.assigned.entry(period.to_string()).or_default();
+        if amount.abs() < 0.005 {
+            entry.remove(category);
+        } else {
+            entry.insert(category.to_string(), amount);
+        }
+    }
+
+    /// Move money between envelopes within a period (e.g. to cover overspending)
+    pub fn move_amount(&mut self, period: &str, from: &str, to: &str, amount: f64) {
+        let from_amount = self.assigned(period, from) - amount;
+        let to_amount = self.assigned(period, to) + amount;
+        self.assign(period, from, from_amount);
+        self.assign(period, to, to_amount);
+    }
+
+    /// Add another set of assignments to these, period by period and envelope by envelope
+    pub fn add_all(&mut self, other: Envelopes) {
+        for (period, amounts) in other.assigned {
+            for (category, amount) in amounts {
+                let total = self.assigned(&period, &category) + amount;
+                self.assign(&period, &category, total);
+            }
+        }
+    }
+
+    /// Total assigned across all envelopes in a period
+    pub fn total_assigned(&self, period: &str) -> f64 {
+        self.assigned.get(period).map_or(0.0, |p| p.values().sum())
+    }
+}
+
+/// One envelope's position in a period
+#[derive(Debug, Clone)]
+pub struct EnvelopeRow {
+    pub category: String,
+    /// Left over (or overspent, if negative) from earlier periods
+    pub rollover: f64,
+    pub assigned: f64,
+    pub spent: f64,
+    /// What can still be spent: rollover + assigned - spent
+    pub available: f64,
+}
+
+impl Budget {
+    /// Spending per top-level category in a period
+    fn period_spending(&self, period: &str) -> HashMap<String, f64> {
+        let mut totals: HashMap<String, f64> = HashMap::new();
+        for expense in self.expenses.iter().filter(|e| e.active && period_key(e.date) == period) {
+            for (category, amount) in expense.category_amounts() {
+                let root = category::root_category(category, &self.category_parents);
+                *totals.entry(root.to_string()).or_insert(0.0) += amount;
+            }
+        }
+        totals
+    }
+
+    /// Envelope for every top-level category, with leftovers from earlier periods
+    /// rolled over into `period`. Rollover starts from the first period anything
+    /// was assigned, so spending from before envelope mode doesn't count.
+    pub fn envelope_rows(&self, period: &str) -> Vec<EnvelopeRow> {
+        let first = self.envelopes.assigned.keys().next().cloned().unwrap_or_else(|| period.to_string());
+        let mut past_periods: Vec<String> = self
+            .expenses
+            .iter()
+            .map(|e| period_key(e.date))
+            .chain(self.envelopes.assigned.keys().cloned())
+            .filter(|p| p.as_str() >= first.as_str() && p.as_str() < period)
+            .collect();
+        past_periods.sort();
+        past_periods.dedup();
+
+        let past_spending: Vec<(String, HashMap<String, f64>)> = past_periods
+            .into_iter()
+            .map(|p| {
+                let spending = self.period_spending(&p);
+                (p, spending)
+            })
+            .collect();
+        let spending = self.period_spending(period);
+
+        self.categories
+            .iter()
+            .filter(|c| !self.category_parents.contains_key(*c))
+            .map(|category| {
+                let rollover: f64 = past_spending
+                    .iter()
+                    .map(|(p, spent)| self.envelopes.assigned(p, category) - spent.get(category).copied().unwrap_or(0.0))
+                    .sum();
+                let assigned = self.envelopes.assigned(period, category);
+                let spent = spending.get(category).copied().unwrap_or(0.0);
+                EnvelopeRow {
+                    category: category.clone(),
+                    rollover,
+                    assigned,
+                    spent,
+                    available: rollover + assigned - spent,
+                }
+            })
+            .collect()
+    }
+
+    /// Income not yet given to an envelope this period. Zero means every dollar has a job.
+    pub fn to_be_assigned(&self, period: &str) -> f64 {
+        self.income - self.envelopes.total_assigned(period)
+    }
+}
@@ -1,22 +1,99 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use uuid::Uuid;
 
+/// One category/amount line of a split expense
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SplitLine {
+    pub category: String,
+    pub amount: f64,
+}
+
+impl SplitLine {
+    pub fn new(category: String, amount: f64) -> Self {
+        Self { category, amount }
+    }
+}
+
+/// A file (receipt, invoice) attached to an expense. The file itself lives in the
+/// content-addressed attachments folder, so identical files are stored once.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// SHA-256 of the file contents (hex)
+    pub hash: String,
+    /// Lowercase extension without the dot, e.g. "pdf" (may be empty)
+    pub extension: String,
+    /// Original file name, for display
+    pub file_name: String,
+}
+
+impl Attachment {
+    /// Name of the stored file inside the attachments folder
+    pub fn stored_name(&self) -> String {
+        if self.extension.is_empty() {
+            self.hash.clone()
+        } else {
+            format!("{}.{}", self.hash, self.extension)
+        }
+    }
+
+    /// Whether a thumbnail can be shown for this file
+    pub fn is_image(&self) -> bool {
+        matches!(self.extension.as_str(), "png" | "jpg" | "jpeg")
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Expense {
     pub id: Uuid,
     pub amount: f64,
     pub category: String,
     pub description: String,
+    /// Who was paid (merchant, landlord, ...), kept apart from the free-form description
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub payee: String,
     pub date: NaiveDate,
     #[serde(default = "default_active")]
     pub active: bool,
+    /// Category breakdown for split expenses (empty for single-category expenses).
+    /// Lines always sum to `amount`; `category` holds the first line's category.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub splits: Vec<SplitLine>,
+    /// Cross-cutting labels such as "vacation-2026" or "reimbursable"
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub tags: BTreeSet<String>,
+    /// Receipts and other supporting files
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    /// Savings goal this expense contributes to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub goal_id: Option<Uuid>,
+    /// Debt this expense is a payment towards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debt_id: Option<Uuid>,
+    /// Account the expense was paid from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<Uuid>,
 }
 
 fn default_active() -> bool {
     true
 }
 
+/// Normalize user-entered tag text: trimmed, lowercase, spaces become dashes.
+/// Returns None for empty input.
+pub fn normalize_tag(input: &str) -> Option<String> {
+    let tag = input
+        .trim()
+        .trim_start_matches('#')
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-");
+    (!tag.is_empty()).then_some(tag)
+}
+
 impl Expense {
     pub fn new(amount: f64, category: String, description: String, date: NaiveDate) -> Self {
         Self {
@@ -24,8 +101,88 @@ impl Expense {
             amount,
             category,
             description,
+            payee: String::new(),
             date,
             active: true,
+            splits: Vec::new(),
+            tags: BTreeSet::new(),
+            attachments: Vec::new(),
+            goal_id: None,
+            debt_id: None,
+            account_id: None,
         }
     }
+
+    pub fn with_payee(mut self, payee: String) -> Self {
+        self.payee = payee;
+        self
+    }
+
+    pub fn with_tags(mut self, tags: BTreeSet<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.attachments = attachments;
+        self
+    }
+
+    pub fn with_goal(mut self, goal_id: Uuid) -> Self {
+        self.goal_id = Some(goal_id);
+        self
+    }
+
+    pub fn with_debt(mut self, debt_id: Uuid) -> Self {
+        self.debt_id = Some(debt_id);
+        self
+    }
+
+    pub fn with_account(mut self, account_id: Option<Uuid>) -> Self {
+        self.account_id = account_id;
+        self
+    }
+
+    /// Turn this into a split expense. The total becomes the sum of the lines.
+    pub fn with_splits(mut self, splits: Vec<SplitLine>) -> Self {
+        if let Some(first) = splits.first() {
+            self.category = first.category.clone();
+            self.amount = splits.iter().map(|s| s.amount).sum();
+        }
+        self.splits = splits;
+        self
+    }
+
+    pub fn is_split(&self) -> bool {
+        !self.splits.is_empty()
+    }
+
+    /// Amount per category, counting each split line separately
+    pub fn category_amounts(&self) -> Vec<(&str, f64)> {
+        if self.splits.is_empty() {
+            vec![(self.category.as_str(), self.amount)]
+        } else {
+            self.splits.iter().map(|s| (s.category.as_str(), s.amount)).collect()
+        }
+    }
+
+    /// Clone this expense with a fresh ID, marked active (used when copying template expenses)
+    pub fn copy_as_new(&self) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            active: true,
+            ..self.clone()
+        }
+    }
+
+    /// True if `other` looks like the same purchase entered twice: same date,
+    /// amount, category, description and payee. Two coffees on the same day look
+    /// alike too, so treat this as a hint rather than proof.
+    pub fn is_duplicate_of(&self, other: &Expense) -> bool {
+        self.date == other.date
+            && (self.amount - other.amount).abs() < 0.005
+            && self.category == other.category
+            && self.description.trim().eq_ignore_ascii_case(other.description.trim())
+            && self.payee.trim().eq_ignore_ascii_case(other.payee.trim())
+    }
 }
@@ -0,0 +1,176 @@
+use chrono::{Datelike, NaiveDate};
+
+use super::budget::Budget;
+use super::expense::Expense;
+use super::preset::ExpensePreset;
+
+/// Projection of the balance from today to the end of the month
+#[derive(Debug, Clone)]
+pub struct Forecast {
+    pub period_end: NaiveDate,
+    /// Average day-to-day spending this month, excluding recurring presets
+    pub daily_pace: f64,
+    /// Recurring presets due after today
+    pub upcoming_recurring: f64,
+    /// Scheduled income arriving after today
+    pub upcoming_income: f64,
+    /// Balance expected at the end of the month if the pace holds
+    pub projected_balance: f64,
+    /// First day the balance is expected to go negative, if it does this month
+    pub run_out_date: Option<NaiveDate>,
+    /// What can be spent per remaining day (including today) and still cover
+    /// the upcoming recurring expenses
+    pub safe_per_day: f64,
+}
+
+/// Last day of the month containing `date`
+pub fn end_of_month(date: NaiveDate) -> NaiveDate {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|d| d.pred_opt())
+        .unwrap_or(date)
+}
+
+impl Budget {
+    /// Project the balance to the end of `today`'s month from the spending pace so far,
+    /// presets with a default day (treated as recurring) and scheduled income.
+    /// Scheduled income whose day has come is counted as received.
+    pub fn forecast(&self, today: NaiveDate) -> Forecast {
+        let period_end = end_of_month(today);
+        let last_day = period_end.day();
+        // Days past the end of a short month fall on its last day
+        let due_day = |day: u32| day.min(last_day);
+
+        let recurring: Vec<_> = self.presets.iter().filter(|p| p.default_day.is_some()).collect();
+
+        // Pace from this month's expenses so far. Expenses that look like a recurring
+        // preset are left out so they aren't counted twice.
+        let is_recurring = |e: &Expense| recurring.iter().any(|p| p.matches(e));
+        let spent_so_far: f64 = self
+            .expenses
+            .iter()
+            .filter(|e| e.active && e.date.year() == today.year() && e.date.month() == today.month() && e.date <= today)
+            .filter(|e| !is_recurring(e))
+            .map(|e| e.amount)
+            .sum();
+        let daily_pace = spent_so_far / today.day() as f64;
+
+        // Bills already paid this month (early, or dated ahead) are in the balance
+        // already, so only the unpaid ones still to come are subtracted
+        let unpaid: Vec<(NaiveDate, &ExpensePreset)> = self
+            .unpaid_recurring(today)
+            .into_iter()
+            .filter(|(due, _)| *due > today)
+            .collect();
+        let upcoming_recurring: f64 = unpaid.iter().map(|(_, p)| p.amount).sum();
+        let upcoming_income: f64 = self
+            .scheduled_income
+            .iter()
+            .filter(|i| due_day(i.day) > today.day())
+            .map(|i| i.amount)
+            .sum();
+
+        // Paydays already past are part of the balance from then on
+        let received_income: f64 = self
+            .scheduled_income
+            .iter()
+            .filter(|i| due_day(i.day) <= today.day())
+            .map(|i| i.amount)
+            .sum();
+        let starting_balance = self.remaining_balance() + received_income;
+
+        // Walk the remaining days to find when (if ever) the balance goes negative
+        let mut balance = starting_balance;
+        let mut run_out_date = (balance < 0.0).then_some(today);
+        for day in (today.day() + 1)..=last_day {
+            balance -= daily_pace;
+            balance += self
+                .scheduled_income
+                .iter()
+                .filter(|i| due_day(i.day) == day)
+                .map(|i| i.amount)
+                .sum::<f64>();
+            balance -= unpaid
+                .iter()
+                .filter(|(due, _)| due.day() == day)
+                .map(|(_, p)| p.amount)
+                .sum::<f64>();
+            if balance < 0.0 && run_out_date.is_none() {
+                run_out_date = today.with_day(day);
+            }
+        }
+
+        let days_left = (last_day - today.day() + 1) as f64;
+        let safe_per_day =
+            ((starting_balance + upcoming_income - upcoming_recurring) / days_left).max(0.0);
+
+        Forecast {
+            period_end,
+            daily_pace,
+            upcoming_recurring,
+            upcoming_income,
+            projected_balance: balance,
+            run_out_date,
+            safe_per_day,
+        }
+    }
+
+    /// Recurring presets (those with a default day) due in the month containing
+    /// `month` that have no matching active expense recorded that month yet
+    pub fn unpaid_recurring(&self, month: NaiveDate) -> Vec<(NaiveDate, &ExpensePreset)> {
+        let last_day = end_of_month(month).day();
+        self.presets
+            .iter()
+            .filter_map(|p| Some((month.with_day(p.default_day?.min(last_day))?, p)))
+            .filter(|(_, p)| {
+                !self.expenses.iter().any(|e| {
+                    e.active && e.date.year() == month.year() && e.date.month() == month.month() && p.matches(e)
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ScheduledIncome;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    #[test]
+    fn payday_does_not_change_the_projection() {
+        let mut budget = Budget::new();
+        budget.income = 1000.0;
+        budget.scheduled_income.push(ScheduledIncome::new(15, 500.0, "Paycheck".to_string()));
+
+        let before = budget.forecast(date(14));
+        let on_the_day = budget.forecast(date(15));
+        let after = budget.forecast(date(16));
+        assert_eq!(before.projected_balance, 1500.0);
+        assert_eq!(on_the_day.projected_balance, 1500.0);
+        assert_eq!(after.projected_balance, 1500.0);
+        assert_eq!(after.upcoming_income, 0.0);
+    }
+
+    #[test]
+    fn inactive_expense_does_not_pay_a_recurring_preset() {
+        let mut budget = Budget::new();
+        let rent = ExpensePreset::new("Rent".to_string(), 800.0, "Housing & Utilities".to_string(), "Rent".to_string())
+            .with_day(20);
+        let mut paid = Expense::new(800.0, rent.category.clone(), rent.description.clone(), date(2));
+        paid.active = false;
+        budget.presets.push(rent);
+        budget.expenses.push(paid);
+
+        assert_eq!(budget.unpaid_recurring(date(10)).len(), 1);
+        budget.expenses[0].active = true;
+        assert!(budget.unpaid_recurring(date(10)).is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Category used for expenses that record a contribution to a goal
+pub const SAVINGS_CATEGORY: &str = "Savings";
+
+/// Something to save up for, e.g. "Emergency fund $5k" or "New laptop by March".
+/// Contributions are expenses linked through `Expense::goal_id`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavingsGoal {
+    pub id: Uuid,
+    pub name: String,
+    pub target_amount: f64,
+    /// Optional deadline; goals without one are never "behind"
+    #[serde(default)]
+    pub target_date: Option<NaiveDate>,
+    /// When the goal was set, used to judge whether saving is on pace
+    pub created: NaiveDate,
+}
+
+/// Where a goal stands relative to its deadline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalStatus {
+    Reached,
+    OnTrack,
+    Behind,
+    /// Deadline passed without reaching the target
+    Overdue,
+    NoDeadline,
+}
+
+impl GoalStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GoalStatus::Reached => "Reached",
+            GoalStatus::OnTrack => "On track",
+            GoalStatus::Behind => "Behind",
+            GoalStatus::Overdue => "Overdue",
+            GoalStatus::NoDeadline => "No deadline",
+        }
+    }
+}
+
+impl SavingsGoal {
+    pub fn new(name: String, target_amount: f64, target_date: Option<NaiveDate>, created: NaiveDate) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            target_amount,
+            target_date,
+            created,
+        }
+    }
+
+    /// Fraction of the target saved so far (0.0 to 1.0)
+    pub fn progress(&self, saved: f64) -> f64 {
+        if self.target_amount > 0.0 {
+            (saved / self.target_amount).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Months left before the deadline, counting the current month
+    fn months_left(&self, today: NaiveDate) -> Option<u32> {
+        let target = self.target_date?;
+        let months = (target.year() - today.year()) * 12 + target.month() as i32 - today.month() as i32 + 1;
+        Some(months.max(0) as u32)
+    }
+
+    /// Monthly contribution needed from now on to hit the target by the deadline
+    pub fn monthly_needed(&self, saved: f64, today: NaiveDate) -> Option<f64> {
+        let remaining = (self.target_amount - saved).max(0.0);
+        let months = self.months_left(today)?;
+        Some(if months == 0 { remaining } else { remaining / months as f64 })
+    }
+
+    /// On track means saved at least the share of the target that time elapsed
+    /// since the goal was created would suggest
+    pub fn status(&self, saved: f64, today: NaiveDate) -> GoalStatus {
+        if saved >= self.target_amount {
+            return GoalStatus::Reached;
+        }
+        let Some(target_date) = self.target_date else {
+            return GoalStatus::NoDeadline;
+        };
+        if today > target_date {
+            return GoalStatus::Overdue;
+        }
+        let total_days = (target_date - self.created).num_days().max(1) as f64;
+        let elapsed_days = (today - self.created).num_days().max(0) as f64;
+        let expected = self.target_amount * (elapsed_days / total_days).min(1.0);
+        if saved + 0.005 >= expected {
+            GoalStatus::OnTrack
+        } else {
+            GoalStatus::Behind
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Actions that can be triggered from a keyboard shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShortcutAction {
+    AddExpense,
+    OpenTemplates,
+    OpenCalculator,
+    CycleProfile,
+    ManageProfiles,
+    ToggleQuickAdd,
+    EditIncome,
+    CommandPalette,
+}
+
+impl ShortcutAction {
+    /// All bindable actions, in the order they are listed in the UI
+    pub const ALL: &'static [ShortcutAction] = &[
+        ShortcutAction::AddExpense,
+        ShortcutAction::EditIncome,
+        ShortcutAction::OpenTemplates,
+        ShortcutAction::OpenCalculator,
+        ShortcutAction::ToggleQuickAdd,
+        ShortcutAction::CycleProfile,
+        ShortcutAction::ManageProfiles,
+        ShortcutAction::CommandPalette,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShortcutAction::AddExpense => "Add Expense",
+            ShortcutAction::OpenTemplates => "Open Templates",
+            ShortcutAction::OpenCalculator => "Open Calculator",
+            ShortcutAction::CycleProfile => "Switch to Next Profile",
+            ShortcutAction::ManageProfiles => "Manage Profiles",
+            ShortcutAction::ToggleQuickAdd => "Toggle Quick Add",
+            ShortcutAction::EditIncome => "Edit Income",
+            ShortcutAction::CommandPalette => "Command Palette",
+        }
+    }
+
+    pub fn default_binding(&self) -> KeyBinding {
+        match self {
+            ShortcutAction::AddExpense => KeyBinding::new("E", false),
+            ShortcutAction::OpenTemplates => KeyBinding::new("T", false),
+            ShortcutAction::OpenCalculator => KeyBinding::new("C", false),
+            ShortcutAction::CycleProfile => KeyBinding::new("P", false),
+            ShortcutAction::ManageProfiles => KeyBinding::new("P", true),
+            ShortcutAction::ToggleQuickAdd => KeyBinding::new("Q", false),
+            ShortcutAction::EditIncome => KeyBinding::new("I", false),
+            ShortcutAction::CommandPalette => KeyBinding::new("K", true),
+        }
+    }
+}
+
+/// A single key with an optional Ctrl/Cmd modifier
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyBinding {
+    /// Key name as understood by `egui::Key::from_name` (e.g. "E", "F2")
+    pub key: String,
+    /// Whether Ctrl (Cmd on macOS) must be held
+    #[serde(default)]
+    pub command: bool,
+}
+
+impl KeyBinding {
+    pub fn new(key: &str, command: bool) -> Self {
+        Self {
+            key: key.to_string(),
+            command,
+        }
+    }
+
+    /// Human-readable form, e.g. "E" or "⌘K"
+    pub fn label(&self) -> String {
+        if self.command {
+            format!("⌘{}", self.key)
+        } else {
+            self.key.clone()
+        }
+    }
+}
+
+/// User-configurable keyboard shortcuts
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Keymap {
+    /// Overrides of the default bindings (missing actions use their default)
+    #[serde(default)]
+    pub bindings: HashMap<ShortcutAction, KeyBinding>,
+}
+
+impl Keymap {
+    /// Get the effective binding for an action
+    pub fn binding(&self, action: ShortcutAction) -> KeyBinding {
+        self.bindings
+            .get(&action)
+            .cloned()
+            .unwrap_or_else(|| action.default_binding())
+    }
+
+    /// Bind an action to a key. If another action already uses that key,
+    /// it takes over this action's previous binding so no two actions collide.
+    pub fn set_binding(&mut self, action: ShortcutAction, binding: KeyBinding) {
+        let previous = self.binding(action);
+        if let Some(other) = ShortcutAction::ALL
+            .iter()
+            .copied()
+            .find(|a| *a != action && self.binding(*a) == binding)
+        {
+            self.bindings.insert(other, previous);
+        }
+        self.bindings.insert(action, binding);
+    }
+
+    /// Restore every action to its default binding
+    pub fn reset(&mut self) {
+        self.bindings.clear();
+    }
+}
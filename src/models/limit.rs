@@ -0,0 +1,118 @@
+use chrono::{Datelike, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::budget::Budget;
+
+/// Monthly spending limit per category name
+pub type CategoryLimits = HashMap<String, CategoryLimit>;
+
+/// What happens to the unspent (or overspent) part of a limit at month end
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum RolloverPolicy {
+    /// Every month starts fresh
+    #[default]
+    None,
+    /// Leftovers and overspending carry into the next month in full
+    Full,
+    /// Leftovers carry up to this amount; overspending carries in full
+    Capped(f64),
+}
+
+impl RolloverPolicy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RolloverPolicy::None => "No rollover",
+            RolloverPolicy::Full => "Full rollover",
+            RolloverPolicy::Capped(_) => "Capped rollover",
+        }
+    }
+
+    /// Amount carried into the next month from what was left of this one
+    pub fn carry(&self, left: f64) -> f64 {
+        match self {
+            RolloverPolicy::None => 0.0,
+            RolloverPolicy::Full => left,
+            RolloverPolicy::Capped(cap) => left.min(*cap),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryLimit {
+    pub amount: f64,
+    #[serde(default)]
+    pub rollover: RolloverPolicy,
+    /// First day of the month the limit started; nothing carries from before it
+    pub since: NaiveDate,
+}
+
+impl CategoryLimit {
+    pub fn new(amount: f64, rollover: RolloverPolicy, today: NaiveDate) -> Self {
+        Self {
+            amount,
+            rollover,
+            since: today.with_day(1).unwrap_or(today),
+        }
+    }
+}
+
+/// A limited category's position in a month
+#[derive(Debug, Clone)]
+pub struct LimitStatus {
+    pub category: String,
+    pub limit: f64,
+    /// Carried in from earlier months (negative after overspending)
+    pub carried: f64,
+    pub spent: f64,
+    /// limit + carried - spent
+    pub remaining: f64,
+}
+
+impl Budget {
+    /// Active spending on a category (including its subcategories) in the month of `month`
+    fn category_spent_in_month(&self, category: &str, month: NaiveDate) -> f64 {
+        self.expenses
+            .iter()
+            .filter(|e| e.active && e.date.year() == month.year() && e.date.month() == month.month())
+            .flat_map(|e| e.category_amounts())
+            .filter(|(c, _)| *c == category || self.category_parents.get(*c).is_some_and(|p| p == category))
+            .map(|(_, amount)| amount)
+            .sum()
+    }
+
+    /// Limit status for the month containing `today`, applying the rollover
+    /// policy to every month since the limit was set
+    pub fn limit_status(&self, category: &str, today: NaiveDate) -> Option<LimitStatus> {
+        let limit = self.category_limits.get(category)?;
+        let this_month = today.with_day(1).unwrap_or(today);
+
+        let mut carried = 0.0;
+        let mut month = limit.since;
+        while month < this_month {
+            let left = limit.amount + carried - self.category_spent_in_month(category, month);
+            carried = limit.rollover.carry(left);
+            let Some(next) = month.checked_add_months(Months::new(1)) else {
+                break;
+            };
+            month = next;
+        }
+
+        let spent = self.category_spent_in_month(category, this_month);
+        Some(LimitStatus {
+            category: category.to_string(),
+            limit: limit.amount,
+            carried,
+            spent,
+            remaining: limit.amount + carried - spent,
+        })
+    }
+
+    /// Status of every limited category, in category order
+    pub fn limit_statuses(&self, today: NaiveDate) -> Vec<LimitStatus> {
+        self.categories
+            .iter()
+            .filter_map(|c| self.limit_status(c, today))
+            .collect()
+    }
+}
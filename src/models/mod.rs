@@ -1,15 +1,47 @@
+pub mod account;
 pub mod budget;
+pub mod bundle;
+pub mod category;
+pub mod compare;
 pub mod config;
+pub mod debt;
+pub mod envelope;
 pub mod expense;
+pub mod forecast;
+pub mod goal;
+pub mod keymap;
+pub mod limit;
+pub mod overrides;
+pub mod payee;
 pub mod preset;
 pub mod profile;
+pub mod report;
+pub mod rule;
 pub mod shared;
+pub mod suggest;
 pub mod template;
 
+pub use account::{Account, AccountKind, Reconciliation, Transfer};
 pub use budget::{Budget, CategoryColor, DEFAULT_CATEGORIES};
+pub use bundle::{CategoryConflict, ConflictResolution, ProfileBundle, BUNDLE_EXTENSION, BUNDLE_VERSION};
+pub use category::{CategoryParents, DEFAULT_CATEGORY_COLOR};
+pub use compare::{category_union, combine_summaries, ProfileSummary};
 pub use config::{AppConfig, ProfileMeta};
-pub use expense::Expense;
+pub use debt::{simulate_payoff, Compounding, Debt, PayoffStrategy, DEBT_CATEGORY};
+pub use envelope::{period_key, EnvelopeRow, Envelopes};
+pub use expense::{normalize_tag, Attachment, Expense, SplitLine};
+pub use forecast::{end_of_month, Forecast};
+pub use goal::{GoalStatus, SavingsGoal, SAVINGS_CATEGORY};
+pub use keymap::{KeyBinding, Keymap, ShortcutAction};
+pub use limit::{CategoryLimit, CategoryLimits, LimitStatus, RolloverPolicy};
+pub use overrides::{CategoryOverrides, PresetOverrides, Scope};
+pub use payee::{find_payee, normalize_payee, Payee};
 pub use preset::ExpensePreset;
-pub use profile::ProfileData;
+pub use profile::{ProfileData, ScheduledIncome};
+pub use report::{payee_totals, tag_totals, PayeeTotal, TagTotal};
+pub use rule::{apply_rules, Rule, RuleActions, RuleConditions, TextMatch};
 pub use shared::SharedData;
-pub use template::Template;
+pub use suggest::SuggestionIndex;
+pub use template::{
+    is_valid_variable_name, substitute_variables, ExpenseParams, RelativeDate, Template, TemplateVariable, INCOME_VARIABLE,
+};
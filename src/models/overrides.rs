@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
+
+use super::budget::CategoryColor;
+use super::category::CategoryParents;
+use super::preset::ExpensePreset;
+
+/// Where a category or preset lives
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Scope {
+    /// In `SharedData`, seen by every profile that doesn't hide it
+    #[default]
+    Shared,
+    /// Only in the current profile
+    Profile,
+}
+
+/// Categories that only exist in one profile (e.g. "Client meals" in a business
+/// profile), plus the shared categories that profile hides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CategoryOverrides {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub categories: Vec<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub colors: HashMap<String, CategoryColor>,
+    /// Shared categories left out of this profile's pickers
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub hidden: BTreeSet<String>,
+}
+
+impl CategoryOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty() && self.hidden.is_empty()
+    }
+
+    /// True if `category` is one of this profile's own categories
+    pub fn contains(&self, category: &str) -> bool {
+        self.categories.iter().any(|c| c == category)
+    }
+
+    pub fn add(&mut self, category: String, color: CategoryColor) {
+        let trimmed = category.trim().to_string();
+        if !trimmed.is_empty() && !self.contains(&trimmed) {
+            self.categories.push(trimmed.clone());
+            self.colors.insert(trimmed, color);
+        }
+    }
+
+    pub fn remove(&mut self, category: &str) {
+        self.categories.retain(|c| c != category);
+        self.colors.remove(category);
+    }
+
+    pub fn set_color(&mut self, category: &str, color: CategoryColor) {
+        if self.contains(category) {
+            self.colors.insert(category.to_string(), color);
+        }
+    }
+
+    pub fn set_hidden(&mut self, category: &str, hidden: bool) {
+        if hidden {
+            self.hidden.insert(category.to_string());
+        } else {
+            self.hidden.remove(category);
+        }
+    }
+
+    /// Whether a shared category shows in this profile. Hiding a parent hides its subcategories.
+    pub fn shows(&self, category: &str, parents: &CategoryParents) -> bool {
+        !self.hidden.contains(category) && parents.get(category).is_none_or(|p| !self.hidden.contains(p))
+    }
+}
+
+/// Presets that only exist in one profile, plus the shared presets that profile hides
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PresetOverrides {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub presets: Vec<ExpensePreset>,
+    /// Shared presets left out of this profile's Quick Add panel
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub hidden: BTreeSet<Uuid>,
+}
+
+impl PresetOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.presets.is_empty() && self.hidden.is_empty()
+    }
+
+    /// True if the preset is one of this profile's own presets
+    pub fn contains(&self, id: Uuid) -> bool {
+        self.presets.iter().any(|p| p.id == id)
+    }
+
+    pub fn remove(&mut self, id: Uuid) {
+        self.presets.retain(|p| p.id != id);
+    }
+
+    pub fn set_hidden(&mut self, id: Uuid, hidden: bool) {
+        if hidden {
+            self.hidden.insert(id);
+        } else {
+            self.hidden.remove(&id);
+        }
+    }
+}
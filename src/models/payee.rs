@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A merchant or person in the payee directory. Aliases catch the different
+/// ways the same payee shows up, e.g. "AMZN MKTP" for "Amazon".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Payee {
+    pub id: Uuid,
+    pub name: String,
+    /// Matched case-insensitively against the start of an entered payee
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// Category new expenses for this payee start in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_category: Option<String>,
+}
+
+impl Payee {
+    pub fn new(name: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            aliases: Vec::new(),
+            default_category: None,
+        }
+    }
+
+    /// True if `raw` is this payee's name, or starts with one of its aliases
+    pub fn matches(&self, raw: &str) -> bool {
+        let raw = raw.trim().to_lowercase();
+        !raw.is_empty()
+            && (raw == self.name.to_lowercase()
+                || self.aliases.iter().any(|a| !a.trim().is_empty() && raw.starts_with(&a.trim().to_lowercase())))
+    }
+
+    /// Add an alias unless it's empty or already known
+    pub fn add_alias(&mut self, alias: &str) {
+        let alias = alias.trim();
+        if !alias.is_empty() && !self.aliases.iter().any(|a| a.eq_ignore_ascii_case(alias)) {
+            self.aliases.push(alias.to_string());
+        }
+    }
+}
+
+/// Directory entry for an entered payee, if any
+pub fn find_payee<'a>(payees: &'a [Payee], raw: &str) -> Option<&'a Payee> {
+    payees.iter().find(|p| p.matches(raw))
+}
+
+/// The directory name for an entered payee, or the trimmed input if it isn't known
+pub fn normalize_payee(payees: &[Payee], raw: &str) -> String {
+    find_payee(payees, raw).map_or_else(|| raw.trim().to_string(), |p| p.name.clone())
+}
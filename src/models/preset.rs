@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::expense::Expense;
+
 /// A preset expense that can be quickly added with a single click or drag
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExpensePreset {
@@ -29,4 +31,11 @@ impl ExpensePreset {
         self.default_day = Some(day.clamp(1, 31));
         self
     }
+
+    /// True if the expense looks like it was added from this preset
+    pub fn matches(&self, expense: &Expense) -> bool {
+        self.category == expense.category
+            && self.description == expense.description
+            && (self.amount - expense.amount).abs() < 0.005
+    }
 }
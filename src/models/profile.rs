@@ -1,5 +1,27 @@
 use serde::{Deserialize, Serialize};
-use crate::models::Expense;
+use uuid::Uuid;
+use crate::models::{Account, CategoryOverrides, DEFAULT_CATEGORY_COLOR, Debt, Envelopes, Expense, PresetOverrides, SavingsGoal, Transfer};
+
+/// Income expected on a fixed day each month (e.g. a second paycheck), on top of
+/// `ProfileData::income`. Only used for forecasting, where it counts as received
+/// from that day on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledIncome {
+    pub day: u32, // Day of month (1-31)
+    pub amount: f64,
+    #[serde(default)]
+    pub label: String,
+}
+
+impl ScheduledIncome {
+    pub fn new(day: u32, amount: f64, label: String) -> Self {
+        Self {
+            day: day.clamp(1, 31),
+            amount,
+            label,
+        }
+    }
+}
 
 /// Profile-specific budget data (income and expenses only)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -8,6 +30,27 @@ pub struct ProfileData {
     pub income: f64,
     #[serde(default)]
     pub expenses: Vec<Expense>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scheduled_income: Vec<ScheduledIncome>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub goals: Vec<SavingsGoal>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub debts: Vec<Debt>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<Account>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transfers: Vec<Transfer>,
+    /// Zero-based budgeting: income is assigned to category envelopes each month
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub envelope_mode: bool,
+    #[serde(default, skip_serializing_if = "Envelopes::is_empty")]
+    pub envelopes: Envelopes,
+    /// Categories of this profile only, layered on the shared ones
+    #[serde(default, skip_serializing_if = "CategoryOverrides::is_empty")]
+    pub category_overrides: CategoryOverrides,
+    /// Presets of this profile only, layered on the shared ones
+    #[serde(default, skip_serializing_if = "PresetOverrides::is_empty")]
+    pub preset_overrides: PresetOverrides,
 }
 
 impl ProfileData {
@@ -28,4 +71,113 @@ impl ProfileData {
     pub fn remaining_balance(&self) -> f64 {
         self.income - self.total_expenses()
     }
+
+    /// Add expenses from another profile, skipping any this profile already has
+    /// (same id, as in a duplicated profile). Expenses that only look like one
+    /// already here are added and listed in the outcome so they can be checked.
+    /// Links to goals, debts and accounts that don't exist here are dropped.
+    pub fn add_expenses(&mut self, expenses: Vec<Expense>) -> MergeOutcome {
+        let mut outcome = MergeOutcome::default();
+        // Compare against what was here before the batch, so identical purchases
+        // within it (two coffees on one day) are all kept
+        let existing = self.expenses.len();
+        for mut expense in expenses {
+            let before = &self.expenses[..existing];
+            if before.iter().any(|e| e.id == expense.id) {
+                outcome.skipped += 1;
+                continue;
+            }
+            if before.iter().any(|e| e.is_duplicate_of(&expense)) {
+                let title = if expense.description.trim().is_empty() { &expense.category } else { &expense.description };
+                outcome.possible_duplicates.push(format!(
+                    "{} ${:.2} on {}",
+                    title,
+                    expense.amount,
+                    expense.date.format("%b %d")
+                ));
+            }
+            expense.goal_id = expense.goal_id.filter(|id| self.goals.iter().any(|g| g.id == *id));
+            expense.debt_id = expense.debt_id.filter(|id| self.debts.iter().any(|d| d.id == *id));
+            expense.account_id = expense.account_id.filter(|id| self.accounts.iter().any(|a| a.id == *id));
+            outcome.added.push(expense.id);
+            self.expenses.push(expense);
+        }
+        outcome
+    }
+
+    /// Fold another profile into this one. Income and envelope assignments are added
+    /// together, and envelope mode stays on if either profile used it. Goals, debts,
+    /// accounts, transfers and profile-only categories and presets are brought over
+    /// unless they're already here (as in a duplicated profile). Hidden shared
+    /// entries stay as they are.
+    pub fn merge(&mut self, other: ProfileData) -> MergeOutcome {
+        self.income += other.income;
+        self.envelope_mode |= other.envelope_mode;
+        self.envelopes.add_all(other.envelopes);
+        for scheduled in other.scheduled_income {
+            if !self.scheduled_income.contains(&scheduled) {
+                self.scheduled_income.push(scheduled);
+            }
+        }
+        for goal in other.goals {
+            if !self.goals.iter().any(|g| g.id == goal.id) {
+                self.goals.push(goal);
+            }
+        }
+        for debt in other.debts {
+            if !self.debts.iter().any(|d| d.id == debt.id) {
+                self.debts.push(debt);
+            }
+        }
+        for account in other.accounts {
+            if !self.accounts.iter().any(|a| a.id == account.id) {
+                self.accounts.push(account);
+            }
+        }
+        for transfer in other.transfers {
+            if !self.transfers.iter().any(|t| t.id == transfer.id) {
+                self.transfers.push(transfer);
+            }
+        }
+        let mut categories = other.category_overrides;
+        for category in categories.categories {
+            let color = categories.colors.remove(&category).unwrap_or(DEFAULT_CATEGORY_COLOR);
+            self.category_overrides.add(category, color);
+        }
+        for preset in other.preset_overrides.presets {
+            if !self.preset_overrides.contains(preset.id) {
+                self.preset_overrides.presets.push(preset);
+            }
+        }
+        self.add_expenses(other.expenses)
+    }
+}
+
+/// Result of moving, copying or merging expenses into a profile
+#[derive(Debug, Clone, Default)]
+pub struct MergeOutcome {
+    /// Ids of the expenses that were added
+    pub added: Vec<Uuid>,
+    /// Expenses left out because the profile already had them
+    pub skipped: usize,
+    /// Added expenses that look like one already there, described for the user to check
+    pub possible_duplicates: Vec<String>,
+}
+
+impl MergeOutcome {
+    /// Short description such as "Added 4 expenses, skipped 1 already there"
+    pub fn describe(&self) -> String {
+        let plural = |n: usize, word: &str| if n == 1 { format!("{} {}", n, word) } else { format!("{} {}s", n, word) };
+        let mut text = format!("Added {}", plural(self.added.len(), "expense"));
+        if self.skipped > 0 {
+            text.push_str(&format!(", skipped {} already there", self.skipped));
+        }
+        if !self.possible_duplicates.is_empty() {
+            text.push_str(&format!(
+                ". These look like expenses it already had: {}",
+                self.possible_duplicates.join(", ")
+            ));
+        }
+        text
+    }
 }
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use super::payee::{normalize_payee, Payee};
+use super::profile::ProfileData;
+
+/// Spending for one tag, broken down by profile
+#[derive(Debug, Clone)]
+pub struct TagTotal {
+    pub tag: String,
+    pub total: f64,
+    pub count: usize,
+    /// (profile name, amount) for each profile that used the tag
+    pub by_profile: Vec<(String, f64)>,
+}
+
+/// Sum active expenses per tag across the given (profile name, data) pairs.
+/// An expense with several tags counts fully toward each of them.
+pub fn tag_totals(profiles: &[(String, ProfileData)]) -> Vec<TagTotal> {
+    let mut totals: BTreeMap<&str, TagTotal> = BTreeMap::new();
+
+    for (profile_name, data) in profiles {
+        for expense in data.expenses.iter().filter(|e| e.active) {
+            for tag in &expense.tags {
+                let entry = totals.entry(tag.as_str()).or_insert_with(|| TagTotal {
+                    tag: tag.clone(),
+                    total: 0.0,
+                    count: 0,
+                    by_profile: Vec::new(),
+                });
+                entry.total += expense.amount;
+                entry.count += 1;
+                match entry.by_profile.iter_mut().find(|(name, _)| name == profile_name) {
+                    Some((_, amount)) => *amount += expense.amount,
+                    None => entry.by_profile.push((profile_name.clone(), expense.amount)),
+                }
+            }
+        }
+    }
+
+    let mut totals: Vec<TagTotal> = totals.into_values().collect();
+    totals.sort_by(|a, b| b.total.total_cmp(&a.total));
+    totals
+}
+
+/// Spending for one payee, broken down by profile
+#[derive(Debug, Clone)]
+pub struct PayeeTotal {
+    pub payee: String,
+    pub total: f64,
+    pub count: usize,
+    /// (profile name, amount) for each profile that paid them
+    pub by_profile: Vec<(String, f64)>,
+}
+
+/// Sum active expenses per payee across the given (profile name, data) pairs.
+/// Payees are normalized through the directory, so aliases count toward one payee.
+pub fn payee_totals(profiles: &[(String, ProfileData)], payees: &[Payee]) -> Vec<PayeeTotal> {
+    let mut totals: BTreeMap<String, PayeeTotal> = BTreeMap::new();
+
+    for (profile_name, data) in profiles {
+        for expense in data.expenses.iter().filter(|e| e.active && !e.payee.trim().is_empty()) {
+            let payee = normalize_payee(payees, &expense.payee);
+            let entry = totals.entry(payee.to_lowercase()).or_insert_with(|| PayeeTotal {
+                payee,
+                total: 0.0,
+                count: 0,
+                by_profile: Vec::new(),
+            });
+            entry.total += expense.amount;
+            entry.count += 1;
+            match entry.by_profile.iter_mut().find(|(name, _)| name == profile_name) {
+                Some((_, amount)) => *amount += expense.amount,
+                None => entry.by_profile.push((profile_name.clone(), expense.amount)),
+            }
+        }
+    }
+
+    let mut totals: Vec<PayeeTotal> = totals.into_values().collect();
+    totals.sort_by(|a, b| b.total.total_cmp(&a.total));
+    totals
+}
@@ -0,0 +1,165 @@
+use regex::RegexBuilder;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::expense::{normalize_tag, Expense};
+
+/// How a rule matches text such as an expense description
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TextMatch {
+    /// Case-insensitive substring
+    Contains(String),
+    /// Case-insensitive regular expression
+    Regex(String),
+}
+
+impl TextMatch {
+    pub fn pattern(&self) -> &str {
+        match self {
+            TextMatch::Contains(text) | TextMatch::Regex(text) => text,
+        }
+    }
+
+    /// Invalid regular expressions never match
+    pub fn is_match(&self, text: &str) -> bool {
+        match self {
+            TextMatch::Contains(needle) => text.to_lowercase().contains(&needle.to_lowercase()),
+            TextMatch::Regex(pattern) => RegexBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+                .is_ok_and(|re| re.is_match(text)),
+        }
+    }
+
+    /// Why the pattern can't be used, if it can't
+    pub fn error(&self) -> Option<String> {
+        match self {
+            TextMatch::Contains(_) => None,
+            TextMatch::Regex(pattern) => RegexBuilder::new(pattern).build().err().map(|e| e.to_string()),
+        }
+    }
+}
+
+/// Conditions an expense has to meet; unset conditions are ignored
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleConditions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<TextMatch>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_amount: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_amount: Option<f64>,
+    /// Payee name, compared case-insensitively
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payee: Option<String>,
+}
+
+impl RuleConditions {
+    pub fn is_empty(&self) -> bool {
+        self.description.is_none() && self.min_amount.is_none() && self.max_amount.is_none() && self.payee.is_none()
+    }
+}
+
+/// What a matching rule does to the expense
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RuleActions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub set_category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub add_tag: Option<String>,
+    #[serde(default)]
+    pub mark_inactive: bool,
+}
+
+impl RuleActions {
+    pub fn is_empty(&self) -> bool {
+        self.set_category.is_none() && self.add_tag.is_none() && !self.mark_inactive
+    }
+}
+
+/// An auto-categorization rule, shared by all profiles
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rule {
+    pub id: Uuid,
+    pub name: String,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub conditions: RuleConditions,
+    #[serde(default)]
+    pub actions: RuleActions,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Rule {
+    pub fn new(name: String, conditions: RuleConditions, actions: RuleActions) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name,
+            enabled: true,
+            conditions,
+            actions,
+        }
+    }
+
+    /// Rule that puts expenses like this one in its category: matches the
+    /// description (and payee, if any)
+    pub fn from_expense(expense: &Expense) -> Self {
+        let description = expense.description.trim();
+        let payee = expense.payee.trim();
+        let conditions = RuleConditions {
+            description: (!description.is_empty()).then(|| TextMatch::Contains(description.to_string())),
+            payee: (!payee.is_empty()).then(|| payee.to_string()),
+            ..RuleConditions::default()
+        };
+        let actions = RuleActions {
+            set_category: Some(expense.category.clone()),
+            ..RuleActions::default()
+        };
+        let name = [payee, description].into_iter().find(|s| !s.is_empty()).unwrap_or(&expense.category);
+        Self::new(name.to_string(), conditions, actions)
+    }
+
+    /// A rule without conditions matches nothing, rather than everything
+    pub fn matches(&self, expense: &Expense) -> bool {
+        let c = &self.conditions;
+        !c.is_empty()
+            && c.description.as_ref().is_none_or(|m| m.is_match(&expense.description))
+            && c.min_amount.is_none_or(|min| expense.amount >= min)
+            && c.max_amount.is_none_or(|max| expense.amount <= max)
+            && c.payee.as_ref().is_none_or(|p| expense.payee.eq_ignore_ascii_case(p.trim()))
+    }
+
+    /// Apply the actions if the rule matches. Returns true if the expense changed.
+    pub fn apply(&self, expense: &mut Expense) -> bool {
+        if !self.enabled || !self.matches(expense) {
+            return false;
+        }
+        let mut changed = false;
+        // Split expenses keep their per-line categories
+        if let Some(category) = &self.actions.set_category
+            && !expense.is_split()
+            && expense.category != *category
+        {
+            expense.category = category.clone();
+            changed = true;
+        }
+        if let Some(tag) = self.actions.add_tag.as_deref().and_then(normalize_tag) {
+            changed |= expense.tags.insert(tag);
+        }
+        if self.actions.mark_inactive && expense.active {
+            expense.active = false;
+            changed = true;
+        }
+        changed
+    }
+}
+
+/// Run the enabled rules top to bottom, so a later rule's category wins.
+/// Returns true if the expense changed.
+pub fn apply_rules(rules: &[Rule], expense: &mut Expense) -> bool {
+    rules.iter().fold(false, |changed, rule| rule.apply(expense) | changed)
+}
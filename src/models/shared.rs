@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::models::{CategoryColor, ExpensePreset, Template, DEFAULT_CATEGORIES};
+use crate::models::category::{self, CategoryParents};
+use crate::models::limit::{CategoryLimit, CategoryLimits};
+use crate::models::{
+    CategoryColor, ExpenseParams, ExpensePreset, Payee, Rule, Template, TemplateVariable, DEFAULT_CATEGORIES,
+};
 
 fn default_categories() -> Vec<String> {
     DEFAULT_CATEGORIES.iter().map(|(s, _)| s.to_string()).collect()
@@ -22,9 +26,19 @@ pub struct SharedData {
     #[serde(default = "default_category_colors")]
     pub category_colors: HashMap<String, CategoryColor>,
     #[serde(default)]
+    pub category_parents: CategoryParents,
+    #[serde(default)]
+    pub category_limits: CategoryLimits,
+    #[serde(default)]
     pub templates: Vec<Template>,
     #[serde(default)]
     pub presets: Vec<ExpensePreset>,
+    /// Auto-categorization rules, applied in order
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+    /// Payee directory with aliases and default categories
+    #[serde(default)]
+    pub payees: Vec<Payee>,
 }
 
 impl Default for SharedData {
@@ -32,8 +46,12 @@ impl Default for SharedData {
         Self {
             categories: default_categories(),
             category_colors: default_category_colors(),
+            category_parents: CategoryParents::new(),
+            category_limits: CategoryLimits::new(),
             templates: Vec::new(),
             presets: Vec::new(),
+            rules: Vec::new(),
+            payees: Vec::new(),
         }
     }
 }
@@ -51,16 +69,69 @@ impl SharedData {
         }
     }
 
+    /// Add a subcategory under a top-level category. It inherits the parent's
+    /// color until one is set explicitly.
+    pub fn add_subcategory(&mut self, category: String, parent: &str) {
+        let trimmed = category.trim().to_string();
+        if trimmed.is_empty() || self.categories.contains(&trimmed) || !self.is_top_level(parent) {
+            return;
+        }
+        self.categories.push(trimmed.clone());
+        self.category_parents.insert(trimmed, parent.to_string());
+    }
+
+    /// Removing a parent promotes its subcategories to top-level categories,
+    /// keeping the color they inherited
     pub fn remove_category(&mut self, category: &str) {
+        if let Some(color) = self.category_colors.get(category).copied() {
+            for (child, _) in self.category_parents.iter().filter(|(_, parent)| *parent == category) {
+                self.category_colors.entry(child.clone()).or_insert(color);
+            }
+        }
         self.categories.retain(|c| c != category);
         self.category_colors.remove(category);
+        self.category_parents.remove(category);
+        self.category_parents.retain(|_, parent| parent != category);
+        self.category_limits.remove(category);
+    }
+
+    /// Set or clear (with `None`) a category's monthly limit
+    pub fn set_category_limit(&mut self, category: &str, limit: Option<CategoryLimit>) {
+        match limit {
+            Some(limit) if self.categories.iter().any(|c| c == category) => {
+                self.category_limits.insert(category.to_string(), limit);
+            }
+            _ => {
+                self.category_limits.remove(category);
+            }
+        }
+    }
+
+    /// Move a category under `parent`, or to the top level with `None`.
+    /// Ignored if it would nest more than one level deep.
+    pub fn set_category_parent(&mut self, category: &str, parent: Option<&str>) {
+        if !self.categories.iter().any(|c| c == category) {
+            return;
+        }
+        match parent {
+            Some(parent) => {
+                let has_children = self.category_parents.values().any(|p| p == category);
+                if parent != category && self.is_top_level(parent) && !has_children {
+                    self.category_parents.insert(category.to_string(), parent.to_string());
+                }
+            }
+            None => {
+                self.category_parents.remove(category);
+            }
+        }
+    }
+
+    fn is_top_level(&self, category: &str) -> bool {
+        self.categories.iter().any(|c| c == category) && !self.category_parents.contains_key(category)
     }
 
     pub fn get_category_color(&self, category: &str) -> CategoryColor {
-        self.category_colors
-            .get(category)
-            .copied()
-            .unwrap_or([156, 163, 175]) // Default gray
+        category::category_color(category, &self.category_colors, &self.category_parents)
     }
 
     pub fn set_category_color(&mut self, category: &str, color: CategoryColor) {
@@ -69,6 +140,13 @@ impl SharedData {
         }
     }
 
+    /// Drop a subcategory's own color so it inherits from its parent again
+    pub fn reset_category_color(&mut self, category: &str) {
+        if self.category_parents.contains_key(category) {
+            self.category_colors.remove(category);
+        }
+    }
+
     pub fn add_preset(&mut self, preset: ExpensePreset) {
         self.presets.push(preset);
     }
@@ -77,10 +155,6 @@ impl SharedData {
         self.presets.retain(|p| p.id != id);
     }
 
-    pub fn get_preset(&self, id: uuid::Uuid) -> Option<&ExpensePreset> {
-        self.presets.iter().find(|p| p.id == id)
-    }
-
     pub fn add_template(&mut self, template: Template) {
         self.templates.push(template);
     }
@@ -95,9 +169,56 @@ impl SharedData {
         }
     }
 
+    /// Add a rule, or replace the one with the same id
+    pub fn save_rule(&mut self, rule: Rule) {
+        match self.rules.iter_mut().find(|r| r.id == rule.id) {
+            Some(existing) => *existing = rule,
+            None => self.rules.push(rule),
+        }
+    }
+
+    pub fn remove_rule(&mut self, id: uuid::Uuid) {
+        self.rules.retain(|r| r.id != id);
+    }
+
+    /// Move a rule one place earlier (`up`) or later in the run order
+    pub fn move_rule(&mut self, id: uuid::Uuid, up: bool) {
+        if let Some(index) = self.rules.iter().position(|r| r.id == id) {
+            let target = if up { index.checked_sub(1) } else { Some(index + 1) };
+            if let Some(target) = target.filter(|t| *t < self.rules.len()) {
+                self.rules.swap(index, target);
+            }
+        }
+    }
+
+    pub fn get_payee_mut(&mut self, id: uuid::Uuid) -> Option<&mut Payee> {
+        self.payees.iter_mut().find(|p| p.id == id)
+    }
+
+    pub fn remove_payee(&mut self, id: uuid::Uuid) {
+        self.payees.retain(|p| p.id != id);
+    }
+
+    pub fn add_template_expense(&mut self, id: uuid::Uuid, expense: crate::models::Expense) {
+        if let Some(template) = self.templates.iter_mut().find(|t| t.id == id) {
+            template.expenses.push(expense);
+        }
+    }
+
     pub fn update_template_expenses(&mut self, id: uuid::Uuid, expenses: Vec<crate::models::Expense>) {
         if let Some(template) = self.templates.iter_mut().find(|t| t.id == id) {
             template.expenses = expenses;
         }
     }
+
+    pub fn update_template_params(
+        &mut self,
+        id: uuid::Uuid,
+        params: HashMap<uuid::Uuid, ExpenseParams>,
+        variables: Vec<TemplateVariable>,
+    ) {
+        if let Some(template) = self.templates.iter_mut().find(|t| t.id == id) {
+            template.set_params(params, variables);
+        }
+    }
 }
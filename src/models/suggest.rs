@@ -0,0 +1,124 @@
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use super::expense::Expense;
+
+/// A description seen in past expenses, with what it was last entered as
+#[derive(Debug, Clone)]
+pub struct KnownDescription {
+    pub description: String,
+    /// How many expenses used it
+    pub count: usize,
+    pub last_amount: f64,
+    pub last_category: String,
+    last_date: NaiveDate,
+    tokens: Vec<String>,
+}
+
+/// Category and amount suggested for a description being typed
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub category: String,
+    pub amount: f64,
+    /// Past description the amount came from
+    pub matched: String,
+}
+
+/// Lowercase words of a description, ignoring punctuation and numbers
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| t.chars().any(char::is_alphabetic))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Descriptions, amounts and categories learned from expense history
+#[derive(Debug, Clone, Default)]
+pub struct SuggestionIndex {
+    entries: Vec<KnownDescription>,
+}
+
+impl SuggestionIndex {
+    /// Index past expenses; descriptions are grouped case-insensitively and
+    /// keep the amount and category of their most recent use
+    pub fn build<'a>(expenses: impl IntoIterator<Item = &'a Expense>) -> Self {
+        let mut by_key: HashMap<String, KnownDescription> = HashMap::new();
+        for expense in expenses {
+            let description = expense.description.trim();
+            if description.is_empty() {
+                continue;
+            }
+            let entry = by_key
+                .entry(description.to_lowercase())
+                .or_insert_with(|| KnownDescription {
+                    description: description.to_string(),
+                    count: 0,
+                    last_amount: expense.amount,
+                    last_category: expense.category.clone(),
+                    last_date: expense.date,
+                    tokens: tokenize(description),
+                });
+            entry.count += 1;
+            if expense.date >= entry.last_date {
+                entry.description = description.to_string();
+                entry.last_amount = expense.amount;
+                entry.last_category = expense.category.clone();
+                entry.last_date = expense.date;
+            }
+        }
+
+        let mut entries: Vec<KnownDescription> = by_key.into_values().collect();
+        entries.sort_by(|a, b| b.count.cmp(&a.count).then(b.last_date.cmp(&a.last_date)));
+        Self { entries }
+    }
+
+    /// Known descriptions starting with what's been typed, most used first.
+    /// Nothing is offered once the input already equals a known description.
+    pub fn complete(&self, typed: &str, limit: usize) -> Vec<&KnownDescription> {
+        let typed = typed.trim().to_lowercase();
+        if typed.is_empty() || self.entries.iter().any(|e| e.description.to_lowercase() == typed) {
+            return Vec::new();
+        }
+        self.entries
+            .iter()
+            .filter(|e| e.description.to_lowercase().starts_with(&typed))
+            .take(limit)
+            .collect()
+    }
+
+    /// Most likely category for a description: past descriptions are scored by
+    /// shared words (the last typed word may be unfinished) and weighted by how
+    /// often they were used, then the scores are summed per category
+    pub fn suggest(&self, typed: &str) -> Option<Suggestion> {
+        let typed_tokens = tokenize(typed);
+        let (last, complete) = typed_tokens.split_last()?;
+
+        let mut by_category: HashMap<&str, f64> = HashMap::new();
+        let mut best: HashMap<&str, (f64, &KnownDescription)> = HashMap::new();
+        for entry in &self.entries {
+            let shared = complete.iter().filter(|t| entry.tokens.contains(t)).count()
+                + usize::from(entry.tokens.iter().any(|t| t.starts_with(last.as_str())));
+            if shared == 0 {
+                continue;
+            }
+            let union = typed_tokens.len() + entry.tokens.len() - shared;
+            let score = shared as f64 / union.max(1) as f64 * (1.0 + (entry.count as f64).ln());
+            let category = entry.last_category.as_str();
+            *by_category.entry(category).or_insert(0.0) += score;
+            let top = best.entry(category).or_insert((score, entry));
+            if score > top.0 {
+                *top = (score, entry);
+            }
+        }
+
+        let (category, _) = by_category
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+        let (_, entry) = best.get(category)?;
+        Some(Suggestion {
+            category: category.to_string(),
+            amount: entry.last_amount,
+            matched: entry.description.clone(),
+        })
+    }
+}
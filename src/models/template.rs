@@ -1,13 +1,87 @@
+use chrono::{Datelike, NaiveDate, Weekday};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::expense::Expense;
+use super::forecast::end_of_month;
+
+/// Variable every template can use: the profile's income
+pub const INCOME_VARIABLE: &str = "income";
+
+/// Where in the target month a template expense lands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RelativeDate {
+    /// Day of the month, moved to the last day in shorter months
+    Day(u32),
+    LastDay,
+    FirstBusinessDay,
+    LastBusinessDay,
+}
+
+impl RelativeDate {
+    /// Date in the month containing `month`. Business days are Monday to Friday.
+    pub fn resolve(self, month: NaiveDate) -> NaiveDate {
+        let last = end_of_month(month);
+        let first = last.with_day(1).unwrap_or(last);
+        let is_business_day = |d: &NaiveDate| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun);
+        match self {
+            RelativeDate::Day(day) => last.with_day(day.clamp(1, last.day())).unwrap_or(last),
+            RelativeDate::LastDay => last,
+            RelativeDate::FirstBusinessDay => first.iter_days().take(7).find(is_business_day).unwrap_or(first),
+            RelativeDate::LastBusinessDay => last
+                .iter_days()
+                .rev()
+                .take(7)
+                .find(is_business_day)
+                .unwrap_or(last),
+        }
+    }
+
+    pub fn label(self) -> String {
+        match self {
+            RelativeDate::Day(day) => format!("Day {}", day),
+            RelativeDate::LastDay => "Last day".to_string(),
+            RelativeDate::FirstBusinessDay => "First business day".to_string(),
+            RelativeDate::LastBusinessDay => "Last business day".to_string(),
+        }
+    }
+}
+
+/// How one template expense is filled in when the template is applied
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExpenseParams {
+    /// Date in the target month. None keeps the day of the month it was saved with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<RelativeDate>,
+    /// Amount formula that can use variables, e.g. "rent" or "income × 10%"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+}
+
+impl ExpenseParams {
+    pub fn is_empty(&self) -> bool {
+        self.date.is_none() && self.amount.is_none()
+    }
+}
+
+/// A named amount asked for when the template is applied, with its usual value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateVariable {
+    pub name: String,
+    pub value: f64,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Template {
     pub id: Uuid,
     pub name: String,
     pub expenses: Vec<Expense>,
+    /// Date and amount rules, by expense id
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub params: HashMap<Uuid, ExpenseParams>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variables: Vec<TemplateVariable>,
 }
 
 impl Template {
@@ -16,10 +90,80 @@ impl Template {
             id: Uuid::new_v4(),
             name,
             expenses,
+            params: HashMap::new(),
+            variables: Vec::new(),
         }
     }
 
     pub fn total(&self) -> f64 {
         self.expenses.iter().filter(|e| e.active).map(|e| e.amount).sum()
     }
+
+    /// Where an expense lands in the month the template is applied to
+    pub fn date_rule(&self, expense: &Expense) -> RelativeDate {
+        self.params
+            .get(&expense.id)
+            .and_then(|p| p.date)
+            .unwrap_or(RelativeDate::Day(expense.date.day()))
+    }
+
+    /// Amount formula for an expense, if it has one
+    pub fn amount_formula(&self, expense: &Expense) -> Option<&str> {
+        self.params.get(&expense.id).and_then(|p| p.amount.as_deref())
+    }
+
+    /// Replace the rules and variables, dropping rules for expenses no longer in the template
+    pub fn set_params(&mut self, mut params: HashMap<Uuid, ExpenseParams>, variables: Vec<TemplateVariable>) {
+        params.retain(|id, p| !p.is_empty() && self.expenses.iter().any(|e| e.id == *id));
+        self.params = params;
+        self.variables = variables;
+    }
+}
+
+/// True if `name` can be used as a variable in amount formulas. "x" followed by
+/// digits is taken, since formulas accept x as a multiplication sign.
+pub fn is_valid_variable_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !is_multiplication(name)
+}
+
+fn is_multiplication(name: &str) -> bool {
+    name.starts_with(['x', 'X']) && name[1..].chars().all(|d| d.is_ascii_digit())
+}
+
+/// Replace variable names in an amount formula with their values, so it can be
+/// evaluated like any amount: "income × 10%" becomes "(3200) × 10%". Names are
+/// matched ignoring case. Returns the first unknown name as the error.
+pub fn substitute_variables(formula: &str, values: &HashMap<String, f64>) -> Result<String, String> {
+    let mut result = String::with_capacity(formula.len());
+    let mut chars = formula.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if !(c.is_ascii_alphabetic() || c == '_') {
+            result.push(c);
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, next)) = chars.peek() {
+            if !(next.is_ascii_alphanumeric() || next == '_') {
+                break;
+            }
+            end = i + next.len_utf8();
+            chars.next();
+        }
+        let name = &formula[start..end];
+        // "x" or "x3" as in "2x3" is multiplication
+        if is_multiplication(name) {
+            result.push_str(name);
+            continue;
+        }
+        let value = values
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+            .ok_or_else(|| name.to_string())?;
+        result.push_str(&format!("({})", value));
+    }
+    Ok(result)
 }
@@ -0,0 +1,69 @@
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::json_store::get_data_dir;
+use crate::models::Attachment;
+
+/// Get path to the attachments directory (files are named by content hash)
+pub fn get_attachments_dir() -> Option<PathBuf> {
+    get_data_dir().map(|d| d.join("attachments"))
+}
+
+/// Get path to the stored copy of an attachment
+pub fn get_attachment_path(attachment: &Attachment) -> Option<PathBuf> {
+    get_attachments_dir().map(|d| d.join(attachment.stored_name()))
+}
+
+/// Copy a file into the attachments directory. Files with identical contents
+/// share one stored copy.
+pub fn import_attachment(source: &Path) -> Result<Attachment, String> {
+    let bytes = fs::read(source).map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
+
+    let hash: String = Sha256::digest(&bytes).iter().map(|b| format!("{:02x}", b)).collect();
+    let attachment = Attachment {
+        hash,
+        extension: source
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default(),
+        file_name: source
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    };
+
+    let path = get_attachment_path(&attachment).ok_or("Could not get attachments dir")?;
+    if !path.exists() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create attachments dir: {}", e))?;
+        }
+        fs::write(&path, &bytes).map_err(|e| format!("Failed to write attachment: {}", e))?;
+    }
+
+    Ok(attachment)
+}
+
+/// Delete stored files whose hash isn't in `referenced`. Returns how many were removed.
+pub fn remove_orphaned_attachments(referenced: &HashSet<String>) -> Result<usize, String> {
+    let Some(dir) = get_attachments_dir() else {
+        return Ok(0);
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(0); // Nothing attached yet
+    };
+
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(stem) = path.file_stem().map(|s| s.to_string_lossy().into_owned()) else {
+            continue;
+        };
+        if path.is_file() && !referenced.contains(&stem) {
+            fs::remove_file(&path).map_err(|e| format!("Failed to delete attachment: {}", e))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
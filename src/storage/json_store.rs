@@ -1,11 +1,12 @@
-use directories::ProjectDirs;
+use directories::{ProjectDirs, UserDirs};
 use serde::{de::DeserializeOwned, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
 use crate::models::{
-    AppConfig, Budget, CategoryColor, ExpensePreset, ProfileData, SharedData, Template,
+    AppConfig, Budget, CategoryColor, CategoryLimits, CategoryOverrides, CategoryParents, ExpensePreset, Payee,
+    PresetOverrides, ProfileBundle, ProfileData, Rule, SharedData, Template, BUNDLE_EXTENSION, BUNDLE_VERSION,
 };
 
 const APP_NAME: &str = "budget-app";
@@ -88,6 +89,12 @@ pub fn save_config(config: &AppConfig) -> Result<(), String> {
 struct CategoriesFile {
     names: Vec<String>,
     colors: HashMap<String, CategoryColor>,
+    /// Subcategory -> parent (missing in files written before subcategories existed)
+    #[serde(default)]
+    parents: CategoryParents,
+    /// Monthly limits (missing in files written before limits existed)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    limits: CategoryLimits,
 }
 
 pub fn load_shared_data() -> SharedData {
@@ -96,12 +103,13 @@ pub fn load_shared_data() -> SharedData {
     };
 
     // Load categories
-    let (categories, category_colors) = load_json::<CategoriesFile>(&shared_dir.join("categories.json"))
-        .map(|c| (c.names, c.colors))
-        .unwrap_or_else(|| {
-            let default = SharedData::default();
-            (default.categories, default.category_colors)
-        });
+    let (categories, category_colors, category_parents, category_limits) =
+        load_json::<CategoriesFile>(&shared_dir.join("categories.json"))
+            .map(|c| (c.names, c.colors, c.parents, c.limits))
+            .unwrap_or_else(|| {
+                let default = SharedData::default();
+                (default.categories, default.category_colors, default.category_parents, default.category_limits)
+            });
 
     // Load presets
     let presets: Vec<ExpensePreset> =
@@ -111,11 +119,23 @@ pub fn load_shared_data() -> SharedData {
     let templates: Vec<Template> =
         load_json(&shared_dir.join("templates.json")).unwrap_or_default();
 
+    // Load auto-categorization rules
+    let rules: Vec<Rule> =
+        load_json(&shared_dir.join("rules.json")).unwrap_or_default();
+
+    // Load payee directory
+    let payees: Vec<Payee> =
+        load_json(&shared_dir.join("payees.json")).unwrap_or_default();
+
     SharedData {
         categories,
         category_colors,
+        category_parents,
+        category_limits,
         presets,
         templates,
+        rules,
+        payees,
     }
 }
 
@@ -123,12 +143,14 @@ pub fn save_shared_data(data: &SharedData) -> Result<(), String> {
     let shared_dir = get_shared_dir().ok_or("Could not get shared dir")?;
     fs::create_dir_all(&shared_dir).map_err(|e| format!("Failed to create shared dir: {}", e))?;
 
-    // Save categories (names + colors together)
+    // Save categories (names, colors and parents together)
     save_json(
         &shared_dir.join("categories.json"),
         &CategoriesFile {
             names: data.categories.clone(),
             colors: data.category_colors.clone(),
+            parents: data.category_parents.clone(),
+            limits: data.category_limits.clone(),
         },
     )?;
 
@@ -138,6 +160,12 @@ pub fn save_shared_data(data: &SharedData) -> Result<(), String> {
     // Save templates
     save_json(&shared_dir.join("templates.json"), &data.templates)?;
 
+    // Save rules
+    save_json(&shared_dir.join("rules.json"), &data.rules)?;
+
+    // Save payee directory
+    save_json(&shared_dir.join("payees.json"), &data.payees)?;
+
     Ok(())
 }
 
@@ -152,6 +180,18 @@ pub fn load_profile(profile_id: &str) -> ProfileData {
     load_json(&path).unwrap_or_default()
 }
 
+/// Like `load_profile`, but a file that can't be read or parsed is an error rather
+/// than an empty profile, so it's safe to save the result back. A profile that was
+/// never saved loads as empty.
+pub fn try_load_profile(profile_id: &str) -> Result<ProfileData, String> {
+    let path = get_profile_path(profile_id).ok_or("Could not get profile path")?;
+    if !path.exists() {
+        return Ok(ProfileData::default());
+    }
+    let contents = fs::read_to_string(&path).map_err(|e| format!("Failed to read profile: {}", e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("Failed to parse profile: {}", e))
+}
+
 pub fn save_profile(profile_id: &str, data: &ProfileData) -> Result<(), String> {
     let path = get_profile_path(profile_id).ok_or("Could not get profile path")?;
     save_json(&path, data)
@@ -170,6 +210,34 @@ pub fn duplicate_profile(source_id: &str, new_id: &str) -> Result<(), String> {
     save_profile(new_id, &source)
 }
 
+// =============================================================================
+// Bundle Export/Import
+// =============================================================================
+
+/// Where exported bundles go by default: Downloads, Documents or the home directory
+pub fn default_bundle_path(profile_id: &str) -> Option<PathBuf> {
+    let dirs = UserDirs::new()?;
+    let dir = dirs
+        .download_dir()
+        .or(dirs.document_dir())
+        .unwrap_or(dirs.home_dir());
+    Some(dir.join(format!("{}.{}", profile_id, BUNDLE_EXTENSION)))
+}
+
+pub fn export_profile_bundle(path: &PathBuf, bundle: &ProfileBundle) -> Result<(), String> {
+    save_json(path, bundle)
+}
+
+pub fn load_profile_bundle(path: &PathBuf) -> Result<ProfileBundle, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let bundle: ProfileBundle =
+        serde_json::from_str(&contents).map_err(|e| format!("Not a profile bundle: {}", e))?;
+    if bundle.version > BUNDLE_VERSION {
+        return Err("This bundle was made by a newer version of the app".to_string());
+    }
+    Ok(bundle)
+}
+
 // =============================================================================
 // Migration (Legacy budget.json -> New Structure)
 // =============================================================================
@@ -201,8 +269,12 @@ pub fn migrate_legacy_budget() -> Result<bool, String> {
     let shared = SharedData {
         categories: old_budget.categories,
         category_colors: old_budget.category_colors,
+        category_parents: old_budget.category_parents,
+        category_limits: old_budget.category_limits,
         templates: old_budget.templates,
         presets: old_budget.presets,
+        rules: Vec::new(),
+        payees: Vec::new(),
     };
     save_shared_data(&shared)?;
 
@@ -210,6 +282,15 @@ pub fn migrate_legacy_budget() -> Result<bool, String> {
     let profile = ProfileData {
         income: old_budget.income,
         expenses: old_budget.expenses,
+        scheduled_income: old_budget.scheduled_income,
+        goals: old_budget.goals,
+        debts: old_budget.debts,
+        accounts: old_budget.accounts,
+        transfers: old_budget.transfers,
+        envelope_mode: old_budget.envelope_mode,
+        envelopes: old_budget.envelopes,
+        category_overrides: CategoryOverrides::default(),
+        preset_overrides: PresetOverrides::default(),
     };
     save_profile("main", &profile)?;
 
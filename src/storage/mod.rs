@@ -1,3 +1,4 @@
+pub mod attachments;
 pub mod json_store;
 
 pub use json_store::{
@@ -8,7 +9,13 @@ pub use json_store::{
     // Shared data functions
     load_shared_data, save_shared_data,
     // Profile functions
-    load_profile, save_profile, delete_profile_file, duplicate_profile,
+    load_profile, try_load_profile, save_profile, delete_profile_file, duplicate_profile,
+    // Bundle functions
+    default_bundle_path, export_profile_bundle, load_profile_bundle,
     // Migration
     migrate_legacy_budget,
 };
+
+pub use attachments::{
+    get_attachment_path, import_attachment, remove_orphaned_attachments,
+};
@@ -0,0 +1,520 @@
+use chrono::NaiveDate;
+use egui::{Color32, ComboBox, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use uuid::Uuid;
+
+use super::amount_input::{evaluate_amount, render_amount_preview};
+use crate::models::{Account, AccountKind, Budget, Reconciliation, Transfer};
+
+/// Actions that can be returned from the accounts panel
+pub enum AccountAction {
+    Create(Account),
+    Delete(Uuid),
+    Transfer(Transfer),
+    /// Accept a statement balance as the new starting point for the account
+    Reconcile(Uuid, Reconciliation),
+}
+
+/// Window listing accounts with balances, transfers and statement reconciliation
+pub struct AccountsPanel {
+    pub is_open: bool,
+    new_name: String,
+    new_kind: AccountKind,
+    new_opening: String,
+    transfer_from: Option<Uuid>,
+    transfer_to: Option<Uuid>,
+    transfer_amount: String,
+    transfer_note: String,
+    /// Account whose register and reconciliation are shown
+    selected: Option<Uuid>,
+    statement_date: String,
+    statement_balance: String,
+}
+
+impl Default for AccountsPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountsPanel {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            new_name: String::new(),
+            new_kind: AccountKind::Checking,
+            new_opening: String::new(),
+            transfer_from: None,
+            transfer_to: None,
+            transfer_amount: String::new(),
+            transfer_note: String::new(),
+            selected: None,
+            statement_date: String::new(),
+            statement_balance: String::new(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.new_name.clear();
+        self.new_opening.clear();
+        self.transfer_amount.clear();
+        self.transfer_note.clear();
+        self.selected = None;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    fn select(&mut self, id: Uuid, today: NaiveDate) {
+        self.selected = Some(id);
+        self.statement_date = today.format("%Y-%m-%d").to_string();
+        self.statement_balance.clear();
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, budget: &Budget, today: NaiveDate) -> Vec<AccountAction> {
+        let mut actions: Vec<AccountAction> = Vec::new();
+
+        if !self.is_open {
+            return actions;
+        }
+
+        // The selected account may have been deleted
+        if self.selected.is_some_and(|id| !budget.accounts.iter().any(|a| a.id == id)) {
+            self.selected = None;
+        }
+
+        egui::Window::new("Accounts")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([500.0, 620.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        if self.selected.is_some() {
+                            let back_btn = egui::Button::new(
+                                RichText::new("←").size(16.0).color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+                            if ui.add(back_btn).on_hover_text("Back to accounts").clicked() {
+                                self.selected = None;
+                            }
+                        }
+
+                        let title = self
+                            .selected
+                            .and_then(|id| budget.accounts.iter().find(|a| a.id == id))
+                            .map_or("Accounts".to_string(), |a| format!("Reconcile {}", a.name));
+                        ui.label(
+                            RichText::new(title)
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(16.0);
+
+                    match self.selected.and_then(|id| budget.accounts.iter().find(|a| a.id == id)) {
+                        Some(account) => self.render_reconcile(ui, budget, account, &mut actions),
+                        None => {
+                            self.render_accounts(ui, budget, today, &mut actions);
+                            if budget.accounts.len() >= 2 {
+                                ui.add_space(12.0);
+                                self.render_transfer_form(ui, budget, today, &mut actions);
+                            }
+                            ui.add_space(12.0);
+                            self.render_new_account_form(ui, &mut actions);
+                        }
+                    }
+                });
+            });
+
+        actions
+    }
+
+    fn render_accounts(
+        &mut self,
+        ui: &mut egui::Ui,
+        budget: &Budget,
+        today: NaiveDate,
+        actions: &mut Vec<AccountAction>,
+    ) {
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.spacing_mut().item_spacing = Vec2::new(8.0, 6.0);
+
+                if budget.accounts.is_empty() {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(40.0);
+                        ui.label(
+                            RichText::new("No accounts yet")
+                                .size(14.0)
+                                .color(Color32::from_rgb(156, 163, 175)),
+                        );
+                    });
+                }
+
+                for account in &budget.accounts {
+                    let balance = budget.account_balance(account);
+                    egui::Frame::none()
+                        .fill(Color32::from_rgb(249, 250, 251))
+                        .rounding(Rounding::same(10.0))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+                        .inner_margin(Margin::symmetric(14.0, 10.0))
+                        .show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+                            ui.horizontal(|ui| {
+                                ui.vertical(|ui| {
+                                    ui.label(
+                                        RichText::new(&account.name)
+                                            .size(14.0)
+                                            .color(Color32::from_rgb(17, 24, 39))
+                                            .strong(),
+                                    );
+                                    let reconciled = account.reconciled.map_or("Never reconciled".to_string(), |r| {
+                                        format!("Reconciled {}", r.date.format("%b %d, %Y"))
+                                    });
+                                    ui.label(
+                                        RichText::new(format!("{} · {}", account.kind.label(), reconciled))
+                                            .size(10.0)
+                                            .color(Color32::from_rgb(107, 114, 128)),
+                                    );
+                                });
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    let del_btn = egui::Button::new(
+                                        RichText::new("X").size(10.0).color(Color32::from_rgb(156, 163, 175)),
+                                    )
+                                    .fill(Color32::TRANSPARENT)
+                                    .stroke(Stroke::NONE);
+                                    if ui
+                                        .add(del_btn)
+                                        .on_hover_text("Delete account and its transfers (expenses are kept)")
+                                        .clicked()
+                                    {
+                                        actions.push(AccountAction::Delete(account.id));
+                                    }
+
+                                    let reconcile_btn = egui::Button::new(
+                                        RichText::new("Reconcile")
+                                            .size(11.0)
+                                            .color(Color32::from_rgb(99, 102, 241)),
+                                    )
+                                    .fill(Color32::from_rgb(238, 242, 255))
+                                    .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                                    .rounding(Rounding::same(6.0));
+                                    if ui.add(reconcile_btn).clicked() {
+                                        self.select(account.id, today);
+                                    }
+
+                                    let color = if balance < 0.0 {
+                                        Color32::from_rgb(220, 38, 38)
+                                    } else {
+                                        Color32::from_rgb(17, 24, 39)
+                                    };
+                                    ui.label(RichText::new(format_signed(balance)).size(14.0).color(color).strong());
+                                });
+                            });
+                        });
+                }
+            });
+    }
+
+    fn render_transfer_form(
+        &mut self,
+        ui: &mut egui::Ui,
+        budget: &Budget,
+        today: NaiveDate,
+        actions: &mut Vec<AccountAction>,
+    ) {
+        ui.label(
+            RichText::new("Transfer")
+                .size(12.0)
+                .color(Color32::from_rgb(100, 100, 110)),
+        );
+        ui.add_space(4.0);
+
+        let name_of = |id: Option<Uuid>| {
+            id.and_then(|id| budget.accounts.iter().find(|a| a.id == id))
+                .map_or("Choose…".to_string(), |a| a.name.clone())
+        };
+
+        ui.horizontal(|ui| {
+            ComboBox::from_id_salt("transfer_from")
+                .selected_text(name_of(self.transfer_from))
+                .width(100.0)
+                .show_ui(ui, |ui| {
+                    for account in &budget.accounts {
+                        ui.selectable_value(&mut self.transfer_from, Some(account.id), &account.name);
+                    }
+                });
+            ui.label(RichText::new("→").size(13.0).color(Color32::from_rgb(107, 114, 128)));
+            ComboBox::from_id_salt("transfer_to")
+                .selected_text(name_of(self.transfer_to))
+                .width(100.0)
+                .show_ui(ui, |ui| {
+                    for account in &budget.accounts {
+                        ui.selectable_value(&mut self.transfer_to, Some(account.id), &account.name);
+                    }
+                });
+            ui.add(TextEdit::singleline(&mut self.transfer_amount).desired_width(60.0).hint_text("0.00"));
+            ui.add(TextEdit::singleline(&mut self.transfer_note).desired_width(70.0).hint_text("Note"));
+
+            let amount = evaluate_amount(&self.transfer_amount).filter(|a| *a > 0.0);
+            let accounts = match (self.transfer_from, self.transfer_to) {
+                (Some(from), Some(to)) if from != to => Some((from, to)),
+                _ => None,
+            };
+            let can_move = amount.is_some() && accounts.is_some();
+            let move_btn = egui::Button::new(
+                RichText::new("Move")
+                    .size(12.0)
+                    .color(if can_move { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+            )
+            .fill(if can_move { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(50.0, 26.0));
+
+            if ui.add(move_btn).on_hover_text("Transfers don't count as spending").clicked()
+                && let (Some(amount), Some((from, to))) = (amount, accounts)
+            {
+                actions.push(AccountAction::Transfer(Transfer::new(
+                    from,
+                    to,
+                    amount,
+                    today,
+                    self.transfer_note.trim().to_string(),
+                )));
+                self.transfer_amount.clear();
+                self.transfer_note.clear();
+            }
+        });
+    }
+
+    fn render_new_account_form(&mut self, ui: &mut egui::Ui, actions: &mut Vec<AccountAction>) {
+        ui.label(
+            RichText::new("New Account")
+                .size(12.0)
+                .color(Color32::from_rgb(100, 100, 110)),
+        );
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            ui.add(
+                TextEdit::singleline(&mut self.new_name)
+                    .desired_width(130.0)
+                    .hint_text("Everyday checking"),
+            );
+            ComboBox::from_id_salt("new_account_kind")
+                .selected_text(self.new_kind.label())
+                .width(100.0)
+                .show_ui(ui, |ui| {
+                    for kind in AccountKind::ALL {
+                        ui.selectable_value(&mut self.new_kind, kind, kind.label());
+                    }
+                });
+            ui.add(
+                TextEdit::singleline(&mut self.new_opening)
+                    .desired_width(80.0)
+                    .hint_text("Opening"),
+            )
+            .on_hover_text("Opening balance - negative if owed, e.g. a credit card");
+            render_amount_preview(ui, &self.new_opening);
+
+            // Empty means zero; negative balances are allowed here
+            let opening = if self.new_opening.trim().is_empty() {
+                Some(0.0)
+            } else {
+                evaluate_amount(&self.new_opening)
+            };
+            let can_add = !self.new_name.trim().is_empty() && opening.is_some();
+
+            let add_btn = egui::Button::new(
+                RichText::new("Add")
+                    .size(12.0)
+                    .color(if can_add { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+            )
+            .fill(if can_add { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(50.0, 26.0));
+
+            if ui.add(add_btn).clicked()
+                && let Some(opening) = opening
+                && !self.new_name.trim().is_empty()
+            {
+                actions.push(AccountAction::Create(Account::new(
+                    self.new_name.trim().to_string(),
+                    self.new_kind,
+                    opening,
+                )));
+                self.new_name.clear();
+                self.new_opening.clear();
+            }
+        });
+    }
+
+    /// Register since the last reconciliation and a statement balance to match
+    fn render_reconcile(
+        &mut self,
+        ui: &mut egui::Ui,
+        budget: &Budget,
+        account: &Account,
+        actions: &mut Vec<AccountAction>,
+    ) {
+        let muted = Color32::from_rgb(107, 114, 128);
+        let register = budget.account_register(account);
+        let start = account.reconciled.map_or(account.opening_balance, |r| r.statement_balance);
+
+        let start_label = match account.reconciled {
+            Some(r) => format!("Reconciled balance on {}", r.date.format("%b %d, %Y")),
+            None => "Opening balance".to_string(),
+        };
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(start_label).size(12.0).color(muted));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.label(RichText::new(format_signed(start)).size(12.0).color(muted));
+            });
+        });
+        ui.add_space(6.0);
+
+        // Running balance
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if register.is_empty() {
+                    ui.label(
+                        RichText::new("No activity since then")
+                            .size(12.0)
+                            .color(Color32::from_rgb(156, 163, 175)),
+                    );
+                }
+                egui::Grid::new("account_register")
+                    .striped(true)
+                    .num_columns(4)
+                    .spacing(Vec2::new(16.0, 4.0))
+                    .show(ui, |ui| {
+                        for entry in &register {
+                            ui.label(RichText::new(entry.date.format("%b %d").to_string()).size(11.0).color(muted));
+                            ui.label(RichText::new(&entry.description).size(11.0));
+                            let color = if entry.amount < 0.0 {
+                                Color32::from_rgb(220, 38, 38)
+                            } else {
+                                Color32::from_rgb(5, 150, 105)
+                            };
+                            ui.label(RichText::new(format_signed(entry.amount)).size(11.0).color(color));
+                            ui.label(RichText::new(format_signed(entry.balance)).size(11.0).strong());
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        ui.add_space(12.0);
+
+        // Statement to match
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Statement date").size(12.0).color(muted));
+            ui.add(TextEdit::singleline(&mut self.statement_date).desired_width(90.0).hint_text("YYYY-MM-DD"));
+            ui.label(RichText::new("Ending balance $").size(12.0).color(muted));
+            ui.add(TextEdit::singleline(&mut self.statement_balance).desired_width(80.0).hint_text("0.00"));
+        });
+
+        let date = NaiveDate::parse_from_str(self.statement_date.trim(), "%Y-%m-%d").ok();
+        let statement = evaluate_amount(&self.statement_balance);
+        let (Some(date), Some(statement)) = (date, statement) else {
+            return;
+        };
+        // Reconciling earlier than the last reconciliation would drop activity
+        if account.reconciled.is_some_and(|r| date < r.date) {
+            ui.label(
+                RichText::new("Statement date is before the last reconciliation")
+                    .size(12.0)
+                    .color(Color32::from_rgb(220, 38, 38)),
+            );
+            return;
+        }
+
+        let computed = budget.account_balance_on(account, date);
+        let difference = statement - computed;
+        let matched = difference.abs() < 0.005;
+
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(format!("Tracked balance {}", format_signed(computed)))
+                    .size(12.0)
+                    .color(muted),
+            );
+            let (text, color) = if matched {
+                ("Matches the statement".to_string(), Color32::from_rgb(5, 150, 105))
+            } else {
+                (format!("Off by {}", format_signed(difference)), Color32::from_rgb(220, 38, 38))
+            };
+            ui.label(RichText::new(text).size(12.0).color(color).strong());
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let label = if matched { "Mark Reconciled" } else { "Accept Statement" };
+                let reconcile_btn = egui::Button::new(RichText::new(label).size(12.0).color(Color32::WHITE))
+                    .fill(Color32::from_rgb(99, 102, 241))
+                    .stroke(Stroke::NONE)
+                    .rounding(Rounding::same(6.0))
+                    .min_size(Vec2::new(110.0, 28.0));
+                let hint = if matched {
+                    "Start future balances from this statement"
+                } else {
+                    "Use the statement balance from now on, absorbing the difference"
+                };
+                if ui.add(reconcile_btn).on_hover_text(hint).clicked() {
+                    actions.push(AccountAction::Reconcile(
+                        account.id,
+                        Reconciliation { date, statement_balance: statement },
+                    ));
+                    self.statement_balance.clear();
+                }
+            });
+        });
+    }
+}
+
+/// "$12.34" or "-$12.34"
+fn format_signed(amount: f64) -> String {
+    if amount < 0.0 {
+        format!("-${:.2}", -amount)
+    } else {
+        format!("${:.2}", amount)
+    }
+}
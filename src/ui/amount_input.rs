@@ -0,0 +1,226 @@
+use egui::{Color32, RichText, Ui};
+
+/// Evaluate an amount field. Accepts a plain number or an arithmetic expression
+/// (see `evaluate_expression`). The result is rounded to cents.
+pub fn evaluate_amount(input: &str) -> Option<f64> {
+    evaluate_expression(input).map(|value| (value * 100.0).round() / 100.0)
+}
+
+/// Evaluate an arithmetic expression with + − × ÷ (or * /), the usual precedence,
+/// parentheses and percentages: "12.50+3.99", "240/3", "80+15%" (80 plus 15% of 80).
+/// `$` and spaces are ignored. Commas may group thousands ("1,250.00") or be a
+/// decimal comma ("12,50"); anything else with a comma is invalid. Returns None for
+/// invalid input, division by zero or a non-finite result.
+pub fn evaluate_expression(input: &str) -> Option<f64> {
+    let input = normalize_commas(input)?;
+    let chars: Vec<char> = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '$')
+        .map(|c| match c {
+            '−' => '-',
+            '×' | 'x' | 'X' => '*',
+            '÷' => '/',
+            other => other,
+        })
+        .collect();
+    if chars.is_empty() {
+        return None;
+    }
+
+    let mut parser = Parser { chars: &chars, pos: 0 };
+    let value = parser.expression()?;
+    (parser.pos == chars.len() && value.is_finite()).then_some(value)
+}
+
+/// Rewrite the commas in each number: thousands groups ("1,250.00") are dropped and
+/// a lone comma before 1-2 digits ("12,50") becomes a decimal point. None if a
+/// number has commas that are neither, e.g. "1,2345" or "1,234,5".
+fn normalize_commas(input: &str) -> Option<String> {
+    let mut result = String::with_capacity(input.len());
+    let mut number = String::new();
+    for c in input.chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_digit() || c == '.' || c == ',' {
+            number.push(c);
+            continue;
+        }
+        if number.contains(',') {
+            let (whole, fraction) = number.split_once('.').unwrap_or((&number, ""));
+            let groups: Vec<&str> = whole.split(',').collect();
+            let is_thousands = (1..=3).contains(&groups[0].len())
+                && groups[1..].iter().all(|g| g.len() == 3 && g.chars().all(|d| d.is_ascii_digit()));
+            let is_decimal_comma = !number.contains('.')
+                && groups.len() == 2
+                && !groups[0].is_empty()
+                && (1..=2).contains(&groups[1].len());
+            if is_thousands {
+                result.push_str(&whole.replace(',', ""));
+                if number.contains('.') {
+                    result.push('.');
+                    result.push_str(fraction);
+                }
+            } else if is_decimal_comma {
+                result.push_str(&number.replace(',', "."));
+            } else {
+                return None;
+            }
+        } else {
+            result.push_str(&number);
+        }
+        number.clear();
+        result.push(c);
+    }
+    result.pop(); // The trailing space added above
+    Some(result)
+}
+
+/// True if the input is more than a plain number, so a preview is worth showing
+fn is_expression(input: &str) -> bool {
+    input.trim().parse::<f64>().is_err()
+}
+
+/// Small "= 16.49" preview shown next to an amount field while typing an expression
+pub fn render_amount_preview(ui: &mut Ui, input: &str) {
+    if input.trim().is_empty() || !is_expression(input) {
+        return;
+    }
+    match evaluate_amount(input) {
+        Some(value) => ui.label(
+            RichText::new(format!("= {:.2}", value))
+                .size(12.0)
+                .color(Color32::from_rgb(107, 114, 128)),
+        ),
+        None => ui.label(
+            RichText::new("= ?")
+                .size(12.0)
+                .color(Color32::from_rgb(239, 68, 68)),
+        ),
+    };
+}
+
+/// Recursive descent parser over the normalized characters
+struct Parser<'a> {
+    chars: &'a [char],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// expression := term (('+' | '-') term)*
+    /// A bare percentage term is relative to the running total: 80+15% = 92
+    fn expression(&mut self) -> Option<f64> {
+        let (mut value, _) = self.term()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.pos += 1;
+            let (rhs, is_percent) = self.term()?;
+            let rhs = if is_percent { value * rhs } else { rhs };
+            value = if op == '+' { value + rhs } else { value - rhs };
+        }
+        Some(value)
+    }
+
+    /// term := factor (('*' | '/') factor)*
+    /// Returns whether the term was a single percentage (as a fraction)
+    fn term(&mut self) -> Option<(f64, bool)> {
+        let (mut value, mut is_percent) = self.factor()?;
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.pos += 1;
+            let (rhs, _) = self.factor()?;
+            if op == '*' {
+                value *= rhs;
+            } else {
+                if rhs == 0.0 {
+                    return None;
+                }
+                value /= rhs;
+            }
+            is_percent = false;
+        }
+        Some((value, is_percent))
+    }
+
+    /// factor := ('-' | '+') factor | primary '%'?
+    fn factor(&mut self) -> Option<(f64, bool)> {
+        match self.peek()? {
+            '-' => {
+                self.pos += 1;
+                let (value, is_percent) = self.factor()?;
+                Some((-value, is_percent))
+            }
+            '+' => {
+                self.pos += 1;
+                self.factor()
+            }
+            _ => {
+                let value = self.primary()?;
+                if self.peek() == Some('%') {
+                    self.pos += 1;
+                    Some((value / 100.0, true))
+                } else {
+                    Some((value, false))
+                }
+            }
+        }
+    }
+
+    /// primary := number | '(' expression ')'
+    fn primary(&mut self) -> Option<f64> {
+        if self.peek()? == '(' {
+            self.pos += 1;
+            let value = self.expression()?;
+            if self.peek()? != ')' {
+                return None;
+            }
+            self.pos += 1;
+            return Some(value);
+        }
+
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        let number: String = self.chars[start..self.pos].iter().collect();
+        number.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_numbers_and_expressions() {
+        assert_eq!(evaluate_amount("12.50"), Some(12.5));
+        assert_eq!(evaluate_amount("12.50+3.99"), Some(16.49));
+        assert_eq!(evaluate_amount("240/3"), Some(80.0));
+        assert_eq!(evaluate_amount("80+15%"), Some(92.0));
+        assert_eq!(evaluate_amount("$ 40 × 2"), Some(80.0));
+        assert_eq!(evaluate_amount("10/0"), None);
+    }
+
+    #[test]
+    fn thousands_separators() {
+        assert_eq!(evaluate_amount("1,250"), Some(1250.0));
+        assert_eq!(evaluate_amount("1,250.75"), Some(1250.75));
+        assert_eq!(evaluate_amount("$1,234,567.10"), Some(1234567.1));
+        assert_eq!(evaluate_amount("1,250+2,000"), Some(3250.0));
+    }
+
+    #[test]
+    fn decimal_comma() {
+        assert_eq!(evaluate_amount("12,50"), Some(12.5));
+        assert_eq!(evaluate_amount("12,5"), Some(12.5));
+        assert_eq!(evaluate_amount("0,99+1,01"), Some(2.0));
+    }
+
+    #[test]
+    fn ambiguous_commas_are_rejected() {
+        assert_eq!(evaluate_amount("1,2345"), None);
+        assert_eq!(evaluate_amount("1,234,5"), None);
+        assert_eq!(evaluate_amount("1234,567"), None);
+        assert_eq!(evaluate_amount(",50"), None);
+        assert_eq!(evaluate_amount("1,50.25"), None);
+    }
+}
@@ -0,0 +1,187 @@
+use egui::{Color32, Margin, RichText, Rounding, Stroke, Vec2};
+
+use crate::models::Budget;
+
+/// Read-only window onto an archived profile, so its data can be looked at
+/// without restoring it
+pub struct ArchivedView {
+    pub is_open: bool,
+    name: String,
+    /// The profile's budget, or why it couldn't be loaded
+    budget: Result<Budget, String>,
+}
+
+impl Default for ArchivedView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArchivedView {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            name: String::new(),
+            budget: Ok(Budget::new()),
+        }
+    }
+
+    pub fn open(&mut self, name: String, budget: Result<Budget, String>) {
+        self.is_open = true;
+        self.name = name;
+        self.budget = budget;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.budget = Ok(Budget::new());
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context) {
+        if !self.is_open {
+            return;
+        }
+
+        let muted = Color32::from_rgb(107, 114, 128);
+        let dark = Color32::from_rgb(17, 24, 39);
+
+        egui::Window::new("Archived Profile")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([520.0, 560.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(&self.name)
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.label(
+                        RichText::new("Archived profiles are read-only. Restore it to make changes.")
+                            .size(12.0)
+                            .color(muted),
+                    );
+                    ui.add_space(12.0);
+
+                    let budget = match &self.budget {
+                        Ok(budget) => budget,
+                        Err(e) => {
+                            ui.label(
+                                RichText::new(format!("Couldn't open this profile: {}", e))
+                                    .size(12.0)
+                                    .color(Color32::from_rgb(220, 38, 38)),
+                            );
+                            return;
+                        }
+                    };
+
+                    render_totals(ui, budget);
+                    ui.add_space(16.0);
+
+                    ui.label(RichText::new("Expenses").size(13.0).color(Color32::from_rgb(100, 100, 110)).strong());
+                    ui.add_space(4.0);
+
+                    if budget.expenses.is_empty() {
+                        ui.label(RichText::new("No expenses").size(12.0).color(Color32::from_rgb(156, 163, 175)));
+                        return;
+                    }
+
+                    let mut expenses: Vec<_> = budget.expenses.iter().collect();
+                    expenses.sort_by_key(|e| std::cmp::Reverse(e.date));
+
+                    egui::ScrollArea::vertical()
+                        .max_height(360.0)
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            egui::Grid::new("archived_expenses_grid")
+                                .num_columns(4)
+                                .striped(true)
+                                .spacing(Vec2::new(16.0, 6.0))
+                                .show(ui, |ui| {
+                                    for expense in expenses {
+                                        let color = if expense.active { dark } else { Color32::from_rgb(180, 180, 180) };
+                                        let title = if !expense.description.is_empty() {
+                                            &expense.description
+                                        } else if !expense.payee.is_empty() {
+                                            &expense.payee
+                                        } else {
+                                            &expense.category
+                                        };
+                                        ui.label(RichText::new(expense.date.format("%b %d, %Y").to_string()).size(12.0).color(muted));
+                                        ui.label(RichText::new(title).size(12.0).color(color));
+                                        ui.label(RichText::new(&expense.category).size(11.0).color(muted));
+                                        ui.label(RichText::new(format!("${:.2}", expense.amount)).size(12.0).color(color).strong());
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                });
+            });
+    }
+}
+
+/// Income, spending and balance cards
+fn render_totals(ui: &mut egui::Ui, budget: &Budget) {
+    let balance = budget.remaining_balance();
+    let balance_color = if balance < 0.0 {
+        Color32::from_rgb(220, 38, 38)
+    } else {
+        Color32::from_rgb(5, 150, 105)
+    };
+    let cards = [
+        ("Income", budget.total_income(), Color32::from_rgb(17, 24, 39)),
+        ("Expenses", budget.total_expenses(), Color32::from_rgb(17, 24, 39)),
+        ("Balance", balance, balance_color),
+    ];
+
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing = Vec2::new(8.0, 8.0);
+        let width = (ui.available_width() - 16.0) / 3.0;
+        for (label, amount, color) in cards {
+            let sign = if amount < -0.005 { "-" } else { "" };
+            egui::Frame::none()
+                .fill(Color32::from_rgb(249, 250, 251))
+                .rounding(Rounding::same(12.0))
+                .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+                .inner_margin(Margin::same(12.0))
+                .show(ui, |ui| {
+                    ui.set_width(width - 24.0);
+                    ui.label(RichText::new(label).size(11.0).color(Color32::from_rgb(107, 114, 128)));
+                    ui.label(RichText::new(format!("{}${:.2}", sign, amount.abs())).size(18.0).color(color).strong());
+                });
+        }
+    });
+}
@@ -0,0 +1,346 @@
+use egui::{Color32, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use std::path::PathBuf;
+
+use crate::models::{CategoryColor, CategoryConflict, ConflictResolution, ProfileBundle};
+
+/// Actions that can be returned from the bundle dialog
+pub enum BundleAction {
+    Export {
+        profile_id: String,
+        path: PathBuf,
+        include_presets: bool,
+        include_templates: bool,
+    },
+    /// Read a bundle file to preview it before importing
+    Load(PathBuf),
+    /// Import a previewed bundle as a new profile with the chosen conflict resolutions
+    Import(Box<ProfileBundle>, Vec<CategoryConflict>),
+}
+
+enum Mode {
+    Export { profile_id: String, profile_name: String },
+    Import,
+}
+
+/// Dialog for exporting a profile to a bundle file and importing one
+pub struct BundleDialog {
+    pub is_open: bool,
+    mode: Mode,
+    path: String,
+    include_presets: bool,
+    include_templates: bool,
+    /// Bundle read from `path` with its category conflicts, waiting for confirmation
+    loaded: Option<(ProfileBundle, Vec<CategoryConflict>)>,
+    /// Outcome of the last export, load or import
+    message: Option<Result<String, String>>,
+}
+
+impl Default for BundleDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BundleDialog {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            mode: Mode::Import,
+            path: String::new(),
+            include_presets: true,
+            include_templates: true,
+            loaded: None,
+            message: None,
+        }
+    }
+
+    pub fn open_export(&mut self, profile_id: String, profile_name: String, default_path: Option<PathBuf>) {
+        self.is_open = true;
+        self.mode = Mode::Export { profile_id, profile_name };
+        self.path = default_path.map(|p| p.display().to_string()).unwrap_or_default();
+        self.loaded = None;
+        self.message = None;
+    }
+
+    pub fn open_import(&mut self) {
+        self.is_open = true;
+        self.mode = Mode::Import;
+        self.path.clear();
+        self.loaded = None;
+        self.message = None;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.loaded = None;
+        self.message = None;
+    }
+
+    /// Show a bundle read from disk for review
+    pub fn set_loaded(&mut self, bundle: ProfileBundle, conflicts: Vec<CategoryConflict>) {
+        self.loaded = Some((bundle, conflicts));
+        self.message = None;
+    }
+
+    pub fn set_message(&mut self, message: Result<String, String>) {
+        self.message = Some(message);
+    }
+
+    /// `categories` are the local categories, to check new names against
+    pub fn render(&mut self, ctx: &egui::Context, categories: &[String]) -> Option<BundleAction> {
+        let mut action = None;
+
+        if !self.is_open {
+            return action;
+        }
+
+        // A bundle file dropped on the window is loaded right away
+        if matches!(self.mode, Mode::Import)
+            && let Some(path) = ctx.input(|i| i.raw.dropped_files.iter().find_map(|f| f.path.clone()))
+        {
+            self.path = path.display().to_string();
+            action = Some(BundleAction::Load(path));
+        }
+
+        let title = match &self.mode {
+            Mode::Export { .. } => "Export Profile",
+            Mode::Import => "Import Profile",
+        };
+
+        egui::Window::new("Profile Bundle")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([480.0, 0.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(title)
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(12.0);
+
+                    match &self.mode {
+                        Mode::Export { profile_id, profile_name } => {
+                            if let Some(export) =
+                                self.render_export(ui, profile_id.clone(), profile_name.clone())
+                            {
+                                action = Some(export);
+                            }
+                        }
+                        Mode::Import => {
+                            if let Some(import) = self.render_import(ui, categories) {
+                                action = Some(import);
+                            }
+                        }
+                    }
+
+                    if let Some(message) = &self.message {
+                        ui.add_space(12.0);
+                        let (text, color) = match message {
+                            Ok(text) => (text, Color32::from_rgb(5, 150, 105)),
+                            Err(text) => (text, Color32::from_rgb(220, 38, 38)),
+                        };
+                        ui.label(RichText::new(text).size(12.0).color(color));
+                    }
+                });
+            });
+
+        action
+    }
+
+    fn render_export(&mut self, ui: &mut egui::Ui, profile_id: String, profile_name: String) -> Option<BundleAction> {
+        let muted = Color32::from_rgb(107, 114, 128);
+
+        ui.label(
+            RichText::new(format!(
+                "Saves \"{}\" and the categories it uses to a single file you can import on another machine. \
+                 Receipts aren't included.",
+                profile_name
+            ))
+            .size(12.0)
+            .color(muted),
+        );
+        ui.add_space(8.0);
+        ui.checkbox(&mut self.include_presets, "Include the presets it uses");
+        ui.checkbox(&mut self.include_templates, "Include the templates it uses");
+        ui.add_space(8.0);
+
+        ui.label(RichText::new("Save to").size(12.0).color(muted));
+        ui.add(TextEdit::singleline(&mut self.path).desired_width(f32::INFINITY));
+        ui.add_space(12.0);
+
+        let path = self.path.trim();
+        let can_export = !path.is_empty();
+        if primary_button(ui, "Export", can_export) {
+            return Some(BundleAction::Export {
+                profile_id,
+                path: PathBuf::from(path),
+                include_presets: self.include_presets,
+                include_templates: self.include_templates,
+            });
+        }
+        None
+    }
+
+    fn render_import(&mut self, ui: &mut egui::Ui, categories: &[String]) -> Option<BundleAction> {
+        let muted = Color32::from_rgb(107, 114, 128);
+
+        ui.label(
+            RichText::new("Enter the path of a bundle file, or drop it on this window.")
+                .size(12.0)
+                .color(muted),
+        );
+        ui.add_space(8.0);
+
+        let mut action = None;
+        ui.horizontal(|ui| {
+            let response = ui.add(TextEdit::singleline(&mut self.path).desired_width(340.0));
+            let path = self.path.trim();
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if (ui.add_enabled(!path.is_empty(), egui::Button::new("Open")).clicked() || submitted) && !path.is_empty() {
+                action = Some(BundleAction::Load(PathBuf::from(path)));
+            }
+        });
+
+        let Some((bundle, conflicts)) = &mut self.loaded else {
+            return action;
+        };
+
+        ui.add_space(12.0);
+        ui.label(
+            RichText::new(&bundle.name)
+                .size(14.0)
+                .color(Color32::from_rgb(17, 24, 39))
+                .strong(),
+        );
+        ui.label(
+            RichText::new(format!(
+                "Exported {} · {} expenses · {} categories · {} presets · {} templates",
+                bundle.exported_at.format("%b %d, %Y"),
+                bundle.profile.expenses.len(),
+                bundle.categories.len(),
+                bundle.presets.len(),
+                bundle.templates.len(),
+            ))
+            .size(11.0)
+            .color(muted),
+        );
+
+        let mut valid = true;
+        if !conflicts.is_empty() {
+            ui.add_space(12.0);
+            ui.label(
+                RichText::new("These categories already exist here with a different color")
+                    .size(12.0)
+                    .color(Color32::from_rgb(100, 100, 110))
+                    .strong(),
+            );
+            ui.add_space(4.0);
+
+            egui::ScrollArea::vertical().max_height(220.0).show(ui, |ui| {
+                for conflict in conflicts.iter_mut() {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(&conflict.name).size(12.0).color(Color32::from_rgb(17, 24, 39)));
+                        if conflict.local_name != conflict.name {
+                            ui.label(
+                                RichText::new(format!("(\"{}\" here)", conflict.local_name))
+                                    .size(11.0)
+                                    .color(muted),
+                            );
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.add_space(8.0);
+                        swatch(ui, conflict.local_color);
+                        if ui.radio(conflict.resolution == ConflictResolution::KeepLocal, "Keep mine").clicked() {
+                            conflict.resolution = ConflictResolution::KeepLocal;
+                        }
+                        swatch(ui, conflict.bundle_color);
+                        if ui
+                            .radio(conflict.resolution == ConflictResolution::UseBundleColor, "Use theirs")
+                            .clicked()
+                        {
+                            conflict.resolution = ConflictResolution::UseBundleColor;
+                        }
+                        let is_rename = matches!(conflict.resolution, ConflictResolution::Rename(_));
+                        if ui.radio(is_rename, "Import as").clicked() && !is_rename {
+                            conflict.resolution =
+                                ConflictResolution::Rename(format!("{} ({})", conflict.name, bundle.name));
+                        }
+                        if let ConflictResolution::Rename(new_name) = &mut conflict.resolution {
+                            let taken = new_name.trim().is_empty()
+                                || categories.iter().any(|c| c.eq_ignore_ascii_case(new_name.trim()));
+                            valid &= !taken;
+                            let response = ui.add(TextEdit::singleline(new_name).desired_width(120.0));
+                            if taken {
+                                response.on_hover_text("Pick a name that isn't already a category");
+                            }
+                        }
+                    });
+                    ui.add_space(4.0);
+                }
+            });
+        }
+
+        ui.add_space(12.0);
+        if primary_button(ui, "Import as new profile", valid)
+            && let Some((bundle, conflicts)) = self.loaded.take()
+        {
+            action = Some(BundleAction::Import(Box::new(bundle), conflicts));
+        }
+        action
+    }
+}
+
+fn primary_button(ui: &mut egui::Ui, label: &str, enabled: bool) -> bool {
+    let button = egui::Button::new(
+        RichText::new(label)
+            .size(12.0)
+            .color(if enabled { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+    )
+    .fill(if enabled { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+    .stroke(Stroke::NONE)
+    .rounding(Rounding::same(6.0))
+    .min_size(Vec2::new(80.0, 28.0));
+    ui.add(button).clicked() && enabled
+}
+
+fn swatch(ui: &mut egui::Ui, color: CategoryColor) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::new(12.0, 12.0), egui::Sense::hover());
+    ui.painter()
+        .rect_filled(rect, Rounding::same(3.0), Color32::from_rgb(color[0], color[1], color[2]));
+}
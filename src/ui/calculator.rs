@@ -1,20 +1,36 @@
-use egui::{Color32, Margin, RichText, Rounding, Stroke, Vec2};
+use egui::{Color32, Key, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+
+use super::amount_input::evaluate_expression;
+use crate::models::Budget;
+
+/// Number of memory registers (M1, M2, ...)
+const MEMORY_REGISTERS: usize = 3;
+
+/// Oldest tape entries are dropped past this length
+const MAX_TAPE_ENTRIES: usize = 50;
+
+/// Actions that send the calculator result to another form
+pub enum CalculatorAction {
+    UseAsExpense(f64),
+    UseAsIncome(f64),
+}
+
+/// A finished calculation shown on the tape
+struct TapeEntry {
+    expression: String,
+    result: f64,
+}
 
 pub struct Calculator {
     pub is_open: bool,
-    display: String,
-    current_value: f64,
-    pending_operation: Option<Operation>,
-    pending_value: f64,
+    /// Expression being typed (editable directly or through the keypad)
+    input: String,
+    /// The input holds a result from "=", so the next digit starts a new expression
     just_calculated: bool,
-}
-
-#[derive(Clone, Copy)]
-enum Operation {
-    Add,
-    Subtract,
-    Multiply,
-    Divide,
+    /// Set when "=" was pressed on an invalid expression
+    error: bool,
+    tape: Vec<TapeEntry>,
+    memory: [Option<f64>; MEMORY_REGISTERS],
 }
 
 impl Default for Calculator {
@@ -27,11 +43,11 @@ impl Calculator {
     pub fn new() -> Self {
         Self {
             is_open: false,
-            display: "0".to_string(),
-            current_value: 0.0,
-            pending_operation: None,
-            pending_value: 0.0,
+            input: String::new(),
             just_calculated: false,
+            error: false,
+            tape: Vec::new(),
+            memory: [None; MEMORY_REGISTERS],
         }
     }
 
@@ -44,81 +60,82 @@ impl Calculator {
     }
 
     fn clear(&mut self) {
-        self.display = "0".to_string();
-        self.current_value = 0.0;
-        self.pending_operation = None;
-        self.pending_value = 0.0;
+        self.input.clear();
         self.just_calculated = false;
+        self.error = false;
     }
 
-    fn input_digit(&mut self, digit: &str) {
-        if self.just_calculated {
-            self.display = digit.to_string();
-            self.just_calculated = false;
-        } else if self.display == "0" && digit != "." {
-            self.display = digit.to_string();
-        } else if digit == "." && self.display.contains('.') {
-            // Don't add another decimal point
-        } else {
-            self.display.push_str(digit);
+    /// Current value of the expression, if it's valid
+    fn value(&self) -> Option<f64> {
+        evaluate_expression(&self.input)
+    }
+
+    /// Append keypad input. Digits after a result start a new expression,
+    /// operators continue from the result.
+    fn push(&mut self, text: &str) {
+        let starts_number = text.chars().all(|c| c.is_ascii_digit() || c == '.' || c == '(');
+        if self.just_calculated && starts_number {
+            self.input.clear();
         }
-        self.current_value = self.display.parse().unwrap_or(0.0);
+        self.input.push_str(text);
+        self.just_calculated = false;
+        self.error = false;
     }
 
-    fn set_operation(&mut self, op: Operation) {
-        if self.pending_operation.is_some() && !self.just_calculated {
-            self.calculate();
+    /// Insert a value (tape, memory or live budget value). Follows an open operator
+    /// or parenthesis, otherwise replaces the input.
+    fn insert_value(&mut self, value: f64) {
+        let continues = self
+            .input
+            .trim_end()
+            .ends_with(['+', '-', '−', '*', '×', '/', '÷', '(']);
+        if !continues {
+            self.input.clear();
         }
-        self.pending_value = self.current_value;
-        self.pending_operation = Some(op);
-        self.just_calculated = true;
+        self.input.push_str(&format_number(value));
+        self.just_calculated = false;
+        self.error = false;
     }
 
     fn calculate(&mut self) {
-        if let Some(op) = self.pending_operation {
-            let result = match op {
-                Operation::Add => self.pending_value + self.current_value,
-                Operation::Subtract => self.pending_value - self.current_value,
-                Operation::Multiply => self.pending_value * self.current_value,
-                Operation::Divide => {
-                    if self.current_value != 0.0 {
-                        self.pending_value / self.current_value
-                    } else {
-                        0.0 // Avoid division by zero
-                    }
+        if self.input.trim().is_empty() {
+            return;
+        }
+        match self.value() {
+            Some(result) => {
+                self.tape.push(TapeEntry {
+                    expression: self.input.trim().to_string(),
+                    result,
+                });
+                if self.tape.len() > MAX_TAPE_ENTRIES {
+                    self.tape.remove(0);
                 }
-            };
-            self.current_value = result;
-            self.display = format_number(result);
-            self.pending_operation = None;
-            self.just_calculated = true;
+                self.input = format_number(result);
+                self.just_calculated = true;
+                self.error = false;
+            }
+            None => self.error = true,
         }
     }
 
     fn backspace(&mut self) {
-        if self.display.len() > 1 {
-            self.display.pop();
-        } else {
-            self.display = "0".to_string();
-        }
-        self.current_value = self.display.parse().unwrap_or(0.0);
-    }
-
-    fn negate(&mut self) {
-        self.current_value = -self.current_value;
-        self.display = format_number(self.current_value);
+        self.input.pop();
+        self.just_calculated = false;
+        self.error = false;
     }
 
-    pub fn render(&mut self, ctx: &egui::Context) {
+    pub fn render(&mut self, ctx: &egui::Context, budget: &Budget) -> Option<CalculatorAction> {
         if !self.is_open {
-            return;
+            return None;
         }
 
+        let mut action: Option<CalculatorAction> = None;
+
         egui::Window::new("Calculator")
             .collapsible(false)
             .resizable(false)
             .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-            .fixed_size([280.0, 340.0])
+            .fixed_size([520.0, 470.0])
             .frame(
                 egui::Frame::none()
                     .fill(Color32::WHITE)
@@ -160,113 +177,309 @@ impl Calculator {
 
                     ui.add_space(8.0);
 
-                    // Button grid dimensions
-                    let button_size = Vec2::new(54.0, 44.0);
-                    let spacing = 8.0;
-                    let total_width = button_size.x * 4.0 + spacing * 3.0; // 240px
-
-                    // Display - matches button grid width, single row height
-                    let (display_rect, _) = ui.allocate_exact_size(
-                        Vec2::new(total_width, 32.0),
-                        egui::Sense::hover(),
-                    );
-                    ui.painter().rect_filled(
-                        display_rect,
-                        Rounding::same(6.0),
-                        Color32::from_rgb(245, 247, 250),
-                    );
-                    ui.painter().text(
-                        display_rect.right_center() - Vec2::new(10.0, 0.0),
-                        egui::Align2::RIGHT_CENTER,
-                        &self.display,
-                        egui::FontId::new(18.0, egui::FontFamily::Monospace),
-                        Color32::from_rgb(17, 24, 39),
-                    );
-
-                    ui.add_space(8.0);
+                    ui.horizontal_top(|ui| {
+                        // Left: display and keypad
+                        ui.vertical(|ui| {
+                            self.render_keypad(ui);
+                        });
 
-                    // Button grid
-                    ui.spacing_mut().item_spacing = Vec2::new(spacing, spacing);
+                        ui.add_space(16.0);
 
-                    // Row 1: C, +/-, ←, ÷
-                    ui.horizontal(|ui| {
-                        if calc_button(ui, "C", button_size, ButtonStyle::Function).clicked() {
-                            self.clear();
-                        }
-                        if calc_button(ui, "+/-", button_size, ButtonStyle::Function).clicked() {
-                            self.negate();
-                        }
-                        if calc_button(ui, "←", button_size, ButtonStyle::Function).clicked() {
-                            self.backspace();
-                        }
-                        if calc_button(ui, "÷", button_size, ButtonStyle::Operator).clicked() {
-                            self.set_operation(Operation::Divide);
-                        }
+                        // Right: tape, memory and live values
+                        ui.vertical(|ui| {
+                            ui.set_width(216.0);
+                            self.render_tape(ui);
+                            ui.add_space(12.0);
+                            self.render_memory(ui);
+                            ui.add_space(12.0);
+                            self.render_live_values(ui, budget);
+                        });
                     });
 
-                    // Row 2: 7, 8, 9, ×
-                    ui.horizontal(|ui| {
-                        if calc_button(ui, "7", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("7");
-                        }
-                        if calc_button(ui, "8", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("8");
-                        }
-                        if calc_button(ui, "9", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("9");
-                        }
-                        if calc_button(ui, "×", button_size, ButtonStyle::Operator).clicked() {
-                            self.set_operation(Operation::Multiply);
-                        }
-                    });
+                    ui.add_space(12.0);
 
-                    // Row 3: 4, 5, 6, -
+                    // Send the result to the expense or income form
+                    let value = self.value();
                     ui.horizontal(|ui| {
-                        if calc_button(ui, "4", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("4");
-                        }
-                        if calc_button(ui, "5", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("5");
-                        }
-                        if calc_button(ui, "6", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("6");
-                        }
-                        if calc_button(ui, "-", button_size, ButtonStyle::Operator).clicked() {
-                            self.set_operation(Operation::Subtract);
+                        let can_use = value.is_some_and(|v| v > 0.0);
+                        let expense_btn = egui::Button::new(
+                            RichText::new("Use as expense amount")
+                                .size(12.0)
+                                .color(if can_use { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+                        )
+                        .fill(if can_use { Color32::from_rgb(239, 68, 68) } else { Color32::from_rgb(220, 220, 225) })
+                        .stroke(Stroke::NONE)
+                        .rounding(Rounding::same(8.0))
+                        .min_size(Vec2::new(170.0, 32.0));
+                        if ui.add(expense_btn).clicked()
+                            && let Some(v) = value.filter(|v| *v > 0.0)
+                        {
+                            action = Some(CalculatorAction::UseAsExpense(v));
                         }
-                    });
 
-                    // Row 4: 1, 2, 3, +
-                    ui.horizontal(|ui| {
-                        if calc_button(ui, "1", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("1");
-                        }
-                        if calc_button(ui, "2", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("2");
-                        }
-                        if calc_button(ui, "3", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("3");
-                        }
-                        if calc_button(ui, "+", button_size, ButtonStyle::Operator).clicked() {
-                            self.set_operation(Operation::Add);
+                        let can_use_income = value.is_some_and(|v| v >= 0.0);
+                        let income_btn = egui::Button::new(
+                            RichText::new("Use as income")
+                                .size(12.0)
+                                .color(if can_use_income { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+                        )
+                        .fill(if can_use_income { Color32::from_rgb(16, 185, 129) } else { Color32::from_rgb(220, 220, 225) })
+                        .stroke(Stroke::NONE)
+                        .rounding(Rounding::same(8.0))
+                        .min_size(Vec2::new(130.0, 32.0));
+                        if ui.add(income_btn).clicked()
+                            && let Some(v) = value.filter(|v| *v >= 0.0)
+                        {
+                            action = Some(CalculatorAction::UseAsIncome(v));
                         }
                     });
+                });
+            });
 
-                    // Row 5: 0 (wide), ., =
-                    ui.horizontal(|ui| {
-                        let wide_size = Vec2::new(button_size.x * 2.0 + spacing, button_size.y);
-                        if calc_button(ui, "0", wide_size, ButtonStyle::Number).clicked() {
-                            self.input_digit("0");
+        if action.is_some() {
+            self.close();
+        }
+
+        action
+    }
+
+    fn render_keypad(&mut self, ui: &mut egui::Ui) {
+        // Button grid dimensions
+        let button_size = Vec2::new(54.0, 40.0);
+        let spacing = 8.0;
+        let total_width = button_size.x * 4.0 + spacing * 3.0; // 240px
+
+        // Expression display - type directly or use the keypad, Enter evaluates
+        egui::Frame::none()
+            .fill(Color32::from_rgb(245, 247, 250))
+            .rounding(Rounding::same(6.0))
+            .inner_margin(Margin::symmetric(10.0, 6.0))
+            .show(ui, |ui| {
+                ui.set_width(total_width - 20.0);
+                let response = ui.add(
+                    TextEdit::singleline(&mut self.input)
+                        .desired_width(f32::INFINITY)
+                        .horizontal_align(egui::Align::RIGHT)
+                        .font(egui::FontId::new(18.0, egui::FontFamily::Monospace))
+                        .hint_text("0")
+                        .frame(false),
+                );
+                if response.changed() {
+                    self.just_calculated = false;
+                    self.error = false;
+                }
+                if response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+                    self.calculate();
+                }
+
+                // Live result (or the error from the last "=")
+                let preview = if self.error {
+                    RichText::new("Invalid expression").color(Color32::from_rgb(239, 68, 68))
+                } else {
+                    match self.value() {
+                        Some(v) if !self.just_calculated => {
+                            RichText::new(format!("= {}", format_number(v))).color(Color32::from_rgb(107, 114, 128))
                         }
-                        if calc_button(ui, ".", button_size, ButtonStyle::Number).clicked() {
-                            self.input_digit(".");
+                        _ => RichText::new(" "),
+                    }
+                };
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.label(preview.size(11.0));
+                });
+            });
+
+        ui.add_space(8.0);
+
+        // Button grid
+        ui.spacing_mut().item_spacing = Vec2::new(spacing, spacing);
+
+        // Row 1: C, ←, (, )
+        ui.horizontal(|ui| {
+            if calc_button(ui, "C", button_size, ButtonStyle::Function).clicked() {
+                self.clear();
+            }
+            if calc_button(ui, "←", button_size, ButtonStyle::Function).clicked() {
+                self.backspace();
+            }
+            if calc_button(ui, "(", button_size, ButtonStyle::Function).clicked() {
+                self.push("(");
+            }
+            if calc_button(ui, ")", button_size, ButtonStyle::Function).clicked() {
+                self.push(")");
+            }
+        });
+
+        // Rows 2-4: digits with ÷ × − on the right
+        for (digits, operator) in [(["7", "8", "9"], "÷"), (["4", "5", "6"], "×"), (["1", "2", "3"], "−")] {
+            ui.horizontal(|ui| {
+                for digit in digits {
+                    if calc_button(ui, digit, button_size, ButtonStyle::Number).clicked() {
+                        self.push(digit);
+                    }
+                }
+                if calc_button(ui, operator, button_size, ButtonStyle::Operator).clicked() {
+                    self.push(operator);
+                }
+            });
+        }
+
+        // Row 5: %, 0, ., +
+        ui.horizontal(|ui| {
+            if calc_button(ui, "%", button_size, ButtonStyle::Function).clicked() {
+                self.push("%");
+            }
+            if calc_button(ui, "0", button_size, ButtonStyle::Number).clicked() {
+                self.push("0");
+            }
+            if calc_button(ui, ".", button_size, ButtonStyle::Number).clicked() {
+                self.push(".");
+            }
+            if calc_button(ui, "+", button_size, ButtonStyle::Operator).clicked() {
+                self.push("+");
+            }
+        });
+
+        // Row 6: = (full width)
+        if calc_button(ui, "=", Vec2::new(total_width, button_size.y), ButtonStyle::Equals).clicked() {
+            self.calculate();
+        }
+    }
+
+    /// Past calculations, newest first. Click one to reuse its result.
+    fn render_tape(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new("Tape")
+                    .size(12.0)
+                    .color(Color32::from_rgb(100, 100, 110)),
+            );
+            if !self.tape.is_empty() {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("Clear").clicked() {
+                        self.tape.clear();
+                    }
+                });
+            }
+        });
+        ui.add_space(4.0);
+
+        let mut reuse: Option<f64> = None;
+        egui::Frame::none()
+            .fill(Color32::from_rgb(249, 250, 251))
+            .rounding(Rounding::same(8.0))
+            .inner_margin(Margin::same(8.0))
+            .show(ui, |ui| {
+                egui::ScrollArea::vertical()
+                    .max_height(110.0)
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        if self.tape.is_empty() {
+                            ui.label(
+                                RichText::new("No calculations yet")
+                                    .size(11.0)
+                                    .color(Color32::from_rgb(156, 163, 175)),
+                            );
                         }
-                        if calc_button(ui, "=", button_size, ButtonStyle::Equals).clicked() {
-                            self.calculate();
+                        for entry in self.tape.iter().rev() {
+                            let line = egui::Label::new(
+                                RichText::new(format!("{} = {}", entry.expression, format_number(entry.result)))
+                                    .size(11.0)
+                                    .family(egui::FontFamily::Monospace)
+                                    .color(Color32::from_rgb(55, 65, 81)),
+                            )
+                            .truncate()
+                            .sense(egui::Sense::click());
+                            if ui.add(line).on_hover_text("Use this result").clicked() {
+                                reuse = Some(entry.result);
+                            }
                         }
                     });
+            });
+
+        if let Some(value) = reuse {
+            self.insert_value(value);
+        }
+    }
+
+    fn render_memory(&mut self, ui: &mut egui::Ui) {
+        ui.label(
+            RichText::new("Memory")
+                .size(12.0)
+                .color(Color32::from_rgb(100, 100, 110)),
+        );
+        ui.add_space(4.0);
+
+        let value = self.value();
+        ui.spacing_mut().item_spacing = Vec2::new(4.0, 4.0);
+        for idx in 0..MEMORY_REGISTERS {
+            ui.horizontal(|ui| {
+                let stored = self.memory[idx];
+                ui.label(
+                    RichText::new(format!("M{}", idx + 1))
+                        .size(11.0)
+                        .color(Color32::from_rgb(99, 102, 241))
+                        .strong(),
+                );
+                ui.label(
+                    RichText::new(stored.map(format_number).unwrap_or_else(|| "—".to_string()))
+                        .size(11.0)
+                        .family(egui::FontFamily::Monospace)
+                        .color(Color32::from_rgb(55, 65, 81)),
+                );
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.add_enabled(stored.is_some(), egui::Button::new("MC").small()).clicked() {
+                        self.memory[idx] = None;
+                    }
+                    if ui.add_enabled(value.is_some(), egui::Button::new("M−").small()).clicked()
+                        && let Some(v) = value
+                    {
+                        self.memory[idx] = Some(stored.unwrap_or(0.0) - v);
+                    }
+                    if ui.add_enabled(value.is_some(), egui::Button::new("M+").small()).clicked()
+                        && let Some(v) = value
+                    {
+                        self.memory[idx] = Some(stored.unwrap_or(0.0) + v);
+                    }
+                    if ui.add_enabled(stored.is_some(), egui::Button::new("MR").small()).clicked()
+                        && let Some(v) = stored
+                    {
+                        self.insert_value(v);
+                    }
                 });
             });
+        }
+    }
+
+    /// Current budget figures that can be pulled into the expression
+    fn render_live_values(&mut self, ui: &mut egui::Ui, budget: &Budget) {
+        ui.label(
+            RichText::new("Recall")
+                .size(12.0)
+                .color(Color32::from_rgb(100, 100, 110)),
+        );
+        ui.add_space(4.0);
+
+        let values = [
+            ("Remaining", budget.remaining_balance()),
+            ("Income", budget.total_income()),
+            ("Spent", budget.total_expenses()),
+        ];
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(4.0, 4.0);
+            for (label, value) in values {
+                let chip = egui::Button::new(
+                    RichText::new(format!("{} ${:.2}", label, value))
+                        .size(11.0)
+                        .color(Color32::from_rgb(79, 70, 229)),
+                )
+                .fill(Color32::from_rgb(238, 242, 255))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(10.0));
+                if ui.add(chip).on_hover_text("Insert into the expression").clicked() {
+                    self.insert_value(value);
+                }
+            }
+        });
     }
 }
 
@@ -0,0 +1,301 @@
+use chrono::{Datelike, Months, NaiveDate};
+use egui::{Align2, Color32, FontId, Pos2, Rect, RichText, Rounding, Stroke, Ui, Vec2};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::models::{end_of_month, Budget, Expense, ExpensePreset};
+
+/// Entries drawn inside a day cell before "+N more"
+const MAX_CELL_ENTRIES: usize = 3;
+
+pub enum CalendarAction {
+    /// Add an expense from a preset on this date (dropped or ghost clicked)
+    AddPreset(Uuid, NaiveDate),
+    /// Open the expense form on this date
+    AddExpenseOn(NaiveDate),
+}
+
+/// Month grid of the budget: expenses per day, daily totals and recurring
+/// presets that are still due shown as ghost entries
+pub struct CalendarView {
+    year: i32,
+    month: u32,
+    /// Day cells from the last frame, used to find where a preset was dropped
+    day_rects: Vec<(NaiveDate, Rect)>,
+}
+
+impl Default for CalendarView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CalendarView {
+    pub fn new() -> Self {
+        let today = chrono::Local::now().date_naive();
+        Self {
+            year: today.year(),
+            month: today.month(),
+            day_rects: Vec::new(),
+        }
+    }
+
+    /// Day under a screen position, if it's over the calendar
+    pub fn day_at(&self, pos: Pos2) -> Option<NaiveDate> {
+        self.day_rects.iter().find(|(_, rect)| rect.contains(pos)).map(|(date, _)| *date)
+    }
+
+    fn first_of_month(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1).unwrap_or_default()
+    }
+
+    fn shift_month(&mut self, forward: bool) {
+        let first = self.first_of_month();
+        let shifted = if forward {
+            first.checked_add_months(Months::new(1))
+        } else {
+            first.checked_sub_months(Months::new(1))
+        };
+        if let Some(date) = shifted {
+            self.year = date.year();
+            self.month = date.month();
+        }
+    }
+
+    /// `dragging_preset` highlights the day under the pointer as a drop target
+    pub fn render(
+        &mut self,
+        ui: &mut Ui,
+        budget: &Budget,
+        today: NaiveDate,
+        dragging_preset: bool,
+        height: f32,
+    ) -> Option<CalendarAction> {
+        let mut action = None;
+        let first = self.first_of_month();
+
+        // Month navigation
+        ui.horizontal(|ui| {
+            let nav_btn = |text: &str| {
+                egui::Button::new(RichText::new(text).size(18.0).color(Color32::from_rgb(107, 114, 128)))
+                    .fill(Color32::TRANSPARENT)
+                    .stroke(Stroke::NONE)
+                    .min_size(Vec2::new(28.0, 28.0))
+            };
+            if ui.add(nav_btn("‹")).clicked() {
+                self.shift_month(false);
+            }
+            ui.label(
+                RichText::new(first.format("%B %Y").to_string())
+                    .size(15.0)
+                    .color(Color32::from_rgb(17, 24, 39))
+                    .strong(),
+            );
+            if ui.add(nav_btn("›")).clicked() {
+                self.shift_month(true);
+            }
+            let is_current = self.year == today.year() && self.month == today.month();
+            if !is_current && ui.small_button("Today").clicked() {
+                self.year = today.year();
+                self.month = today.month();
+            }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let month_total: f64 = budget
+                    .expenses
+                    .iter()
+                    .filter(|e| e.active && e.date.year() == self.year && e.date.month() == self.month)
+                    .map(|e| e.amount)
+                    .sum();
+                ui.label(
+                    RichText::new(format!("-${:.2} this month", month_total))
+                        .size(12.0)
+                        .color(Color32::from_rgb(156, 163, 175)),
+                );
+            });
+        });
+        ui.add_space(6.0);
+
+        // Group the month's expenses and still-due presets by day
+        let mut by_day: HashMap<u32, Vec<&Expense>> = HashMap::new();
+        for expense in &budget.expenses {
+            if expense.date.year() == self.year && expense.date.month() == self.month {
+                by_day.entry(expense.date.day()).or_default().push(expense);
+            }
+        }
+        let mut ghosts: HashMap<u32, Vec<&ExpensePreset>> = HashMap::new();
+        for (date, preset) in budget.unpaid_recurring(first) {
+            if date >= today {
+                ghosts.entry(date.day()).or_default().push(preset);
+            }
+        }
+
+        // Weekday headers
+        let width = ui.available_width();
+        let cell_width = width / 7.0;
+        let (header_rect, _) = ui.allocate_exact_size(Vec2::new(width, 18.0), egui::Sense::hover());
+        for (i, name) in ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"].iter().enumerate() {
+            ui.painter().text(
+                Pos2::new(header_rect.left() + cell_width * (i as f32 + 0.5), header_rect.center().y),
+                Align2::CENTER_CENTER,
+                name,
+                FontId::proportional(11.0),
+                Color32::from_rgb(156, 163, 175),
+            );
+        }
+
+        // Grid
+        let offset = first.weekday().num_days_from_sunday();
+        let days = end_of_month(first).day();
+        let weeks = (offset + days).div_ceil(7);
+        let cell_height = ((height - 60.0) / weeks as f32).clamp(56.0, 110.0);
+        let (grid_rect, _) = ui.allocate_exact_size(Vec2::new(width, cell_height * weeks as f32), egui::Sense::hover());
+        let pointer = ui.ctx().pointer_latest_pos();
+
+        self.day_rects.clear();
+        for day in 1..=days {
+            let index = offset + day - 1;
+            let (row, col) = (index / 7, index % 7);
+            let cell = Rect::from_min_size(
+                Pos2::new(grid_rect.left() + col as f32 * cell_width, grid_rect.top() + row as f32 * cell_height),
+                Vec2::new(cell_width, cell_height),
+            )
+            .shrink(2.0);
+            let Some(date) = first.with_day(day) else { continue };
+            self.day_rects.push((date, cell));
+
+            let expenses = by_day.get(&day).map(Vec::as_slice).unwrap_or_default();
+            let due = ghosts.get(&day).map(Vec::as_slice).unwrap_or_default();
+            let is_drop_target = dragging_preset && pointer.is_some_and(|p| cell.contains(p));
+
+            let response = ui.interact(cell, ui.id().with(("calendar_day", date)), egui::Sense::click());
+            let painter = ui.painter().with_clip_rect(cell);
+
+            let (fill, stroke) = if is_drop_target {
+                (Color32::from_rgb(238, 242, 255), Stroke::new(2.0, Color32::from_rgb(99, 102, 241)))
+            } else if date == today {
+                (Color32::from_rgb(249, 250, 251), Stroke::new(1.5, Color32::from_rgb(165, 180, 252)))
+            } else if response.hovered() {
+                (Color32::from_rgb(249, 250, 251), Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+            } else {
+                (Color32::WHITE, Stroke::new(1.0, Color32::from_rgb(243, 244, 246)))
+            };
+            painter.rect(cell, Rounding::same(8.0), fill, stroke);
+
+            // Day number and daily total
+            let day_color = if date == today { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(55, 65, 81) };
+            painter.text(
+                cell.left_top() + Vec2::new(6.0, 4.0),
+                Align2::LEFT_TOP,
+                day.to_string(),
+                FontId::proportional(12.0),
+                day_color,
+            );
+            let total: f64 = expenses.iter().filter(|e| e.active).map(|e| e.amount).sum();
+            if total > 0.0 {
+                painter.text(
+                    cell.right_top() + Vec2::new(-6.0, 5.0),
+                    Align2::RIGHT_TOP,
+                    format!("${:.0}", total),
+                    FontId::proportional(10.0),
+                    Color32::from_rgb(220, 38, 38),
+                );
+            }
+
+            // Entries: expenses colored by category, then ghost presets
+            let mut y = cell.top() + 22.0;
+            let line_height = 14.0;
+            let room = ((cell.bottom() - y - 2.0) / line_height).floor().max(0.0) as usize;
+            let shown = room.min(MAX_CELL_ENTRIES);
+            let entry_count = expenses.len() + due.len();
+            let mut drawn = 0;
+            for expense in expenses.iter().take(shown) {
+                let c = budget.get_category_color(&expense.category);
+                let color = if expense.active {
+                    Color32::from_rgb(c[0], c[1], c[2])
+                } else {
+                    Color32::from_rgb(200, 200, 200)
+                };
+                let pill = Rect::from_min_size(Pos2::new(cell.left() + 4.0, y), Vec2::new(cell.width() - 8.0, 12.0));
+                painter.rect_filled(pill, Rounding::same(3.0), color.gamma_multiply(0.18));
+                painter.rect_filled(Rect::from_min_size(pill.min, Vec2::new(3.0, 12.0)), Rounding::same(1.5), color);
+                painter.text(
+                    pill.left_center() + Vec2::new(6.0, 0.0),
+                    Align2::LEFT_CENTER,
+                    entry_title(expense),
+                    FontId::proportional(10.0),
+                    Color32::from_rgb(55, 65, 81),
+                );
+                y += line_height;
+                drawn += 1;
+            }
+            for preset in due.iter().take(shown.saturating_sub(drawn)) {
+                let pill = Rect::from_min_size(Pos2::new(cell.left() + 4.0, y), Vec2::new(cell.width() - 8.0, 12.0));
+                painter.rect_stroke(pill, Rounding::same(3.0), Stroke::new(1.0, Color32::from_rgb(209, 213, 219)));
+                painter.text(
+                    pill.left_center() + Vec2::new(4.0, 0.0),
+                    Align2::LEFT_CENTER,
+                    format!("{} ${:.0}", preset.name, preset.amount),
+                    FontId::proportional(10.0),
+                    Color32::from_rgb(156, 163, 175),
+                );
+                y += line_height;
+                drawn += 1;
+            }
+            if entry_count > drawn {
+                painter.text(
+                    Pos2::new(cell.left() + 6.0, cell.bottom() - 3.0),
+                    Align2::LEFT_BOTTOM,
+                    format!("+{} more", entry_count - drawn),
+                    FontId::proportional(9.0),
+                    Color32::from_rgb(156, 163, 175),
+                );
+            }
+
+            // Full list on hover; ghosts can be recorded from the context menu
+            let response = if entry_count > 0 {
+                response.on_hover_ui(|ui| {
+                    ui.label(RichText::new(date.format("%A, %b %d").to_string()).strong());
+                    for expense in expenses {
+                        ui.label(format!("{} · ${:.2}", entry_title(expense), expense.amount));
+                    }
+                    for preset in due {
+                        ui.label(
+                            RichText::new(format!("Due: {} · ${:.2}", preset.name, preset.amount))
+                                .color(Color32::from_rgb(156, 163, 175)),
+                        );
+                    }
+                })
+            } else {
+                response
+            };
+            if response.double_clicked() {
+                action = Some(CalendarAction::AddExpenseOn(date));
+            }
+            response.context_menu(|ui| {
+                if ui.button("Add expense on this day…").clicked() {
+                    action = Some(CalendarAction::AddExpenseOn(date));
+                    ui.close_menu();
+                }
+                for preset in due {
+                    if ui.button(format!("Record {} (${:.2})", preset.name, preset.amount)).clicked() {
+                        action = Some(CalendarAction::AddPreset(preset.id, date));
+                        ui.close_menu();
+                    }
+                }
+            });
+        }
+
+        action
+    }
+}
+
+fn entry_title(expense: &Expense) -> &str {
+    if !expense.description.is_empty() {
+        &expense.description
+    } else if !expense.payee.is_empty() {
+        &expense.payee
+    } else {
+        &expense.category
+    }
+}
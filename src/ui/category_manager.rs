@@ -1,7 +1,10 @@
-use egui::{Color32, Margin, RichText, Rounding, Stroke, TextEdit, Ui, Vec2};
+use egui::{Color32, ComboBox, Margin, RichText, Rounding, Stroke, TextEdit, Ui, Vec2};
 use std::collections::HashMap;
 
-use crate::models::CategoryColor;
+use super::amount_input::evaluate_amount;
+use crate::models::{
+    CategoryColor, CategoryLimits, CategoryOverrides, CategoryParents, RolloverPolicy, DEFAULT_CATEGORY_COLOR,
+};
 
 /// Preset colors for the color picker
 const COLOR_PRESETS: &[[u8; 3]] = &[
@@ -23,15 +26,35 @@ const COLOR_PRESETS: &[[u8; 3]] = &[
 
 pub enum CategoryAction {
     Add(String, CategoryColor),
+    /// Add a category that only exists in the current profile
+    AddToProfile(String, CategoryColor),
+    /// Add a subcategory (name, parent) that inherits the parent's color
+    AddSubcategory(String, String),
     Delete(String),
     UpdateColor(String, CategoryColor),
+    /// Go back to inheriting the parent's color
+    ResetColor(String),
+    /// Move a category under a parent, or to the top level with None
+    SetParent(String, Option<String>),
+    /// Set a monthly limit and rollover policy, or clear it with None
+    SetLimit(String, Option<(f64, RolloverPolicy)>),
+    /// Hide or show a shared category in the current profile
+    SetHidden(String, bool),
 }
 
 pub struct CategoryManager {
     pub is_open: bool,
     new_category_input: String,
     new_category_color: CategoryColor,
+    /// Parent for the new category (None = top level)
+    new_category_parent: Option<String>,
+    /// Add the new category to the current profile instead of the shared list
+    new_category_profile_only: bool,
     color_picker_open_for: Option<String>, // None = new category, Some(name) = existing category
+    /// Limit being edited for the category whose picker is open
+    limit_amount: String,
+    limit_policy: RolloverPolicy,
+    limit_cap: String,
 }
 
 impl Default for CategoryManager {
@@ -46,7 +69,12 @@ impl CategoryManager {
             is_open: false,
             new_category_input: String::new(),
             new_category_color: [59, 130, 246], // Default blue
+            new_category_parent: None,
+            new_category_profile_only: false,
             color_picker_open_for: None,
+            limit_amount: String::new(),
+            limit_policy: RolloverPolicy::None,
+            limit_cap: String::new(),
         }
     }
 
@@ -54,6 +82,8 @@ impl CategoryManager {
         self.is_open = true;
         self.new_category_input.clear();
         self.new_category_color = [59, 130, 246];
+        self.new_category_parent = None;
+        self.new_category_profile_only = false;
         self.color_picker_open_for = None;
     }
 
@@ -63,11 +93,16 @@ impl CategoryManager {
         self.color_picker_open_for = None;
     }
 
+    /// `categories` should be in tree order, including the ones the profile hides and
+    /// its own; `category_colors` holds only explicitly set colors
     pub fn render(
         &mut self,
         ctx: &egui::Context,
         categories: &[String],
         category_colors: &HashMap<String, CategoryColor>,
+        category_parents: &CategoryParents,
+        category_limits: &CategoryLimits,
+        overrides: &CategoryOverrides,
     ) -> Vec<CategoryAction> {
         let mut actions: Vec<CategoryAction> = Vec::new();
 
@@ -79,7 +114,7 @@ impl CategoryManager {
             .collapsible(false)
             .resizable(false)
             .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-            .fixed_size([380.0, 480.0])
+            .fixed_size([380.0, 520.0])
             .frame(egui::Frame::none()
                 .fill(Color32::WHITE)
                 .rounding(Rounding::same(16.0))
@@ -125,30 +160,42 @@ impl CategoryManager {
                     );
                     ui.add_space(4.0);
 
-                    ui.horizontal(|ui| {
-                        // Color swatch with dropdown
-                        let color = Color32::from_rgb(
-                            self.new_category_color[0],
-                            self.new_category_color[1],
-                            self.new_category_color[2],
-                        );
-                        let (rect, response) = ui.allocate_exact_size(Vec2::splat(28.0), egui::Sense::click());
-                        ui.painter().rect_filled(rect, Rounding::same(6.0), color);
-                        ui.painter().rect_stroke(rect, Rounding::same(6.0), Stroke::new(1.0, Color32::from_rgb(200, 200, 210)));
+                    // Profile categories can't have subcategories, since shared ones would
+                    // end up under a parent other profiles don't have
+                    let top_level: Vec<&String> = categories
+                        .iter()
+                        .filter(|c| !category_parents.contains_key(*c) && !overrides.contains(c))
+                        .collect();
+                    if self.new_category_profile_only {
+                        self.new_category_parent = None;
+                    }
 
-                        if response.clicked() {
-                            self.color_picker_open_for = if self.color_picker_open_for == Some("__new__".to_string()) {
-                                None
-                            } else {
-                                Some("__new__".to_string())
-                            };
+                    ui.horizontal(|ui| {
+                        if self.new_category_parent.is_none() {
+                            // Color swatch with dropdown
+                            let color = Color32::from_rgb(
+                                self.new_category_color[0],
+                                self.new_category_color[1],
+                                self.new_category_color[2],
+                            );
+                            let (rect, response) = ui.allocate_exact_size(Vec2::splat(28.0), egui::Sense::click());
+                            ui.painter().rect_filled(rect, Rounding::same(6.0), color);
+                            ui.painter().rect_stroke(rect, Rounding::same(6.0), Stroke::new(1.0, Color32::from_rgb(200, 200, 210)));
+
+                            if response.clicked() {
+                                self.color_picker_open_for = if self.color_picker_open_for == Some("__new__".to_string()) {
+                                    None
+                                } else {
+                                    Some("__new__".to_string())
+                                };
+                            }
+                            response.on_hover_text("Click to choose color");
                         }
-                        response.on_hover_text("Click to choose color");
 
                         let text_response = ui.add(
                             TextEdit::singleline(&mut self.new_category_input)
-                                .desired_width(180.0)
-                                .hint_text("Category name"),
+                                .desired_width(if self.new_category_parent.is_none() { 180.0 } else { 216.0 })
+                                .hint_text(if self.new_category_parent.is_none() { "Category name" } else { "Subcategory name" }),
                         );
 
                         let add_btn = egui::Button::new(
@@ -162,16 +209,61 @@ impl CategoryManager {
                             || (text_response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)));
 
                         if should_add && !self.new_category_input.trim().is_empty() {
-                            actions.push(CategoryAction::Add(
-                                self.new_category_input.clone(),
-                                self.new_category_color,
-                            ));
+                            actions.push(match &self.new_category_parent {
+                                None if self.new_category_profile_only => CategoryAction::AddToProfile(
+                                    self.new_category_input.clone(),
+                                    self.new_category_color,
+                                ),
+                                Some(parent) => CategoryAction::AddSubcategory(
+                                    self.new_category_input.clone(),
+                                    parent.clone(),
+                                ),
+                                None => CategoryAction::Add(
+                                    self.new_category_input.clone(),
+                                    self.new_category_color,
+                                ),
+                            });
                             self.new_category_input.clear();
                             self.new_category_color = [59, 130, 246];
                             self.color_picker_open_for = None;
                         }
                     });
 
+                    // Optional parent for the new category
+                    ui.add_space(6.0);
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.new_category_profile_only, "Only in this profile");
+                        if self.new_category_profile_only {
+                            return;
+                        }
+                        ui.add_space(8.0);
+                        ui.label(
+                            RichText::new("Under")
+                                .size(12.0)
+                                .color(Color32::from_rgb(100, 100, 110)),
+                        );
+                        ComboBox::from_id_salt("new_category_parent")
+                            .width(120.0)
+                            .selected_text(self.new_category_parent.as_deref().unwrap_or("Top level"))
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut self.new_category_parent, None, "Top level");
+                                for parent in &top_level {
+                                    ui.selectable_value(&mut self.new_category_parent, Some((*parent).clone()), parent.as_str());
+                                }
+                            });
+                        if self.new_category_parent.is_some() {
+                            ui.label(
+                                RichText::new("Uses parent color")
+                                    .size(11.0)
+                                    .color(Color32::from_rgb(150, 150, 160)),
+                            );
+                        }
+                    });
+                    // The chosen parent may have been deleted or moved
+                    if self.new_category_parent.as_ref().is_some_and(|p| !top_level.contains(&p)) {
+                        self.new_category_parent = None;
+                    }
+
                     // Color picker for new category
                     if self.color_picker_open_for == Some("__new__".to_string()) {
                         ui.add_space(8.0);
@@ -225,93 +317,223 @@ impl CategoryManager {
                                 );
                             } else {
                                 for category in categories {
-                                    let cat_color = category_colors
-                                        .get(category)
-                                        .copied()
-                                        .unwrap_or([156, 163, 175]);
+                                    let parent = category_parents.get(category);
+                                    let own_color = category_colors.get(category).copied();
+                                    let cat_color = own_color
+                                        .or_else(|| parent.and_then(|p| category_colors.get(p)).copied())
+                                        .unwrap_or(DEFAULT_CATEGORY_COLOR);
                                     let bg_color = Color32::from_rgb(cat_color[0], cat_color[1], cat_color[2]);
                                     let is_picker_open = self.color_picker_open_for == Some(category.clone());
+                                    let has_children = category_parents.values().any(|p| p == category);
+                                    let is_own = overrides.contains(category);
+                                    let is_hidden = !is_own && !overrides.shows(category, category_parents);
+                                    let hidden_with_parent = parent.is_some_and(|p| overrides.hidden.contains(p));
+
+                                    ui.horizontal(|ui| {
+                                        // Indent subcategories under their parent
+                                        if parent.is_some() {
+                                            ui.add_space(20.0);
+                                        }
 
-                                    egui::Frame::none()
-                                        .fill(bg_color.gamma_multiply(0.15))
-                                        .rounding(Rounding::same(8.0))
-                                        .stroke(Stroke::new(1.0, bg_color.gamma_multiply(0.3)))
-                                        .inner_margin(Margin::symmetric(12.0, 10.0))
-                                        .show(ui, |ui| {
-                                            ui.vertical(|ui| {
-                                                ui.horizontal(|ui| {
-                                                    // Color picker button
-                                                    let (rect, response) = ui.allocate_exact_size(Vec2::splat(24.0), egui::Sense::click());
-                                                    ui.painter().rect_filled(rect, Rounding::same(4.0), bg_color);
-                                                    ui.painter().rect_stroke(rect, Rounding::same(4.0), Stroke::new(1.0, bg_color.gamma_multiply(0.7)));
-
-                                                    if response.clicked() {
-                                                        self.color_picker_open_for = if is_picker_open {
-                                                            None
+                                        // Hidden categories are dimmed but stay editable for other profiles
+                                        let tint = if is_hidden { 0.05 } else { 0.15 };
+                                        egui::Frame::none()
+                                            .fill(bg_color.gamma_multiply(tint))
+                                            .rounding(Rounding::same(8.0))
+                                            .stroke(Stroke::new(1.0, bg_color.gamma_multiply(0.3)))
+                                            .inner_margin(Margin::symmetric(12.0, 10.0))
+                                            .show(ui, |ui| {
+                                                ui.vertical(|ui| {
+                                                    ui.horizontal(|ui| {
+                                                        // Color picker button
+                                                        let (rect, response) = ui.allocate_exact_size(Vec2::splat(24.0), egui::Sense::click());
+                                                        ui.painter().rect_filled(rect, Rounding::same(4.0), bg_color);
+                                                        ui.painter().rect_stroke(rect, Rounding::same(4.0), Stroke::new(1.0, bg_color.gamma_multiply(0.7)));
+
+                                                        if response.clicked() {
+                                                            self.color_picker_open_for = if is_picker_open {
+                                                                None
+                                                            } else {
+                                                                self.load_limit(category_limits, category);
+                                                                Some(category.clone())
+                                                            };
+                                                        }
+                                                        response.on_hover_text(if is_own {
+                                                            "Click to change color"
                                                         } else {
-                                                            Some(category.clone())
-                                                        };
-                                                    }
-                                                    response.on_hover_text("Click to choose color");
-
-                                                    ui.add_space(8.0);
-
-                                                    ui.label(
-                                                        RichText::new(category)
-                                                            .size(13.0)
-                                                            .color(Color32::from_rgb(50, 50, 60)),
-                                                    );
+                                                            "Click to change color, parent or limit"
+                                                        });
 
-                                                    ui.with_layout(
-                                                        egui::Layout::right_to_left(egui::Align::Center),
-                                                        |ui| {
-                                                            let del_btn = egui::Button::new(
-                                                                RichText::new("Delete")
+                                                        ui.add_space(8.0);
+
+                                                        ui.label(
+                                                            RichText::new(category)
+                                                                .size(13.0)
+                                                                .color(if is_hidden {
+                                                                    Color32::from_rgb(150, 150, 160)
+                                                                } else {
+                                                                    Color32::from_rgb(50, 50, 60)
+                                                                }),
+                                                        );
+                                                        if is_own {
+                                                            ui.label(
+                                                                RichText::new("This profile")
+                                                                    .size(10.0)
+                                                                    .color(Color32::from_rgb(99, 102, 241)),
+                                                            )
+                                                            .on_hover_text("Only shown in the current profile");
+                                                        }
+                                                        if let Some(limit) = category_limits.get(category) {
+                                                            ui.label(
+                                                                RichText::new(format!("${:.0}/mo", limit.amount))
                                                                     .size(11.0)
-                                                                    .color(Color32::from_rgb(220, 38, 38)),
+                                                                    .color(Color32::from_rgb(100, 100, 110)),
                                                             )
-                                                            .fill(Color32::from_rgb(254, 242, 242))
-                                                            .stroke(Stroke::new(1.0, Color32::from_rgb(254, 202, 202)))
-                                                            .rounding(Rounding::same(4.0));
-
-                                                            if ui.add(del_btn).clicked() {
-                                                                actions.push(CategoryAction::Delete(category.clone()));
-                                                            }
-                                                        },
-                                                    );
-                                                });
+                                                            .on_hover_text(limit.rollover.label());
+                                                        }
+
+                                                        ui.with_layout(
+                                                            egui::Layout::right_to_left(egui::Align::Center),
+                                                            |ui| {
+                                                                let del_btn = egui::Button::new(
+                                                                    RichText::new("Delete")
+                                                                        .size(11.0)
+                                                                        .color(Color32::from_rgb(220, 38, 38)),
+                                                                )
+                                                                .fill(Color32::from_rgb(254, 242, 242))
+                                                                .stroke(Stroke::new(1.0, Color32::from_rgb(254, 202, 202)))
+                                                                .rounding(Rounding::same(4.0));
+
+                                                                if ui.add(del_btn).clicked() {
+                                                                    actions.push(CategoryAction::Delete(category.clone()));
+                                                                }
 
-                                                // Color picker dropdown for this category
-                                                if is_picker_open {
-                                                    ui.add_space(8.0);
-                                                    egui::Frame::none()
-                                                        .fill(Color32::from_rgb(255, 255, 255))
-                                                        .rounding(Rounding::same(6.0))
-                                                        .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
-                                                        .inner_margin(Margin::same(8.0))
-                                                        .show(ui, |ui| {
-                                                            ui.horizontal_wrapped(|ui| {
-                                                                ui.spacing_mut().item_spacing = Vec2::new(5.0, 5.0);
-                                                                for preset in COLOR_PRESETS {
-                                                                    let preset_color = Color32::from_rgb(preset[0], preset[1], preset[2]);
-                                                                    let is_selected = *preset == cat_color;
-                                                                    let (rect, response) = ui.allocate_exact_size(Vec2::splat(24.0), egui::Sense::click());
-                                                                    ui.painter().rect_filled(rect, Rounding::same(4.0), preset_color);
-                                                                    if is_selected {
-                                                                        ui.painter().rect_stroke(rect, Rounding::same(4.0), Stroke::new(2.5, Color32::from_rgb(30, 30, 40)));
+                                                                if is_own {
+                                                                    return;
+                                                                }
+                                                                if hidden_with_parent {
+                                                                    ui.label(
+                                                                        RichText::new("Hidden with parent")
+                                                                            .size(10.0)
+                                                                            .color(Color32::from_rgb(150, 150, 160)),
+                                                                    );
+                                                                    return;
+                                                                }
+                                                                let toggle_btn = egui::Button::new(
+                                                                    RichText::new(if is_hidden { "Show" } else { "Hide" })
+                                                                        .size(11.0)
+                                                                        .color(Color32::from_rgb(99, 102, 241)),
+                                                                )
+                                                                .fill(Color32::from_rgb(238, 242, 255))
+                                                                .stroke(Stroke::NONE)
+                                                                .rounding(Rounding::same(4.0));
+                                                                if ui
+                                                                    .add(toggle_btn)
+                                                                    .on_hover_text(if is_hidden {
+                                                                        "Show in this profile"
                                                                     } else {
-                                                                        ui.painter().rect_stroke(rect, Rounding::same(4.0), Stroke::new(1.0, preset_color.gamma_multiply(0.7)));
+                                                                        "Hide in this profile; other profiles still see it"
+                                                                    })
+                                                                    .clicked()
+                                                                {
+                                                                    actions.push(CategoryAction::SetHidden(category.clone(), !is_hidden));
+                                                                }
+                                                            },
+                                                        );
+                                                    });
+
+                                                    // Color picker dropdown for this category
+                                                    if is_picker_open {
+                                                        ui.add_space(8.0);
+                                                        egui::Frame::none()
+                                                            .fill(Color32::from_rgb(255, 255, 255))
+                                                            .rounding(Rounding::same(6.0))
+                                                            .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                                                            .inner_margin(Margin::same(8.0))
+                                                            .show(ui, |ui| {
+                                                                ui.horizontal_wrapped(|ui| {
+                                                                    ui.spacing_mut().item_spacing = Vec2::new(5.0, 5.0);
+                                                                    for preset in COLOR_PRESETS {
+                                                                        let preset_color = Color32::from_rgb(preset[0], preset[1], preset[2]);
+                                                                        let is_selected = *preset == cat_color;
+                                                                        let (rect, response) = ui.allocate_exact_size(Vec2::splat(24.0), egui::Sense::click());
+                                                                        ui.painter().rect_filled(rect, Rounding::same(4.0), preset_color);
+                                                                        if is_selected {
+                                                                            ui.painter().rect_stroke(rect, Rounding::same(4.0), Stroke::new(2.5, Color32::from_rgb(30, 30, 40)));
+                                                                        } else {
+                                                                            ui.painter().rect_stroke(rect, Rounding::same(4.0), Stroke::new(1.0, preset_color.gamma_multiply(0.7)));
+                                                                        }
+                                                                        if response.clicked() {
+                                                                            actions.push(CategoryAction::UpdateColor(category.clone(), *preset));
+                                                                            self.color_picker_open_for = None;
+                                                                        }
                                                                     }
-                                                                    if response.clicked() {
-                                                                        actions.push(CategoryAction::UpdateColor(category.clone(), *preset));
+                                                                });
+
+                                                                // Profile categories only have a color
+                                                                if is_own {
+                                                                    return;
+                                                                }
+
+                                                                if parent.is_some() && own_color.is_some() {
+                                                                    ui.add_space(4.0);
+                                                                    if ui.small_button("Use parent color").clicked() {
+                                                                        actions.push(CategoryAction::ResetColor(category.clone()));
                                                                         self.color_picker_open_for = None;
                                                                     }
                                                                 }
+
+                                                                // Parent (categories with subcategories stay top-level)
+                                                                ui.add_space(6.0);
+                                                                ui.horizontal(|ui| {
+                                                                    ui.label(
+                                                                        RichText::new("Under")
+                                                                            .size(11.0)
+                                                                            .color(Color32::from_rgb(100, 100, 110)),
+                                                                    );
+                                                                    if has_children {
+                                                                        ui.label(
+                                                                            RichText::new("Top level (has subcategories)")
+                                                                                .size(11.0)
+                                                                                .color(Color32::from_rgb(150, 150, 160)),
+                                                                        );
+                                                                        return;
+                                                                    }
+                                                                    let mut selected = parent.cloned();
+                                                                    ComboBox::from_id_salt(("category_parent", category))
+                                                                        .width(160.0)
+                                                                        .selected_text(selected.as_deref().unwrap_or("Top level"))
+                                                                        .show_ui(ui, |ui| {
+                                                                            ui.selectable_value(&mut selected, None, "Top level");
+                                                                            for candidate in categories
+                                                                                .iter()
+                                                                                .filter(|c| {
+                                                                                    *c != category
+                                                                                        && !category_parents.contains_key(*c)
+                                                                                        && !overrides.contains(c)
+                                                                                })
+                                                                            {
+                                                                                ui.selectable_value(&mut selected, Some(candidate.clone()), candidate.as_str());
+                                                                            }
+                                                                        });
+                                                                    if selected.as_ref() != parent {
+                                                                        actions.push(CategoryAction::SetParent(category.clone(), selected));
+                                                                    }
+                                                                });
+
+                                                                // Monthly limit and what happens to leftovers
+                                                                ui.add_space(6.0);
+                                                                self.render_limit_editor(
+                                                                    ui,
+                                                                    category,
+                                                                    category_limits.contains_key(category),
+                                                                    &mut actions,
+                                                                );
                                                             });
-                                                        });
-                                                }
+                                                    }
+                                                });
                                             });
-                                        });
+                                    });
                                 }
                             }
                         });
@@ -322,6 +544,72 @@ impl CategoryManager {
     }
 }
 
+impl CategoryManager {
+    fn load_limit(&mut self, category_limits: &CategoryLimits, category: &str) {
+        let limit = category_limits.get(category);
+        self.limit_amount = limit.map(|l| format!("{:.2}", l.amount)).unwrap_or_default();
+        self.limit_policy = limit.map(|l| l.rollover).unwrap_or_default();
+        self.limit_cap = match self.limit_policy {
+            RolloverPolicy::Capped(cap) => format!("{:.2}", cap),
+            _ => String::new(),
+        };
+    }
+
+    fn render_limit_editor(
+        &mut self,
+        ui: &mut Ui,
+        category: &str,
+        has_limit: bool,
+        actions: &mut Vec<CategoryAction>,
+    ) {
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new("Limit $")
+                    .size(11.0)
+                    .color(Color32::from_rgb(100, 100, 110)),
+            );
+            ui.add(TextEdit::singleline(&mut self.limit_amount).desired_width(54.0).hint_text("0.00"));
+
+            let is_capped = matches!(self.limit_policy, RolloverPolicy::Capped(_));
+            ComboBox::from_id_salt(("category_rollover", category))
+                .width(110.0)
+                .selected_text(self.limit_policy.label())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(self.limit_policy == RolloverPolicy::None, RolloverPolicy::None.label()).clicked() {
+                        self.limit_policy = RolloverPolicy::None;
+                    }
+                    if ui.selectable_label(self.limit_policy == RolloverPolicy::Full, RolloverPolicy::Full.label()).clicked() {
+                        self.limit_policy = RolloverPolicy::Full;
+                    }
+                    if ui.selectable_label(is_capped, RolloverPolicy::Capped(0.0).label()).clicked() {
+                        self.limit_policy = RolloverPolicy::Capped(0.0);
+                    }
+                });
+            if is_capped {
+                ui.add(TextEdit::singleline(&mut self.limit_cap).desired_width(44.0).hint_text("Cap"))
+                    .on_hover_text("Most that can carry over; overspending always carries");
+            }
+        });
+
+        ui.horizontal(|ui| {
+            let amount = evaluate_amount(&self.limit_amount).filter(|a| *a > 0.0);
+            let policy = match self.limit_policy {
+                RolloverPolicy::Capped(_) => evaluate_amount(&self.limit_cap).filter(|c| *c >= 0.0).map(RolloverPolicy::Capped),
+                other => Some(other),
+            };
+            if let (Some(amount), Some(policy)) = (amount, policy)
+                && ui.small_button("Save limit").clicked()
+            {
+                actions.push(CategoryAction::SetLimit(category.to_string(), Some((amount, policy))));
+            }
+            if has_limit && ui.small_button("Remove limit").clicked() {
+                actions.push(CategoryAction::SetLimit(category.to_string(), None));
+                self.limit_amount.clear();
+            }
+        });
+    }
+}
+
 /// Renders a small "add category" popup inline
 pub struct AddCategoryPopup {
     pub is_open: bool,
@@ -0,0 +1,281 @@
+use egui::{Color32, Key, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use uuid::Uuid;
+
+use crate::models::ShortcutAction;
+
+/// Commands that can be run from the command palette
+#[derive(Debug, Clone)]
+pub enum PaletteCommand {
+    /// Any action that also has a keyboard shortcut
+    Shortcut(ShortcutAction),
+    OpenCategories,
+    EditShortcuts,
+    OpenTagReport,
+    OpenGoals,
+    OpenDebts,
+    OpenAccounts,
+    OpenEnvelopes,
+    OpenRules,
+    OpenPayees,
+    CompareProfiles,
+    OpenHousehold,
+    ExportProfile,
+    ImportProfile,
+    SwitchProfile(String),
+    ApplyTemplate(Uuid),
+    AddPreset(Uuid),
+}
+
+/// A single searchable entry in the command palette
+pub struct PaletteEntry {
+    pub label: String,
+    /// Secondary text shown on the right (shortcut or entry kind)
+    pub detail: String,
+    pub command: PaletteCommand,
+}
+
+impl PaletteEntry {
+    pub fn new(label: impl Into<String>, detail: impl Into<String>, command: PaletteCommand) -> Self {
+        Self {
+            label: label.into(),
+            detail: detail.into(),
+            command,
+        }
+    }
+}
+
+/// Ctrl/Cmd+K popup with fuzzy search over every app action
+pub struct CommandPalette {
+    pub is_open: bool,
+    query: String,
+    selected: usize,
+    request_focus: bool,
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            query: String::new(),
+            selected: 0,
+            request_focus: false,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.query.clear();
+        self.selected = 0;
+        self.request_focus = true;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.query.clear();
+        self.request_focus = false;
+    }
+
+    /// Returns the command chosen by the user, if any
+    pub fn render(&mut self, ctx: &egui::Context, entries: &[PaletteEntry]) -> Option<PaletteCommand> {
+        if !self.is_open {
+            return None;
+        }
+
+        let mut result: Option<PaletteCommand> = None;
+        let mut should_close = false;
+
+        // Rank entries against the query (best match first, stable for ties)
+        let mut matches: Vec<(i32, &PaletteEntry)> = entries
+            .iter()
+            .filter_map(|e| fuzzy_score(&self.query, &e.label).map(|score| (score, e)))
+            .collect();
+        matches.sort_by_key(|m| std::cmp::Reverse(m.0));
+
+        if self.selected >= matches.len() {
+            self.selected = matches.len().saturating_sub(1);
+        }
+
+        // Keyboard navigation
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, Key::ArrowDown),
+                i.key_pressed(Key::Enter),
+                i.key_pressed(Key::Escape),
+            )
+        });
+        if up {
+            self.selected = self.selected.saturating_sub(1);
+        }
+        if down && self.selected + 1 < matches.len() {
+            self.selected += 1;
+        }
+        if escape {
+            should_close = true;
+        }
+        if enter {
+            if let Some((_, entry)) = matches.get(self.selected) {
+                result = Some(entry.command.clone());
+            }
+            should_close = true;
+        }
+
+        egui::Window::new("Command Palette")
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 120.0])
+            .fixed_size([460.0, 380.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(16.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                egui::Frame::none()
+                    .fill(Color32::from_rgb(249, 250, 251))
+                    .rounding(Rounding::same(10.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+                    .inner_margin(Margin::symmetric(12.0, 10.0))
+                    .show(ui, |ui| {
+                        let response = ui.add(
+                            TextEdit::singleline(&mut self.query)
+                                .desired_width(f32::INFINITY)
+                                .hint_text("Type a command, profile, template or preset...")
+                                .frame(false),
+                        );
+                        if self.request_focus {
+                            response.request_focus();
+                            self.request_focus = false;
+                        }
+                        if response.changed() {
+                            self.selected = 0;
+                        }
+                    });
+
+                ui.add_space(8.0);
+
+                egui::ScrollArea::vertical()
+                    .max_height(300.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        ui.spacing_mut().item_spacing = Vec2::new(4.0, 2.0);
+
+                        if matches.is_empty() {
+                            ui.add_space(12.0);
+                            ui.vertical_centered(|ui| {
+                                ui.label(
+                                    RichText::new("No matching commands")
+                                        .size(13.0)
+                                        .color(Color32::from_rgb(156, 163, 175)),
+                                );
+                            });
+                        }
+
+                        for (idx, (_, entry)) in matches.iter().enumerate() {
+                            let is_selected = idx == self.selected;
+                            let (rect, response) = ui.allocate_exact_size(
+                                Vec2::new(ui.available_width(), 32.0),
+                                egui::Sense::click(),
+                            );
+
+                            if is_selected || response.hovered() {
+                                ui.painter().rect_filled(
+                                    rect,
+                                    Rounding::same(8.0),
+                                    if is_selected {
+                                        Color32::from_rgb(238, 242, 255)
+                                    } else {
+                                        Color32::from_rgb(249, 250, 251)
+                                    },
+                                );
+                            }
+                            ui.painter().text(
+                                rect.left_center() + Vec2::new(12.0, 0.0),
+                                egui::Align2::LEFT_CENTER,
+                                &entry.label,
+                                egui::FontId::proportional(13.0),
+                                if is_selected {
+                                    Color32::from_rgb(99, 102, 241)
+                                } else {
+                                    Color32::from_rgb(55, 65, 81)
+                                },
+                            );
+                            ui.painter().text(
+                                rect.right_center() - Vec2::new(12.0, 0.0),
+                                egui::Align2::RIGHT_CENTER,
+                                &entry.detail,
+                                egui::FontId::proportional(11.0),
+                                Color32::from_rgb(156, 163, 175),
+                            );
+
+                            if is_selected && (up || down) {
+                                response.scroll_to_me(None);
+                            }
+                            if response.clicked() {
+                                result = Some(entry.command.clone());
+                                should_close = true;
+                            }
+                        }
+                    });
+            });
+
+        if should_close {
+            self.close();
+        }
+
+        result
+    }
+}
+
+/// Case-insensitive subsequence match. Returns None if the query doesn't match,
+/// otherwise a score that favours consecutive runs and word-start matches.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    let query: Vec<char> = query.trim().to_lowercase().chars().filter(|c| !c.is_whitespace()).collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut qi = 0;
+    let mut last_match: Option<usize> = None;
+
+    for (ci, c) in candidate.iter().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        if *c != query[qi] {
+            continue;
+        }
+
+        score += 1;
+        let at_word_start = ci == 0 || !candidate[ci - 1].is_alphanumeric();
+        if at_word_start {
+            score += 8;
+        }
+        match last_match {
+            Some(prev) if prev + 1 == ci => score += 5,
+            Some(prev) => score -= (ci - prev - 1).min(5) as i32,
+            None => score -= ci.min(10) as i32,
+        }
+        last_match = Some(ci);
+        qi += 1;
+    }
+
+    (qi == query.len()).then_some(score)
+}
@@ -1,18 +1,26 @@
 use egui::{Color32, RichText, Rounding, Stroke, Ui, Vec2};
 
-use crate::models::Budget;
+use crate::models::{period_key, Budget, Forecast, LimitStatus};
+
+/// Number of categories listed in the dashboard breakdown
+const MAX_CATEGORY_ROWS: usize = 5;
 
 /// Returns true if the Edit button was clicked
-pub fn render_dashboard(ui: &mut Ui, budget: &Budget) -> bool {
+pub fn render_dashboard(ui: &mut Ui, budget: &Budget, forecast: &Forecast, income_shortcut: &str) -> bool {
     let total_income = budget.total_income();
     let total_expenses = budget.total_expenses();
-    let available_balance = budget.remaining_balance();
+    // Envelope mode shows income not yet assigned instead of what's left
+    let (heading, available_balance) = if budget.envelope_mode {
+        ("To Be Assigned", budget.to_be_assigned(&period_key(forecast.period_end)))
+    } else {
+        ("Available Balance", budget.remaining_balance())
+    };
     let mut edit_clicked = false;
 
     ui.vertical(|ui| {
         // Header
         ui.label(
-            RichText::new("Available Balance")
+            RichText::new(heading)
                 .size(13.0)
                 .color(Color32::from_rgb(107, 114, 128)),
         );
@@ -69,7 +77,7 @@ pub fn render_dashboard(ui: &mut Ui, budget: &Budget) -> bool {
                     .rounding(Rounding::same(6.0))
                     .min_size(Vec2::new(50.0, 24.0));
 
-                    if ui.add(edit_btn).on_hover_text("Keyboard shortcut: I").clicked() {
+                    if ui.add(edit_btn).on_hover_text(format!("Keyboard shortcut: {}", income_shortcut)).clicked() {
                         edit_clicked = true;
                     }
 
@@ -116,7 +124,166 @@ pub fn render_dashboard(ui: &mut Ui, budget: &Budget) -> bool {
                     });
                 });
             });
+
+        // Safe to spend per day for the rest of the month
+        ui.add_space(8.0);
+        egui::Frame::none()
+            .fill(Color32::from_rgb(238, 242, 255))
+            .rounding(Rounding::same(12.0))
+            .inner_margin(egui::Margin::symmetric(14.0, 12.0))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let (rect, _) = ui.allocate_exact_size(Vec2::splat(10.0), egui::Sense::hover());
+                    ui.painter().circle_filled(
+                        rect.center(),
+                        5.0,
+                        Color32::from_rgb(99, 102, 241),
+                    );
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new("Safe to spend / day")
+                            .size(13.0)
+                            .color(Color32::from_rgb(55, 48, 163)),
+                    )
+                    .on_hover_text("Remaining balance plus scheduled income, minus upcoming recurring presets, spread over the days left this month");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.label(
+                            RichText::new(format!("${:.2}", forecast.safe_per_day))
+                                .size(16.0)
+                                .color(Color32::from_rgb(79, 70, 229))
+                                .strong(),
+                        );
+                    });
+                });
+            });
+
+        // Spending by top-level category (split expenses count toward each of their
+        // lines, subcategories roll up into their parent)
+        let category_totals = budget.category_totals();
+        if !category_totals.is_empty() {
+            ui.add_space(16.0);
+            ui.label(
+                RichText::new("By Category")
+                    .size(13.0)
+                    .color(Color32::from_rgb(107, 114, 128)),
+            );
+            ui.add_space(4.0);
+
+            ui.spacing_mut().item_spacing = Vec2::new(8.0, 4.0);
+            for (category, amount) in category_totals.iter().take(MAX_CATEGORY_ROWS) {
+                let cat_color = budget.get_category_color(category);
+                let color = Color32::from_rgb(cat_color[0], cat_color[1], cat_color[2]);
+
+                ui.horizontal(|ui| {
+                    let (rect, _) = ui.allocate_exact_size(Vec2::splat(8.0), egui::Sense::hover());
+                    ui.painter().circle_filled(rect.center(), 4.0, color);
+                    ui.label(
+                        RichText::new(category)
+                            .size(12.0)
+                            .color(Color32::from_rgb(55, 65, 81)),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.label(
+                            RichText::new(format!("${:.2}", amount))
+                                .size(12.0)
+                                .color(Color32::from_rgb(55, 65, 81)),
+                        );
+                    });
+                });
+
+                // Share of total spending
+                let share = if total_expenses > 0.0 { (amount / total_expenses) as f32 } else { 0.0 };
+                let (bar_rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 4.0), egui::Sense::hover());
+                ui.painter().rect_filled(bar_rect, Rounding::same(2.0), Color32::from_rgb(243, 244, 246));
+                let mut fill_rect = bar_rect;
+                fill_rect.set_right(bar_rect.left() + bar_rect.width() * share.clamp(0.0, 1.0));
+                ui.painter().rect_filled(fill_rect, Rounding::same(2.0), color);
+
+                // Subcategory breakdown (already included in the parent total above)
+                for (subcategory, sub_amount) in budget.subcategory_totals(category) {
+                    ui.horizontal(|ui| {
+                        ui.add_space(16.0);
+                        ui.label(
+                            RichText::new(&subcategory)
+                                .size(11.0)
+                                .color(Color32::from_rgb(107, 114, 128)),
+                        );
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.label(
+                                RichText::new(format!("${:.2}", sub_amount))
+                                    .size(11.0)
+                                    .color(Color32::from_rgb(107, 114, 128)),
+                            );
+                        });
+                    });
+                }
+            }
+
+            if category_totals.len() > MAX_CATEGORY_ROWS {
+                ui.label(
+                    RichText::new(format!("+{} more", category_totals.len() - MAX_CATEGORY_ROWS))
+                        .size(11.0)
+                        .color(Color32::from_rgb(156, 163, 175)),
+                );
+            }
+        }
+
+        // Monthly limits, including anything carried over from earlier months
+        let limits = budget.limit_statuses(forecast.period_end);
+        if !limits.is_empty() {
+            ui.add_space(16.0);
+            ui.label(
+                RichText::new("Limits")
+                    .size(13.0)
+                    .color(Color32::from_rgb(107, 114, 128)),
+            );
+            ui.add_space(4.0);
+            for status in &limits {
+                render_limit_row(ui, budget, status);
+            }
+        }
     });
 
     edit_clicked
 }
+
+/// One limited category: spent against limit plus carry-over, and what's left
+fn render_limit_row(ui: &mut Ui, budget: &Budget, status: &LimitStatus) {
+    let cat_color = budget.get_category_color(&status.category);
+    let color = Color32::from_rgb(cat_color[0], cat_color[1], cat_color[2]);
+    let muted = Color32::from_rgb(107, 114, 128);
+    let budgeted = status.limit + status.carried;
+
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(&status.category)
+                .size(12.0)
+                .color(Color32::from_rgb(55, 65, 81)),
+        );
+        if status.carried.abs() >= 0.005 {
+            let (text, carried_color) = if status.carried > 0.0 {
+                (format!("+${:.2} carried", status.carried), Color32::from_rgb(5, 150, 105))
+            } else {
+                (format!("-${:.2} carried", -status.carried), Color32::from_rgb(220, 38, 38))
+            };
+            ui.label(RichText::new(text).size(10.0).color(carried_color))
+                .on_hover_text(format!("${:.2} limit this month", status.limit));
+        }
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let remaining_color = if status.remaining < 0.0 { Color32::from_rgb(220, 38, 38) } else { muted };
+            ui.label(
+                RichText::new(format!("${:.2} / ${:.2}", status.spent, budgeted))
+                    .size(12.0)
+                    .color(remaining_color),
+            );
+        });
+    });
+
+    let share = if budgeted > 0.0 { (status.spent / budgeted) as f32 } else { 1.0 };
+    let (bar_rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 4.0), egui::Sense::hover());
+    ui.painter().rect_filled(bar_rect, Rounding::same(2.0), Color32::from_rgb(243, 244, 246));
+    let mut fill_rect = bar_rect;
+    fill_rect.set_right(bar_rect.left() + bar_rect.width() * share.clamp(0.0, 1.0));
+    let fill = if status.remaining < 0.0 { Color32::from_rgb(239, 68, 68) } else { color };
+    ui.painter().rect_filled(fill_rect, Rounding::same(2.0), fill);
+}
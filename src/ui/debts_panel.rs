@@ -0,0 +1,462 @@
+use chrono::{Months, NaiveDate};
+use egui::{Color32, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::amount_input::{evaluate_amount, render_amount_preview};
+use crate::models::{simulate_payoff, Budget, Compounding, Debt, PayoffStrategy};
+
+/// Actions that can be returned from the debts panel
+pub enum DebtAction {
+    Create(Debt),
+    Delete(Uuid),
+    /// Record a payment of this amount towards the debt
+    RecordPayment(Uuid, f64),
+}
+
+/// Window listing debts with balances, amortization schedules and payoff strategies
+pub struct DebtsPanel {
+    pub is_open: bool,
+    new_name: String,
+    new_balance: String,
+    new_apr: String,
+    new_minimum: String,
+    new_compounding: Compounding,
+    /// Payment amount being typed, per debt (empty means the minimum)
+    payment_inputs: HashMap<Uuid, String>,
+    /// Debt whose amortization schedule is expanded
+    schedule_open: Option<Uuid>,
+    /// Extra paid each month on top of the minimums, for the strategy comparison
+    extra_payment: String,
+}
+
+impl Default for DebtsPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebtsPanel {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            new_name: String::new(),
+            new_balance: String::new(),
+            new_apr: String::new(),
+            new_minimum: String::new(),
+            new_compounding: Compounding::Monthly,
+            payment_inputs: HashMap::new(),
+            schedule_open: None,
+            extra_payment: String::new(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.clear_form();
+        self.payment_inputs.clear();
+        self.schedule_open = None;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    fn clear_form(&mut self) {
+        self.new_name.clear();
+        self.new_balance.clear();
+        self.new_apr.clear();
+        self.new_minimum.clear();
+        self.new_compounding = Compounding::Monthly;
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, budget: &Budget, today: NaiveDate) -> Vec<DebtAction> {
+        let mut actions: Vec<DebtAction> = Vec::new();
+
+        if !self.is_open {
+            return actions;
+        }
+
+        egui::Window::new("Debts")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([500.0, 600.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new("Debts & Loans")
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(16.0);
+
+                    let balances: Vec<(&Debt, f64)> = budget
+                        .debts
+                        .iter()
+                        .map(|d| (d, budget.debt_balance(d, today)))
+                        .collect();
+
+                    egui::ScrollArea::vertical()
+                        .max_height(400.0)
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            ui.spacing_mut().item_spacing = Vec2::new(8.0, 8.0);
+
+                            if balances.is_empty() {
+                                ui.vertical_centered(|ui| {
+                                    ui.add_space(40.0);
+                                    ui.label(
+                                        RichText::new("No debts tracked")
+                                            .size(14.0)
+                                            .color(Color32::from_rgb(156, 163, 175)),
+                                    );
+                                });
+                            }
+
+                            for (debt, balance) in &balances {
+                                self.render_debt(ui, debt, *balance, today, &mut actions);
+                            }
+
+                            if balances.iter().any(|(_, b)| *b > 0.0) {
+                                ui.add_space(4.0);
+                                self.render_strategies(ui, &balances);
+                            }
+                        });
+
+                    ui.add_space(12.0);
+                    self.render_new_debt_form(ui, today, &mut actions);
+                });
+            });
+
+        actions
+    }
+
+    fn render_debt(
+        &mut self,
+        ui: &mut egui::Ui,
+        debt: &Debt,
+        balance: f64,
+        today: NaiveDate,
+        actions: &mut Vec<DebtAction>,
+    ) {
+        let muted = Color32::from_rgb(107, 114, 128);
+        let schedule = debt.amortization_schedule(balance, debt.minimum_payment);
+
+        egui::Frame::none()
+            .fill(Color32::from_rgb(249, 250, 251))
+            .rounding(Rounding::same(12.0))
+            .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+            .inner_margin(Margin::same(14.0))
+            .show(ui, |ui| {
+                ui.set_width(ui.available_width());
+
+                // Name, balance and delete
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(&debt.name)
+                            .size(14.0)
+                            .color(Color32::from_rgb(17, 24, 39))
+                            .strong(),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let del_btn = egui::Button::new(
+                            RichText::new("Delete")
+                                .size(11.0)
+                                .color(Color32::from_rgb(220, 38, 38)),
+                        )
+                        .fill(Color32::from_rgb(254, 242, 242))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(254, 202, 202)))
+                        .rounding(Rounding::same(4.0));
+                        if ui.add(del_btn).on_hover_text("Payments stay in the expense list").clicked() {
+                            actions.push(DebtAction::Delete(debt.id));
+                        }
+                        ui.label(
+                            RichText::new(format!("${:.2}", balance))
+                                .size(14.0)
+                                .color(Color32::from_rgb(220, 38, 38))
+                                .strong(),
+                        );
+                    });
+                });
+
+                ui.label(
+                    RichText::new(format!(
+                        "{:.2}% APR · {} compounding · ${:.2} minimum",
+                        debt.apr,
+                        debt.compounding.label(),
+                        debt.minimum_payment
+                    ))
+                    .size(11.0)
+                    .color(muted),
+                );
+
+                // Payoff at the minimum payment
+                if balance <= 0.0 {
+                    ui.label(RichText::new("Paid off").size(11.0).color(Color32::from_rgb(5, 150, 105)));
+                } else if schedule.is_empty() {
+                    ui.label(
+                        RichText::new("Minimum payment doesn't cover the interest")
+                            .size(11.0)
+                            .color(Color32::from_rgb(220, 38, 38)),
+                    );
+                } else {
+                    let interest: f64 = schedule.iter().map(|r| r.interest).sum();
+                    let payoff = today.checked_add_months(Months::new(schedule.len() as u32)).unwrap_or(today);
+                    ui.label(
+                        RichText::new(format!(
+                            "At the minimum: paid off {} ({} months), ${:.2} interest",
+                            payoff.format("%b %Y"),
+                            schedule.len(),
+                            interest
+                        ))
+                        .size(11.0)
+                        .color(muted),
+                    );
+                }
+
+                if balance > 0.0 {
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        let input = self.payment_inputs.entry(debt.id).or_default();
+                        ui.label(RichText::new("$").size(12.0).color(muted));
+                        ui.add(
+                            TextEdit::singleline(input)
+                                .desired_width(80.0)
+                                .hint_text(format!("{:.2}", debt.minimum_payment)),
+                        );
+                        render_amount_preview(ui, input);
+
+                        let amount = if input.trim().is_empty() {
+                            Some(debt.minimum_payment)
+                        } else {
+                            evaluate_amount(input)
+                        }
+                        .filter(|a| *a > 0.0);
+                        let pay_btn = egui::Button::new(
+                            RichText::new("Record Payment")
+                                .size(11.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(6.0));
+                        if ui
+                            .add_enabled(amount.is_some(), pay_btn)
+                            .on_hover_text("Recorded as an expense linked to this debt")
+                            .clicked()
+                            && let Some(amount) = amount
+                        {
+                            actions.push(DebtAction::RecordPayment(debt.id, amount));
+                            input.clear();
+                        }
+
+                        if !schedule.is_empty() {
+                            let is_open = self.schedule_open == Some(debt.id);
+                            let toggle = if is_open { "▾ Schedule" } else { "▸ Schedule" };
+                            let schedule_label = egui::Label::new(RichText::new(toggle).size(11.0).color(muted))
+                                .sense(egui::Sense::click());
+                            if ui.add(schedule_label).clicked() {
+                                self.schedule_open = if is_open { None } else { Some(debt.id) };
+                            }
+                        }
+                    });
+                }
+
+                if self.schedule_open == Some(debt.id) && !schedule.is_empty() {
+                    ui.add_space(4.0);
+                    egui::ScrollArea::vertical()
+                        .id_salt(("amortization", debt.id))
+                        .max_height(160.0)
+                        .show(ui, |ui| {
+                            egui::Grid::new(("amortization_grid", debt.id))
+                                .striped(true)
+                                .spacing(Vec2::new(16.0, 2.0))
+                                .show(ui, |ui| {
+                                    for heading in ["Month", "Payment", "Interest", "Principal", "Balance"] {
+                                        ui.label(RichText::new(heading).size(10.0).color(muted).strong());
+                                    }
+                                    ui.end_row();
+                                    for row in &schedule {
+                                        let month = today.checked_add_months(Months::new(row.month)).unwrap_or(today);
+                                        ui.label(RichText::new(month.format("%b %Y").to_string()).size(10.0));
+                                        ui.label(RichText::new(format!("${:.2}", row.payment)).size(10.0));
+                                        ui.label(RichText::new(format!("${:.2}", row.interest)).size(10.0));
+                                        ui.label(RichText::new(format!("${:.2}", row.principal)).size(10.0));
+                                        ui.label(RichText::new(format!("${:.2}", row.balance)).size(10.0));
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                }
+            });
+    }
+
+    /// Side-by-side avalanche vs snowball for all debts with a balance
+    fn render_strategies(&mut self, ui: &mut egui::Ui, balances: &[(&Debt, f64)]) {
+        let muted = Color32::from_rgb(107, 114, 128);
+
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new("Payoff Strategies")
+                    .size(12.0)
+                    .color(Color32::from_rgb(100, 100, 110)),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.add(
+                    TextEdit::singleline(&mut self.extra_payment)
+                        .desired_width(60.0)
+                        .hint_text("0.00"),
+                );
+                ui.label(RichText::new("Extra / month $").size(11.0).color(muted));
+            });
+        });
+
+        let extra = evaluate_amount(&self.extra_payment).unwrap_or(0.0);
+        let owing: Vec<(&Debt, f64)> = balances.iter().filter(|(_, b)| *b > 0.0).copied().collect();
+        let plans = [PayoffStrategy::Avalanche, PayoffStrategy::Snowball].map(|s| simulate_payoff(&owing, extra, s));
+        let best_interest = plans
+            .iter()
+            .filter(|p| p.months.is_some())
+            .map(|p| p.total_interest)
+            .fold(f64::INFINITY, f64::min);
+
+        for plan in &plans {
+            egui::Frame::none()
+                .fill(Color32::from_rgb(249, 250, 251))
+                .rounding(Rounding::same(8.0))
+                .inner_margin(Margin::symmetric(12.0, 8.0))
+                .show(ui, |ui| {
+                    ui.set_width(ui.available_width());
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(plan.strategy.label())
+                                .size(12.0)
+                                .color(Color32::from_rgb(17, 24, 39))
+                                .strong(),
+                        );
+                        let summary = match plan.months {
+                            Some(months) => format!("{} months · ${:.2} interest", months, plan.total_interest),
+                            None => "Never paid off at these payments".to_string(),
+                        };
+                        let is_best = plan.months.is_some() && (plan.total_interest - best_interest).abs() < 0.005;
+                        ui.label(
+                            RichText::new(summary)
+                                .size(11.0)
+                                .color(if is_best { Color32::from_rgb(5, 150, 105) } else { muted }),
+                        );
+                    });
+                    let order: Vec<&str> = plan
+                        .order
+                        .iter()
+                        .filter_map(|id| owing.iter().find(|(d, _)| d.id == *id).map(|(d, _)| d.name.as_str()))
+                        .collect();
+                    if !order.is_empty() {
+                        ui.label(RichText::new(order.join(" → ")).size(10.0).color(muted));
+                    }
+                });
+        }
+    }
+
+    fn render_new_debt_form(&mut self, ui: &mut egui::Ui, today: NaiveDate, actions: &mut Vec<DebtAction>) {
+        ui.label(
+            RichText::new("New Debt")
+                .size(12.0)
+                .color(Color32::from_rgb(100, 100, 110)),
+        );
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            ui.add(
+                TextEdit::singleline(&mut self.new_name)
+                    .desired_width(100.0)
+                    .hint_text("Car loan"),
+            );
+            ui.add(
+                TextEdit::singleline(&mut self.new_balance)
+                    .desired_width(70.0)
+                    .hint_text("Balance"),
+            );
+            ui.add(
+                TextEdit::singleline(&mut self.new_apr)
+                    .desired_width(50.0)
+                    .hint_text("APR %"),
+            );
+            ui.add(
+                TextEdit::singleline(&mut self.new_minimum)
+                    .desired_width(60.0)
+                    .hint_text("Minimum"),
+            );
+            egui::ComboBox::from_id_salt("debt_compounding")
+                .selected_text(self.new_compounding.label())
+                .width(70.0)
+                .show_ui(ui, |ui| {
+                    for compounding in Compounding::ALL {
+                        ui.selectable_value(&mut self.new_compounding, compounding, compounding.label());
+                    }
+                });
+
+            let balance = evaluate_amount(&self.new_balance).filter(|a| *a > 0.0);
+            let apr = self.new_apr.trim().trim_end_matches('%').parse::<f64>().ok().filter(|a| *a >= 0.0);
+            let minimum = evaluate_amount(&self.new_minimum).filter(|a| *a > 0.0);
+            let can_add = !self.new_name.trim().is_empty() && balance.is_some() && apr.is_some() && minimum.is_some();
+
+            let add_btn = egui::Button::new(
+                RichText::new("Add")
+                    .size(12.0)
+                    .color(if can_add { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+            )
+            .fill(if can_add { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(44.0, 26.0));
+
+            if ui.add(add_btn).clicked()
+                && let (Some(balance), Some(apr), Some(minimum)) = (balance, apr, minimum)
+                && !self.new_name.trim().is_empty()
+            {
+                actions.push(DebtAction::Create(Debt::new(
+                    self.new_name.trim().to_string(),
+                    balance,
+                    apr,
+                    minimum,
+                    self.new_compounding,
+                    today,
+                )));
+                self.clear_form();
+            }
+        });
+    }
+}
@@ -0,0 +1,336 @@
+use egui::{Color32, ComboBox, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+
+use super::amount_input::{evaluate_amount, render_amount_preview};
+use crate::models::{Budget, EnvelopeRow};
+
+/// Actions that can be returned from the envelope panel
+pub enum EnvelopeAction {
+    /// Turn envelope budgeting on or off for the profile
+    SetMode(bool),
+    /// Set the amount assigned to a category this period
+    Assign(String, f64),
+    /// Move assigned money from one envelope to another
+    Move { from: String, to: String, amount: f64 },
+}
+
+/// Window for assigning income to category envelopes (zero-based budgeting)
+pub struct EnvelopePanel {
+    pub is_open: bool,
+    /// Category whose assigned amount is being typed, and the text so far
+    editing: Option<(String, String)>,
+    move_from: String,
+    move_to: String,
+    move_amount: String,
+}
+
+impl Default for EnvelopePanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnvelopePanel {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            editing: None,
+            move_from: String::new(),
+            move_to: String::new(),
+            move_amount: String::new(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.editing = None;
+        self.move_amount.clear();
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, budget: &Budget, period: &str) -> Vec<EnvelopeAction> {
+        let mut actions: Vec<EnvelopeAction> = Vec::new();
+
+        if !self.is_open {
+            return actions;
+        }
+
+        egui::Window::new("Envelopes")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([480.0, 600.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(format!("Envelopes · {}", period))
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(8.0);
+
+                    let mut enabled = budget.envelope_mode;
+                    if ui
+                        .checkbox(
+                            &mut enabled,
+                            RichText::new("Envelope budgeting for this profile")
+                                .size(13.0)
+                                .color(Color32::from_rgb(55, 65, 81)),
+                        )
+                        .changed()
+                    {
+                        actions.push(EnvelopeAction::SetMode(enabled));
+                    }
+
+                    if !budget.envelope_mode {
+                        ui.add_space(8.0);
+                        ui.label(
+                            RichText::new(
+                                "Give every dollar of income a job: assign it to category envelopes each month. \
+                                 Leftovers roll over, and overspending is covered by moving money between envelopes.",
+                            )
+                            .size(12.0)
+                            .color(Color32::from_rgb(107, 114, 128)),
+                        );
+                        return;
+                    }
+
+                    ui.add_space(12.0);
+                    render_to_be_assigned(ui, budget.to_be_assigned(period));
+                    ui.add_space(12.0);
+
+                    let rows = budget.envelope_rows(period);
+                    egui::ScrollArea::vertical()
+                        .max_height(340.0)
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            self.render_rows(ui, budget, &rows, &mut actions);
+                        });
+
+                    ui.add_space(12.0);
+                    self.render_move_form(ui, &rows, &mut actions);
+                });
+            });
+
+        actions
+    }
+
+    fn render_rows(
+        &mut self,
+        ui: &mut egui::Ui,
+        budget: &Budget,
+        rows: &[EnvelopeRow],
+        actions: &mut Vec<EnvelopeAction>,
+    ) {
+        let muted = Color32::from_rgb(107, 114, 128);
+
+        egui::Grid::new("envelope_grid")
+            .num_columns(5)
+            .striped(true)
+            .spacing(Vec2::new(14.0, 6.0))
+            .show(ui, |ui| {
+                for heading in ["Envelope", "Assigned", "Spent", "Available", ""] {
+                    ui.label(RichText::new(heading).size(11.0).color(muted).strong());
+                }
+                ui.end_row();
+
+                for row in rows {
+                    let color = budget.get_category_color(&row.category);
+                    ui.horizontal(|ui| {
+                        let (rect, _) = ui.allocate_exact_size(Vec2::new(4.0, 16.0), egui::Sense::hover());
+                        ui.painter().rect_filled(rect, Rounding::same(2.0), Color32::from_rgb(color[0], color[1], color[2]));
+                        ui.label(RichText::new(&row.category).size(12.0).color(Color32::from_rgb(17, 24, 39)));
+                    });
+
+                    // Assigned amount, committed when the field loses focus
+                    let is_editing = self.editing.as_ref().is_some_and(|(c, _)| *c == row.category);
+                    let mut text = match &self.editing {
+                        Some((_, buffer)) if is_editing => buffer.clone(),
+                        _ => format!("{:.2}", row.assigned),
+                    };
+                    let response = ui.add(TextEdit::singleline(&mut text).desired_width(70.0));
+                    if response.gained_focus() || (is_editing && response.has_focus()) {
+                        self.editing = Some((row.category.clone(), text.clone()));
+                    }
+                    if response.lost_focus() {
+                        if let Some(amount) = evaluate_amount(&text).filter(|a| *a >= 0.0)
+                            && (amount - row.assigned).abs() >= 0.005
+                        {
+                            actions.push(EnvelopeAction::Assign(row.category.clone(), amount));
+                        }
+                        self.editing = None;
+                    }
+                    if row.rollover.abs() >= 0.005 {
+                        response.on_hover_text(format!("{:+.2} rolled over from last month", row.rollover));
+                    }
+
+                    ui.label(RichText::new(format!("${:.2}", row.spent)).size(12.0).color(muted));
+
+                    let available_color = if row.available < -0.005 {
+                        Color32::from_rgb(220, 38, 38)
+                    } else if row.available > 0.005 {
+                        Color32::from_rgb(5, 150, 105)
+                    } else {
+                        muted
+                    };
+                    let sign = if row.available < -0.005 { "-" } else { "" };
+                    ui.label(
+                        RichText::new(format!("{}${:.2}", sign, row.available.abs()))
+                            .size(12.0)
+                            .color(available_color)
+                            .strong(),
+                    );
+
+                    // Cover overspending from an envelope with money left
+                    if row.available < -0.005 {
+                        let shortfall = -row.available;
+                        ui.menu_button(RichText::new("Cover").size(11.0), |ui| {
+                            let sources: Vec<&EnvelopeRow> =
+                                rows.iter().filter(|r| r.category != row.category && r.available > 0.005).collect();
+                            if sources.is_empty() {
+                                ui.label(RichText::new("No envelope has money left").size(11.0).color(muted));
+                            }
+                            for source in sources {
+                                let amount = shortfall.min(source.available);
+                                let label = format!("${:.2} from {}", amount, source.category);
+                                if ui.button(label).clicked() {
+                                    actions.push(EnvelopeAction::Move {
+                                        from: source.category.clone(),
+                                        to: row.category.clone(),
+                                        amount,
+                                    });
+                                    ui.close_menu();
+                                }
+                            }
+                        });
+                    } else {
+                        ui.label("");
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn render_move_form(&mut self, ui: &mut egui::Ui, rows: &[EnvelopeRow], actions: &mut Vec<EnvelopeAction>) {
+        ui.label(
+            RichText::new("Move Money")
+                .size(12.0)
+                .color(Color32::from_rgb(100, 100, 110)),
+        );
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            for (id, value) in [("envelope_move_from", &mut self.move_from), ("envelope_move_to", &mut self.move_to)] {
+                let selected = if value.is_empty() { "Choose…".to_string() } else { value.clone() };
+                ComboBox::from_id_salt(id)
+                    .selected_text(selected)
+                    .width(120.0)
+                    .show_ui(ui, |ui| {
+                        for row in rows {
+                            ui.selectable_value(value, row.category.clone(), &row.category);
+                        }
+                    });
+                if id == "envelope_move_from" {
+                    ui.label(RichText::new("→").size(13.0).color(Color32::from_rgb(107, 114, 128)));
+                }
+            }
+            ui.add(TextEdit::singleline(&mut self.move_amount).desired_width(60.0).hint_text("0.00"));
+            render_amount_preview(ui, &self.move_amount);
+
+            let amount = evaluate_amount(&self.move_amount).filter(|a| *a > 0.0);
+            let can_move = amount.is_some()
+                && !self.move_from.is_empty()
+                && !self.move_to.is_empty()
+                && self.move_from != self.move_to;
+            let move_btn = egui::Button::new(
+                RichText::new("Move")
+                    .size(12.0)
+                    .color(if can_move { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+            )
+            .fill(if can_move { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(50.0, 26.0));
+
+            if ui.add(move_btn).clicked()
+                && can_move
+                && let Some(amount) = amount
+            {
+                actions.push(EnvelopeAction::Move {
+                    from: self.move_from.clone(),
+                    to: self.move_to.clone(),
+                    amount,
+                });
+                self.move_amount.clear();
+            }
+        });
+    }
+}
+
+/// Banner with the income still waiting to be assigned
+fn render_to_be_assigned(ui: &mut egui::Ui, amount: f64) {
+    let (fill, text, message) = if amount.abs() < 0.005 {
+        (Color32::from_rgb(236, 253, 245), Color32::from_rgb(5, 150, 105), "Every dollar has a job")
+    } else if amount > 0.0 {
+        (Color32::from_rgb(254, 243, 199), Color32::from_rgb(180, 83, 9), "Assign the rest to envelopes")
+    } else {
+        (Color32::from_rgb(254, 226, 226), Color32::from_rgb(220, 38, 38), "More assigned than income")
+    };
+
+    egui::Frame::none()
+        .fill(fill)
+        .rounding(Rounding::same(12.0))
+        .inner_margin(Margin::symmetric(14.0, 10.0))
+        .show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.horizontal(|ui| {
+                ui.vertical(|ui| {
+                    ui.label(RichText::new("To Be Assigned").size(12.0).color(text));
+                    ui.label(RichText::new(message).size(10.0).color(text));
+                });
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let sign = if amount < -0.005 { "-" } else { "" };
+                    ui.label(
+                        RichText::new(format!("{}${:.2}", sign, amount.abs()))
+                            .size(20.0)
+                            .color(text)
+                            .strong(),
+                    );
+                });
+            });
+        });
+}
@@ -1,20 +1,45 @@
 use chrono::{Datelike, NaiveDate};
 use egui::{Color32, ComboBox, Margin, RichText, Rounding, Stroke, Vec2};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use uuid::Uuid;
 
-use crate::models::{CategoryColor, Expense};
+use crate::models::{
+    find_payee, normalize_payee, normalize_tag, Account, Attachment, CategoryColor, CategoryParents, Expense, Payee,
+    SplitLine, SuggestionIndex,
+};
+use super::amount_input::{evaluate_amount, render_amount_preview};
 use super::category_manager::AddCategoryPopup;
 
+/// Editable category/amount line of a split expense
+struct SplitLineInput {
+    category: String,
+    amount: String,
+}
+
 pub struct ExpenseForm {
     pub is_open: bool,
     pub amount: String,
     pub category: String,
     pub description: String,
+    pub payee: String,
     pub selected_date: NaiveDate,
     pub show_calendar: bool,
     pub calendar_year: i32,
     pub calendar_month: u32,
     pub add_category_popup: AddCategoryPopup,
+    /// Whether the expense is split across several categories
+    pub is_split: bool,
+    split_lines: Vec<SplitLineInput>,
+    pub tags: BTreeSet<String>,
+    tag_input: String,
+    /// Receipts dropped onto the form (already copied into the attachments folder)
+    attachments: Vec<Attachment>,
+    /// Account paid from; kept between expenses since it rarely changes
+    pub account_id: Option<Uuid>,
+    /// Descriptions and categories learned from past expenses; set by the app when the form opens
+    pub suggestions: SuggestionIndex,
+    /// Payee directory, for autocomplete and normalizing what's typed
+    pub payees: Vec<Payee>,
 }
 
 impl Default for ExpenseForm {
@@ -31,11 +56,20 @@ impl ExpenseForm {
             amount: String::new(),
             category: String::new(),
             description: String::new(),
+            payee: String::new(),
             selected_date: today,
             show_calendar: false,
             calendar_year: today.year(),
             calendar_month: today.month(),
             add_category_popup: AddCategoryPopup::new(),
+            is_split: false,
+            split_lines: Vec::new(),
+            tags: BTreeSet::new(),
+            tag_input: String::new(),
+            attachments: Vec::new(),
+            account_id: None,
+            suggestions: SuggestionIndex::default(),
+            payees: Vec::new(),
         }
     }
 
@@ -55,10 +89,27 @@ impl ExpenseForm {
         self.amount.clear();
         self.category.clear();
         self.description.clear();
+        self.payee.clear();
         self.selected_date = today;
         self.show_calendar = false;
         self.calendar_year = today.year();
         self.calendar_month = today.month();
+        self.is_split = false;
+        self.split_lines.clear();
+        self.tags.clear();
+        self.tag_input.clear();
+        self.attachments.clear();
+    }
+
+    /// Hashes of receipts attached to the expense being entered
+    pub fn attachment_hashes(&self) -> impl Iterator<Item = String> + '_ {
+        self.attachments.iter().map(|a| a.hash.clone())
+    }
+
+    pub fn add_attachment(&mut self, attachment: Attachment) {
+        if !self.attachments.contains(&attachment) {
+            self.attachments.push(attachment);
+        }
     }
 
     /// Returns (Option<Expense>, Option<(new_category_name, color)>)
@@ -67,6 +118,9 @@ impl ExpenseForm {
         ctx: &egui::Context,
         categories: &[String],
         category_colors: &HashMap<String, CategoryColor>,
+        category_parents: &CategoryParents,
+        known_tags: &[String],
+        accounts: &[Account],
     ) -> (Option<Expense>, Option<(String, CategoryColor)>) {
         let mut result_expense: Option<Expense> = None;
         let mut new_category: Option<(String, CategoryColor)> = None;
@@ -80,10 +134,25 @@ impl ExpenseForm {
             self.category = categories[0].clone();
         }
 
+        // Forget an account that has since been deleted
+        if self.account_id.is_some_and(|id| !accounts.iter().any(|a| a.id == id)) {
+            self.account_id = None;
+        }
+
         let mut should_close = false;
 
-        // Increase window height to accommodate calendar
-        let window_height = if self.show_calendar { 600.0 } else { 420.0 };
+        // Increase window height to accommodate calendar and split lines
+        let mut window_height = if self.show_calendar { 680.0 } else { 500.0 };
+        if self.is_split {
+            window_height += 90.0 + self.split_lines.len() as f32 * 40.0;
+        }
+        window_height += 140.0 + self.attachments.len() as f32 * 24.0;
+        if !accounts.is_empty() {
+            window_height += 70.0;
+        }
+        if !self.description.trim().is_empty() {
+            window_height += 60.0;
+        }
 
         egui::Window::new("Add Expense")
             .collapsible(false)
@@ -151,11 +220,12 @@ impl ExpenseForm {
                                     ui.label(RichText::new("$").size(18.0).color(Color32::from_rgb(107, 114, 128)));
                                     ui.add(
                                         egui::TextEdit::singleline(&mut self.amount)
-                                            .desired_width(220.0)
-                                            .hint_text("0.00")
+                                            .desired_width(200.0)
+                                            .hint_text("0.00 or 12.50+3.99")
                                             .frame(false)
                                             .font(egui::TextStyle::Heading),
                                     );
+                                    render_amount_preview(ui, &self.amount);
                                 });
                             });
                     });
@@ -176,9 +246,15 @@ impl ExpenseForm {
                                 ui.painter().rect_filled(rect, Rounding::same(8.0), color);
                             }
 
+                            // Show the full path for subcategories (Housing › Rent)
+                            let selected_text = match category_parents.get(&self.category) {
+                                _ if self.category.is_empty() => "Select category...".to_string(),
+                                Some(parent) => format!("{} › {}", parent, self.category),
+                                None => self.category.clone(),
+                            };
                             ComboBox::from_id_salt("expense_category_popup")
                                 .width(200.0)
-                                .selected_text(if self.category.is_empty() { "Select category..." } else { &self.category })
+                                .selected_text(selected_text)
                                 .show_ui(ui, |ui| {
                                     for cat in categories {
                                         let cat_color = category_colors
@@ -188,6 +264,10 @@ impl ExpenseForm {
                                         let color = Color32::from_rgb(cat_color[0], cat_color[1], cat_color[2]);
 
                                         ui.horizontal(|ui| {
+                                            // Subcategories are indented under their parent
+                                            if category_parents.contains_key(cat) {
+                                                ui.add_space(16.0);
+                                            }
                                             let (rect, _) = ui.allocate_exact_size(Vec2::splat(14.0), egui::Sense::hover());
                                             ui.painter().rect_filled(rect, Rounding::same(4.0), color);
                                             ui.selectable_value(&mut self.category, cat.clone(), cat);
@@ -214,6 +294,69 @@ impl ExpenseForm {
                             self.category = cat_name.clone();
                             new_category = Some((cat_name, cat_color));
                         }
+
+                        // Split toggle - starts with the selected category plus one empty line
+                        if ui.checkbox(&mut self.is_split, RichText::new("Split across categories").size(12.0).color(label_color)).changed()
+                            && self.is_split
+                            && self.split_lines.is_empty()
+                        {
+                            self.split_lines.push(SplitLineInput {
+                                category: self.category.clone(),
+                                amount: self.amount.clone(),
+                            });
+                            self.split_lines.push(SplitLineInput {
+                                category: String::new(),
+                                amount: String::new(),
+                            });
+                        }
+
+                        if self.is_split {
+                            self.render_split_lines(ui, categories, category_colors, category_parents);
+                        }
+                    });
+
+                    // Paying account
+                    if !accounts.is_empty() {
+                        ui.vertical(|ui| {
+                            ui.label(RichText::new("Paid From").size(13.0).color(label_color).strong());
+                            ui.add_space(6.0);
+                            let selected_text = self
+                                .account_id
+                                .and_then(|id| accounts.iter().find(|a| a.id == id))
+                                .map_or("No account".to_string(), |a| a.name.clone());
+                            ComboBox::from_id_salt("expense_account_popup")
+                                .width(200.0)
+                                .selected_text(selected_text)
+                                .show_ui(ui, |ui| {
+                                    ui.selectable_value(&mut self.account_id, None, "No account");
+                                    for account in accounts {
+                                        ui.selectable_value(
+                                            &mut self.account_id,
+                                            Some(account.id),
+                                            format!("{} ({})", account.name, account.kind.label()),
+                                        );
+                                    }
+                                });
+                        });
+                    }
+
+                    // Payee field
+                    ui.vertical(|ui| {
+                        ui.label(RichText::new("Payee").size(13.0).color(label_color).strong());
+                        ui.add_space(6.0);
+                        egui::Frame::none()
+                            .fill(Color32::from_rgb(249, 250, 251))
+                            .rounding(Rounding::same(12.0))
+                            .inner_margin(Margin::symmetric(14.0, 12.0))
+                            .show(ui, |ui| {
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut self.payee)
+                                        .desired_width(300.0)
+                                        .hint_text("Who was paid (optional)")
+                                        .frame(false),
+                                );
+                            });
+                        self.render_payee_matches(ui);
                     });
 
                     // Description field
@@ -232,6 +375,21 @@ impl ExpenseForm {
                                         .frame(false),
                                 );
                             });
+                        self.render_suggestions(ui, categories);
+                    });
+
+                    // Tags field with chips and autocomplete
+                    ui.vertical(|ui| {
+                        ui.label(RichText::new("Tags").size(13.0).color(label_color).strong());
+                        ui.add_space(6.0);
+                        self.render_tags(ui, known_tags);
+                    });
+
+                    // Receipts (files are dropped onto the window)
+                    ui.vertical(|ui| {
+                        ui.label(RichText::new("Receipts").size(13.0).color(label_color).strong());
+                        ui.add_space(6.0);
+                        self.render_attachments(ui);
                     });
 
                     // Date field with calendar button
@@ -464,8 +622,380 @@ impl ExpenseForm {
             });
     }
 
+    fn render_split_lines(
+        &mut self,
+        ui: &mut egui::Ui,
+        categories: &[String],
+        category_colors: &HashMap<String, CategoryColor>,
+        category_parents: &CategoryParents,
+    ) {
+        let mut line_to_remove: Option<usize> = None;
+
+        egui::Frame::none()
+            .fill(Color32::from_rgb(249, 250, 251))
+            .rounding(Rounding::same(12.0))
+            .inner_margin(Margin::same(10.0))
+            .show(ui, |ui| {
+                ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+
+                for (idx, line) in self.split_lines.iter_mut().enumerate() {
+                    ui.horizontal(|ui| {
+                        let cat_color = category_colors
+                            .get(&line.category)
+                            .copied()
+                            .unwrap_or([156, 163, 175]);
+                        let color = Color32::from_rgb(cat_color[0], cat_color[1], cat_color[2]);
+                        let (rect, _) = ui.allocate_exact_size(Vec2::new(4.0, 24.0), egui::Sense::hover());
+                        ui.painter().rect_filled(rect, Rounding::same(2.0), color);
+
+                        ComboBox::from_id_salt(("expense_split_category", idx))
+                            .width(150.0)
+                            .selected_text(if line.category.is_empty() { "Category..." } else { &line.category })
+                            .show_ui(ui, |ui| {
+                                for cat in categories {
+                                    let label = if category_parents.contains_key(cat) {
+                                        format!("    {}", cat)
+                                    } else {
+                                        cat.clone()
+                                    };
+                                    ui.selectable_value(&mut line.category, cat.clone(), label);
+                                }
+                            });
+
+                        ui.label(RichText::new("$").size(13.0).color(Color32::from_rgb(107, 114, 128)));
+                        ui.add(
+                            egui::TextEdit::singleline(&mut line.amount)
+                                .desired_width(70.0)
+                                .hint_text("0.00"),
+                        );
+                        render_amount_preview(ui, &line.amount);
+
+                        let remove_btn = egui::Button::new(
+                            RichText::new("X").size(10.0).color(Color32::from_rgb(156, 163, 175)),
+                        )
+                        .fill(Color32::TRANSPARENT)
+                        .stroke(Stroke::NONE)
+                        .min_size(Vec2::new(20.0, 20.0));
+                        if ui.add(remove_btn).clicked() {
+                            line_to_remove = Some(idx);
+                        }
+                    });
+                }
+
+                ui.horizontal(|ui| {
+                    let add_line_btn = egui::Button::new(
+                        RichText::new("+ Add line").size(12.0).color(Color32::from_rgb(99, 102, 241)),
+                    )
+                    .fill(Color32::from_rgb(238, 242, 255))
+                    .stroke(Stroke::NONE)
+                    .rounding(Rounding::same(8.0));
+                    if ui.add(add_line_btn).clicked() {
+                        self.split_lines.push(SplitLineInput {
+                            category: String::new(),
+                            amount: String::new(),
+                        });
+                    }
+
+                    // Show how much of the total is still unallocated
+                    let allocated: f64 = self.split_lines
+                        .iter()
+                        .filter_map(|l| evaluate_amount(&l.amount))
+                        .sum();
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let (text, color) = match evaluate_amount(&self.amount) {
+                            Some(total) if (total - allocated).abs() < 0.005 => {
+                                ("Fully allocated".to_string(), Color32::from_rgb(5, 150, 105))
+                            }
+                            Some(total) => (
+                                format!("Unallocated: ${:.2}", total - allocated),
+                                Color32::from_rgb(220, 38, 38),
+                            ),
+                            None => (
+                                format!("Total: ${:.2}", allocated),
+                                Color32::from_rgb(107, 114, 128),
+                            ),
+                        };
+                        ui.label(RichText::new(text).size(11.0).color(color));
+                    });
+                });
+            });
+
+        if let Some(idx) = line_to_remove {
+            self.split_lines.remove(idx);
+        }
+    }
+
+    fn render_tags(&mut self, ui: &mut egui::Ui, known_tags: &[String]) {
+        let mut tag_to_remove: Option<String> = None;
+        let mut tag_to_add: Option<String> = None;
+
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(4.0, 4.0);
+
+            for tag in &self.tags {
+                let chip = egui::Button::new(
+                    RichText::new(format!("#{}  ×", tag)).size(11.0).color(Color32::from_rgb(79, 70, 229)),
+                )
+                .fill(Color32::from_rgb(238, 242, 255))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(10.0));
+                if ui.add(chip).on_hover_text("Remove tag").clicked() {
+                    tag_to_remove = Some(tag.clone());
+                }
+            }
+
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.tag_input)
+                    .desired_width(120.0)
+                    .hint_text("Add tag..."),
+            );
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                tag_to_add = normalize_tag(&self.tag_input);
+                response.request_focus();
+            }
+        });
+
+        // Suggest previously used tags that match what's been typed
+        let query = self.tag_input.trim().to_lowercase();
+        if !query.is_empty() {
+            let suggestions: Vec<&String> = known_tags
+                .iter()
+                .filter(|t| t.contains(&query) && !self.tags.contains(*t))
+                .take(5)
+                .collect();
+            if !suggestions.is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    ui.spacing_mut().item_spacing = Vec2::new(4.0, 4.0);
+                    for tag in suggestions {
+                        let suggestion = egui::Button::new(
+                            RichText::new(format!("#{}", tag)).size(11.0).color(Color32::from_rgb(107, 114, 128)),
+                        )
+                        .fill(Color32::from_rgb(243, 244, 246))
+                        .stroke(Stroke::NONE)
+                        .rounding(Rounding::same(10.0));
+                        if ui.add(suggestion).clicked() {
+                            tag_to_add = Some(tag.clone());
+                        }
+                    }
+                });
+            }
+        }
+
+        if let Some(tag) = tag_to_remove {
+            self.tags.remove(&tag);
+        }
+        if let Some(tag) = tag_to_add {
+            self.tags.insert(tag);
+            self.tag_input.clear();
+        }
+    }
+
+    /// Directory payees matching what's typed; picking one fills in its default category
+    fn render_payee_matches(&mut self, ui: &mut egui::Ui) {
+        let typed = self.payee.trim().to_lowercase();
+        if typed.is_empty() {
+            return;
+        }
+
+        // An alias already resolves to a payee: show what it'll be saved as
+        if let Some(payee) = find_payee(&self.payees, &self.payee)
+            && !payee.name.eq_ignore_ascii_case(self.payee.trim())
+        {
+            ui.label(
+                RichText::new(format!("Saved as {}", payee.name))
+                    .size(11.0)
+                    .color(Color32::from_rgb(107, 114, 128)),
+            );
+            return;
+        }
+
+        let matches: Vec<&Payee> = self
+            .payees
+            .iter()
+            .filter(|p| {
+                let name = p.name.to_lowercase();
+                name != typed
+                    && (name.contains(&typed) || p.aliases.iter().any(|a| a.to_lowercase().contains(&typed)))
+            })
+            .take(4)
+            .collect();
+        if matches.is_empty() {
+            return;
+        }
+
+        let mut picked: Option<(String, Option<String>)> = None;
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(4.0, 4.0);
+            for payee in matches {
+                let chip = egui::Button::new(
+                    RichText::new(&payee.name).size(11.0).color(Color32::from_rgb(55, 65, 81)),
+                )
+                .fill(Color32::from_rgb(243, 244, 246))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(10.0));
+                let response = ui.add(chip);
+                let response = match &payee.default_category {
+                    Some(category) => response.on_hover_text(format!("Usually {}", category)),
+                    None => response,
+                };
+                if response.clicked() {
+                    picked = Some((payee.name.clone(), payee.default_category.clone()));
+                }
+            }
+        });
+
+        if let Some((name, category)) = picked {
+            self.payee = name;
+            if let Some(category) = category
+                && !self.is_split
+            {
+                self.category = category;
+            }
+        }
+    }
+
+    /// Completions for the description and a category learned from past expenses
+    fn render_suggestions(&mut self, ui: &mut egui::Ui, categories: &[String]) {
+        let completions = self.suggestions.complete(&self.description, 3);
+        let suggestion = self.suggestions
+            .suggest(&self.description)
+            .filter(|s| s.category != self.category && categories.contains(&s.category));
+        if completions.is_empty() && suggestion.is_none() {
+            return;
+        }
+
+        // (description, amount, category) chosen this frame
+        let mut picked: Option<(Option<String>, f64, String)> = None;
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(4.0, 4.0);
+            for known in &completions {
+                let chip = egui::Button::new(
+                    RichText::new(format!("{} · ${:.2}", known.description, known.last_amount))
+                        .size(11.0)
+                        .color(Color32::from_rgb(55, 65, 81)),
+                )
+                .fill(Color32::from_rgb(243, 244, 246))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(10.0));
+                if ui.add(chip).on_hover_text(format!("Last filed under {}", known.last_category)).clicked() {
+                    picked = Some((Some(known.description.clone()), known.last_amount, known.last_category.clone()));
+                }
+            }
+            if let Some(suggestion) = &suggestion {
+                let chip = egui::Button::new(
+                    RichText::new(format!("Suggested: {}", suggestion.category))
+                        .size(11.0)
+                        .color(Color32::from_rgb(79, 70, 229)),
+                )
+                .fill(Color32::from_rgb(238, 242, 255))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(10.0));
+                let hint = format!("Like \"{}\" (${:.2})", suggestion.matched, suggestion.amount);
+                if ui.add(chip).on_hover_text(hint).clicked() {
+                    picked = Some((None, suggestion.amount, suggestion.category.clone()));
+                }
+            }
+        });
+
+        if let Some((description, amount, category)) = picked {
+            if let Some(description) = description {
+                self.description = description;
+            }
+            // Keep an amount that was already typed
+            if self.amount.trim().is_empty() {
+                self.amount = format!("{:.2}", amount);
+            }
+            if !self.is_split {
+                self.category = category;
+            }
+        }
+    }
+
+    fn render_attachments(&mut self, ui: &mut egui::Ui) {
+        let mut to_remove: Option<usize> = None;
+
+        egui::Frame::none()
+            .fill(Color32::from_rgb(249, 250, 251))
+            .rounding(Rounding::same(12.0))
+            .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+            .inner_margin(Margin::symmetric(14.0, 10.0))
+            .show(ui, |ui| {
+                ui.set_min_width(300.0);
+                for (idx, attachment) in self.attachments.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(format!("📎 {}", attachment.file_name))
+                                .size(12.0)
+                                .color(Color32::from_rgb(55, 65, 81)),
+                        );
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let remove_btn = egui::Button::new(
+                                RichText::new("X").size(10.0).color(Color32::from_rgb(156, 163, 175)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+                            if ui.add(remove_btn).clicked() {
+                                to_remove = Some(idx);
+                            }
+                        });
+                    });
+                }
+                ui.label(
+                    RichText::new("Drop images or PDFs here to attach")
+                        .size(11.0)
+                        .color(Color32::from_rgb(156, 163, 175)),
+                );
+            });
+
+        if let Some(idx) = to_remove {
+            self.attachments.remove(idx);
+        }
+    }
+
+    /// Parse the split lines. All lines must be valid and there must be at least two.
+    fn parse_split_lines(&self) -> Option<Vec<SplitLine>> {
+        if self.split_lines.len() < 2 {
+            return None;
+        }
+        self.split_lines
+            .iter()
+            .map(|line| {
+                let amount = evaluate_amount(&line.amount)?;
+                if amount <= 0.0 || line.category.trim().is_empty() {
+                    return None;
+                }
+                Some(SplitLine::new(line.category.trim().to_string(), amount))
+            })
+            .collect()
+    }
+
     fn create_expense(&self) -> Option<Expense> {
-        let amount: f64 = self.amount.parse().ok()?;
+        if self.is_split {
+            let lines = self.parse_split_lines()?;
+            let allocated: f64 = lines.iter().map(|l| l.amount).sum();
+            // An entered total must match the lines; an empty one is taken from them
+            if let Some(total) = evaluate_amount(&self.amount)
+                && (total - allocated).abs() >= 0.005
+            {
+                return None;
+            }
+            let expense = Expense::new(
+                allocated,
+                lines[0].category.clone(),
+                self.description.trim().to_string(),
+                self.selected_date,
+            );
+            return Some(
+                expense
+                    .with_payee(normalize_payee(&self.payees, &self.payee))
+                    .with_splits(lines)
+                    .with_tags(self.pending_tags())
+                    .with_attachments(self.attachments.clone())
+                    .with_account(self.account_id),
+            );
+        }
+
+        let amount = evaluate_amount(&self.amount)?;
         if amount <= 0.0 {
             return None;
         }
@@ -479,7 +1009,18 @@ impl ExpenseForm {
             self.category.trim().to_string(),
             self.description.trim().to_string(),
             self.selected_date,
-        ))
+        )
+        .with_payee(normalize_payee(&self.payees, &self.payee))
+        .with_tags(self.pending_tags())
+        .with_attachments(self.attachments.clone())
+        .with_account(self.account_id))
+    }
+
+    /// Added tags plus whatever is still typed in the tag field
+    fn pending_tags(&self) -> BTreeSet<String> {
+        let mut tags = self.tags.clone();
+        tags.extend(normalize_tag(&self.tag_input));
+        tags
     }
 }
 
@@ -0,0 +1,332 @@
+use chrono::{Datelike, NaiveDate};
+use egui::{Color32, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use super::amount_input::{evaluate_amount, render_amount_preview};
+use crate::models::{Budget, GoalStatus, SavingsGoal};
+
+/// Actions that can be returned from the goals panel
+pub enum GoalAction {
+    Create(SavingsGoal),
+    Delete(Uuid),
+    /// Record a contribution of this amount to the goal
+    Contribute(Uuid, f64),
+}
+
+/// Window listing savings goals with their progress
+pub struct GoalsPanel {
+    pub is_open: bool,
+    new_name: String,
+    new_target: String,
+    new_date: String,
+    /// Contribution amount being typed, per goal
+    contribution_inputs: HashMap<Uuid, String>,
+}
+
+impl Default for GoalsPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GoalsPanel {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            new_name: String::new(),
+            new_target: String::new(),
+            new_date: String::new(),
+            contribution_inputs: HashMap::new(),
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.clear_form();
+        self.contribution_inputs.clear();
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    fn clear_form(&mut self) {
+        self.new_name.clear();
+        self.new_target.clear();
+        self.new_date.clear();
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, budget: &Budget, today: NaiveDate) -> Vec<GoalAction> {
+        let mut actions: Vec<GoalAction> = Vec::new();
+
+        if !self.is_open {
+            return actions;
+        }
+
+        egui::Window::new("Savings Goals")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([420.0, 540.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new("Savings Goals")
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(16.0);
+
+                    egui::ScrollArea::vertical()
+                        .max_height(330.0)
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            ui.spacing_mut().item_spacing = Vec2::new(8.0, 8.0);
+
+                            if budget.goals.is_empty() {
+                                ui.vertical_centered(|ui| {
+                                    ui.add_space(40.0);
+                                    ui.label(
+                                        RichText::new("No goals yet")
+                                            .size(14.0)
+                                            .color(Color32::from_rgb(156, 163, 175)),
+                                    );
+                                });
+                            }
+
+                            for goal in &budget.goals {
+                                self.render_goal(ui, goal, budget.goal_saved(goal.id), today, &mut actions);
+                            }
+                        });
+
+                    ui.add_space(12.0);
+                    self.render_new_goal_form(ui, today, &mut actions);
+                });
+            });
+
+        actions
+    }
+
+    fn render_goal(
+        &mut self,
+        ui: &mut egui::Ui,
+        goal: &SavingsGoal,
+        saved: f64,
+        today: NaiveDate,
+        actions: &mut Vec<GoalAction>,
+    ) {
+        let status = goal.status(saved, today);
+        let (status_fill, status_text) = match status {
+            GoalStatus::Reached | GoalStatus::OnTrack => (Color32::from_rgb(209, 250, 229), Color32::from_rgb(5, 150, 105)),
+            GoalStatus::Behind => (Color32::from_rgb(254, 243, 199), Color32::from_rgb(180, 83, 9)),
+            GoalStatus::Overdue => (Color32::from_rgb(254, 226, 226), Color32::from_rgb(220, 38, 38)),
+            GoalStatus::NoDeadline => (Color32::from_rgb(243, 244, 246), Color32::from_rgb(107, 114, 128)),
+        };
+
+        egui::Frame::none()
+            .fill(Color32::from_rgb(249, 250, 251))
+            .rounding(Rounding::same(12.0))
+            .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+            .inner_margin(Margin::same(14.0))
+            .show(ui, |ui| {
+                ui.set_width(ui.available_width());
+
+                // Name, status and delete
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(&goal.name)
+                            .size(14.0)
+                            .color(Color32::from_rgb(17, 24, 39))
+                            .strong(),
+                    );
+                    ui.label(
+                        RichText::new(format!(" {} ", status.label()))
+                            .size(10.0)
+                            .color(status_text)
+                            .background_color(status_fill),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let del_btn = egui::Button::new(
+                            RichText::new("Delete")
+                                .size(11.0)
+                                .color(Color32::from_rgb(220, 38, 38)),
+                        )
+                        .fill(Color32::from_rgb(254, 242, 242))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(254, 202, 202)))
+                        .rounding(Rounding::same(4.0));
+                        if ui.add(del_btn).on_hover_text("Contributions stay in the expense list").clicked() {
+                            actions.push(GoalAction::Delete(goal.id));
+                        }
+                    });
+                });
+
+                // Progress bar
+                ui.add_space(4.0);
+                let progress = goal.progress(saved) as f32;
+                let (bar_rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 8.0), egui::Sense::hover());
+                ui.painter().rect_filled(bar_rect, Rounding::same(4.0), Color32::from_rgb(229, 231, 235));
+                if progress > 0.0 {
+                    let mut fill_rect = bar_rect;
+                    fill_rect.set_right(bar_rect.left() + bar_rect.width() * progress);
+                    ui.painter().rect_filled(fill_rect, Rounding::same(4.0), Color32::from_rgb(99, 102, 241));
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(format!("${:.2} of ${:.2}", saved, goal.target_amount))
+                            .size(11.0)
+                            .color(Color32::from_rgb(107, 114, 128)),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let detail = match (goal.target_date, goal.monthly_needed(saved, today)) {
+                            _ if status == GoalStatus::Reached => String::new(),
+                            (Some(date), Some(needed)) => {
+                                format!("${:.2}/mo by {}", needed, date.format("%b %Y"))
+                            }
+                            _ => String::new(),
+                        };
+                        ui.label(
+                            RichText::new(detail)
+                                .size(11.0)
+                                .color(Color32::from_rgb(107, 114, 128)),
+                        );
+                    });
+                });
+
+                // Contribute
+                if status != GoalStatus::Reached {
+                    ui.add_space(4.0);
+                    ui.horizontal(|ui| {
+                        let input = self.contribution_inputs.entry(goal.id).or_default();
+                        ui.label(RichText::new("$").size(12.0).color(Color32::from_rgb(107, 114, 128)));
+                        ui.add(TextEdit::singleline(input).desired_width(80.0).hint_text("0.00"));
+                        render_amount_preview(ui, input);
+
+                        let amount = evaluate_amount(input).filter(|a| *a > 0.0);
+                        let contribute_btn = egui::Button::new(
+                            RichText::new("Contribute")
+                                .size(11.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(6.0));
+                        if ui
+                            .add_enabled(amount.is_some(), contribute_btn)
+                            .on_hover_text("Recorded as a Savings expense")
+                            .clicked()
+                            && let Some(amount) = amount
+                        {
+                            actions.push(GoalAction::Contribute(goal.id, amount));
+                            input.clear();
+                        }
+                    });
+                }
+            });
+    }
+
+    fn render_new_goal_form(&mut self, ui: &mut egui::Ui, today: NaiveDate, actions: &mut Vec<GoalAction>) {
+        ui.label(
+            RichText::new("New Goal")
+                .size(12.0)
+                .color(Color32::from_rgb(100, 100, 110)),
+        );
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            ui.add(
+                TextEdit::singleline(&mut self.new_name)
+                    .desired_width(130.0)
+                    .hint_text("Emergency fund"),
+            );
+            ui.label(RichText::new("$").size(12.0).color(Color32::from_rgb(107, 114, 128)));
+            ui.add(
+                TextEdit::singleline(&mut self.new_target)
+                    .desired_width(70.0)
+                    .hint_text("5000"),
+            );
+            ui.add(
+                TextEdit::singleline(&mut self.new_date)
+                    .desired_width(80.0)
+                    .hint_text("YYYY-MM"),
+            )
+            .on_hover_text("Optional deadline (YYYY-MM or YYYY-MM-DD)");
+
+            let target = evaluate_amount(&self.new_target).filter(|a| *a > 0.0);
+            let date = parse_target_date(&self.new_date);
+            let date_ok = self.new_date.trim().is_empty() || date.is_some();
+            let can_add = !self.new_name.trim().is_empty() && target.is_some() && date_ok;
+
+            let add_btn = egui::Button::new(
+                RichText::new("Add")
+                    .size(12.0)
+                    .color(if can_add { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+            )
+            .fill(if can_add { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(50.0, 26.0));
+
+            if ui.add(add_btn).clicked()
+                && can_add
+                && let Some(target) = target
+            {
+                actions.push(GoalAction::Create(SavingsGoal::new(
+                    self.new_name.trim().to_string(),
+                    target,
+                    date,
+                    today,
+                )));
+                self.clear_form();
+            }
+        });
+    }
+}
+
+/// Parse "YYYY-MM-DD", or "YYYY-MM" meaning the last day of that month
+fn parse_target_date(input: &str) -> Option<NaiveDate> {
+    let input = input.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Some(date);
+    }
+    let first = NaiveDate::parse_from_str(&format!("{}-01", input), "%Y-%m-%d").ok()?;
+    let next_month = if first.month() == 12 {
+        NaiveDate::from_ymd_opt(first.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(first.year(), first.month() + 1, 1)
+    };
+    next_month.and_then(|d| d.pred_opt())
+}
@@ -1,16 +1,50 @@
-use egui::{Color32, Margin, RichText, Rounding, Stroke, Ui, Vec2};
+use egui::{Color32, Margin, RichText, Rounding, Stroke, TextureHandle, Ui, Vec2};
+use std::collections::{BTreeSet, HashSet};
+use std::path::PathBuf;
 use uuid::Uuid;
 
-use crate::models::Budget;
+use crate::models::{Attachment, Budget, Forecast};
+use crate::storage::get_attachment_path;
+
+/// Edge length of receipt thumbnails in the expense list
+const THUMBNAIL_SIZE: f32 = 32.0;
 
 pub enum HistoryAction {
     DeleteExpense(Uuid),
     ToggleExpense(Uuid),
     SaveAsPreset(Uuid),
+    /// Show only expenses with this tag (None clears the filter)
+    FilterByTag(Option<String>),
+    OpenTagReport,
+    /// Files dropped onto an expense row
+    AttachFiles(Uuid, Vec<PathBuf>),
+    OpenAttachment(Attachment),
+    /// Detach a file (by hash) from an expense
+    RemoveAttachment(Uuid, String),
+    /// Count an expense as a payment towards a debt (None unlinks it)
+    LinkDebt(Uuid, Option<Uuid>),
+    /// Switch between the list and the month calendar
+    ShowCalendar(bool),
+    /// Open the rules panel with a rule built from this expense
+    CreateRule(Uuid),
+    /// Add an expense to the selection, or take it out
+    ToggleSelected(Uuid),
+    ClearSelection,
+    /// Open the move/copy dialog for these expenses
+    MoveToProfile(Vec<Uuid>),
 }
 
-/// Render the expenses header (title and count) - call this outside the scroll area
-pub fn render_expenses_header(ui: &mut Ui, expense_count: usize) {
+/// Render the expenses header (title, count and tag filter) - call this outside the scroll area
+pub fn render_expenses_header(
+    ui: &mut Ui,
+    expense_count: usize,
+    tags: &BTreeSet<String>,
+    tag_filter: Option<&str>,
+    calendar_view: bool,
+    selected: &HashSet<Uuid>,
+) -> Option<HistoryAction> {
+    let mut action = None;
+
     ui.horizontal(|ui| {
         ui.label(
             RichText::new("Expenses")
@@ -20,6 +54,23 @@ pub fn render_expenses_header(ui: &mut Ui, expense_count: usize) {
         );
 
         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            // List / calendar toggle
+            for (label, is_calendar) in [("Calendar", true), ("List", false)] {
+                let selected = calendar_view == is_calendar;
+                let toggle = egui::Button::new(
+                    RichText::new(label)
+                        .size(11.0)
+                        .color(if selected { Color32::WHITE } else { Color32::from_rgb(107, 114, 128) }),
+                )
+                .fill(if selected { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(243, 244, 246) })
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(8.0))
+                .min_size(Vec2::new(0.0, 22.0));
+                if ui.add(toggle).clicked() && !selected {
+                    action = Some(HistoryAction::ShowCalendar(is_calendar));
+                }
+            }
+            ui.add_space(8.0);
             ui.label(
                 RichText::new(format!("{} items", expense_count))
                     .size(12.0)
@@ -28,10 +79,99 @@ pub fn render_expenses_header(ui: &mut Ui, expense_count: usize) {
         });
     });
 
+    // Active filter, shown even when no expense carries the tag any more
+    if let Some(tag) = tag_filter {
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(format!("Filtered by #{}", tag))
+                    .size(12.0)
+                    .color(Color32::from_rgb(79, 70, 229))
+                    .strong(),
+            );
+            if ui.small_button("Clear").clicked() {
+                action = Some(HistoryAction::FilterByTag(None));
+            }
+        });
+    }
+
+    // Tag filter chips
+    if !tags.is_empty() {
+        ui.add_space(8.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(4.0, 4.0);
+            for tag in tags {
+                let is_selected = tag_filter == Some(tag.as_str());
+                let chip = egui::Button::new(
+                    RichText::new(format!("#{}", tag))
+                        .size(11.0)
+                        .color(if is_selected { Color32::WHITE } else { Color32::from_rgb(79, 70, 229) }),
+                )
+                .fill(if is_selected { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(238, 242, 255) })
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(10.0))
+                .min_size(Vec2::new(0.0, 22.0));
+                if ui.add(chip).clicked() {
+                    let filter = if is_selected { None } else { Some(tag.clone()) };
+                    action = Some(HistoryAction::FilterByTag(filter));
+                }
+            }
+
+            let report_btn = egui::Button::new(
+                RichText::new("Tag totals...")
+                    .size(11.0)
+                    .color(Color32::from_rgb(107, 114, 128)),
+            )
+            .fill(Color32::TRANSPARENT)
+            .stroke(Stroke::NONE)
+            .min_size(Vec2::new(0.0, 22.0));
+            if ui.add(report_btn).on_hover_text("Per-tag totals across all profiles").clicked() {
+                action = Some(HistoryAction::OpenTagReport);
+            }
+        });
+    }
+
+    // Selection bar (Ctrl/Cmd+click rows to select)
+    if !selected.is_empty() {
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(format!("{} selected", selected.len()))
+                    .size(12.0)
+                    .color(Color32::from_rgb(79, 70, 229))
+                    .strong(),
+            );
+            let move_btn = egui::Button::new(
+                RichText::new("Move/copy to profile…")
+                    .size(11.0)
+                    .color(Color32::from_rgb(99, 102, 241)),
+            )
+            .fill(Color32::from_rgb(238, 242, 255))
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(0.0, 22.0));
+            if ui.add(move_btn).clicked() {
+                action = Some(HistoryAction::MoveToProfile(selected.iter().copied().collect()));
+            }
+            if ui.small_button("Clear").clicked() {
+                action = Some(HistoryAction::ClearSelection);
+            }
+        });
+    }
+
     ui.add_space(16.0);
+
+    action
 }
 
-pub fn render_expenses(ui: &mut Ui, budget: &mut Budget) -> Option<HistoryAction> {
+/// Render the expense list, optionally limited to expenses carrying `tag_filter`.
+/// Rows in `selected` are outlined.
+pub fn render_expenses(
+    ui: &mut Ui,
+    budget: &mut Budget,
+    tag_filter: Option<&str>,
+    selected: &HashSet<Uuid>,
+) -> Option<HistoryAction> {
     let mut action = None;
 
     if budget.expenses.is_empty() {
@@ -63,7 +203,11 @@ pub fn render_expenses(ui: &mut Ui, budget: &mut Budget) -> Option<HistoryAction
         });
     } else {
         // Sort expenses by date (most recent first)
-        let mut expense_indices: Vec<_> = budget.expenses.iter().enumerate().collect();
+        let mut expense_indices: Vec<_> = budget.expenses
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| tag_filter.is_none_or(|tag| e.tags.contains(tag)))
+            .collect();
         expense_indices.sort_by(|a, b| b.1.date.cmp(&a.1.date));
         let sorted_indices: Vec<usize> = expense_indices.iter().map(|(i, _)| *i).collect();
 
@@ -73,6 +217,7 @@ pub fn render_expenses(ui: &mut Ui, budget: &mut Budget) -> Option<HistoryAction
             let expense = &budget.expenses[idx];
             let expense_id = expense.id;
             let is_active = expense.active;
+            let is_selected = selected.contains(&expense_id);
 
             let cat_color = budget.get_category_color(&expense.category);
             let base = Color32::from_rgb(cat_color[0], cat_color[1], cat_color[2]);
@@ -94,10 +239,15 @@ pub fn render_expenses(ui: &mut Ui, budget: &mut Budget) -> Option<HistoryAction
                 Color32::from_rgb(180, 180, 180)
             };
 
-            egui::Frame::none()
+            // Expanded state for split expenses is kept in egui memory
+            let expand_id = ui.id().with(("split_expanded", expense_id));
+            let is_expanded = ui.data(|d| d.get_temp::<bool>(expand_id).unwrap_or(false));
+            let mut toggle_expanded = false;
+
+            let row = egui::Frame::none()
                 .fill(bg_color)
                 .rounding(Rounding::same(10.0))
-                .stroke(Stroke::NONE)
+                .stroke(if is_selected { Stroke::new(1.5, Color32::from_rgb(99, 102, 241)) } else { Stroke::NONE })
                 .inner_margin(Margin::symmetric(12.0, 8.0))
                 .show(ui, |ui| {
                     ui.horizontal(|ui| {
@@ -125,28 +275,94 @@ pub fn render_expenses(ui: &mut Ui, budget: &mut Budget) -> Option<HistoryAction
                         ui.vertical(|ui| {
                             ui.set_min_width(120.0);
 
-                            let title = if expense.description.is_empty() {
-                                expense.category.clone()
-                            } else {
+                            let title = if !expense.description.is_empty() {
                                 expense.description.clone()
+                            } else if !expense.payee.is_empty() {
+                                expense.payee.clone()
+                            } else {
+                                expense.category.clone()
                             };
 
-                            ui.label(
-                                RichText::new(title)
-                                    .size(13.0)
-                                    .color(text_color)
-                                    .strong(),
+                            let title_response = ui.add(
+                                egui::Label::new(
+                                    RichText::new(title)
+                                        .size(13.0)
+                                        .color(text_color)
+                                        .strong(),
+                                )
+                                .sense(egui::Sense::click()),
                             );
 
+                            // Ctrl/Cmd+click to select for moving between profiles
+                            if title_response.clicked() && ui.input(|i| i.modifiers.command) {
+                                action = Some(HistoryAction::ToggleSelected(expense_id));
+                            }
+
+                            // Right-click for a rule, moving it to another profile, or to count it as a debt payment
+                            title_response.context_menu(|ui| {
+                                let move_ids: Vec<Uuid> = if is_selected {
+                                    selected.iter().copied().collect()
+                                } else {
+                                    vec![expense_id]
+                                };
+                                let move_label = if move_ids.len() > 1 {
+                                    format!("Move/copy {} selected to profile…", move_ids.len())
+                                } else {
+                                    "Move/copy to profile…".to_string()
+                                };
+                                if ui.button(move_label).clicked() {
+                                    action = Some(HistoryAction::MoveToProfile(move_ids));
+                                    ui.close_menu();
+                                }
+                                let select_label = if is_selected { "Deselect" } else { "Select (Ctrl+click)" };
+                                if ui.button(select_label).clicked() {
+                                    action = Some(HistoryAction::ToggleSelected(expense_id));
+                                    ui.close_menu();
+                                }
+                                ui.separator();
+                                if ui.button("Create rule from this expense…").clicked() {
+                                    action = Some(HistoryAction::CreateRule(expense_id));
+                                    ui.close_menu();
+                                }
+                                if !budget.debts.is_empty() {
+                                    ui.menu_button("Payment towards", |ui| {
+                                        for debt in &budget.debts {
+                                            if ui.radio(expense.debt_id == Some(debt.id), &debt.name).clicked() {
+                                                action = Some(HistoryAction::LinkDebt(expense_id, Some(debt.id)));
+                                                ui.close_menu();
+                                            }
+                                        }
+                                        if expense.debt_id.is_some() && ui.button("Unlink").clicked() {
+                                            action = Some(HistoryAction::LinkDebt(expense_id, None));
+                                            ui.close_menu();
+                                        }
+                                    });
+                                }
+                            });
+
                             // Category and date on same line, more compact
                             let sub_color = if is_active { base } else { Color32::from_rgb(180, 180, 180) };
                             let date_color = if is_active { Color32::from_rgb(156, 163, 175) } else { Color32::from_rgb(190, 190, 190) };
                             ui.horizontal(|ui| {
-                                ui.label(
-                                    RichText::new(&expense.category)
-                                        .size(10.0)
-                                        .color(sub_color),
-                                );
+                                if expense.is_split() {
+                                    // Split parent - toggle to expand the category lines
+                                    let arrow = if is_expanded { "▾" } else { "▸" };
+                                    let split_label = egui::Label::new(
+                                        RichText::new(format!("{} Split · {} categories", arrow, expense.splits.len()))
+                                            .size(10.0)
+                                            .color(sub_color),
+                                    )
+                                    .sense(egui::Sense::click());
+                                    if ui.add(split_label).on_hover_text("Show split lines").clicked() {
+                                        toggle_expanded = true;
+                                    }
+                                } else {
+                                    ui.label(
+                                        RichText::new(&expense.category)
+                                            .size(10.0)
+                                            .color(sub_color),
+                                    );
+                                }
                                 ui.label(
                                     RichText::new("·")
                                         .size(10.0)
@@ -157,9 +373,59 @@ pub fn render_expenses(ui: &mut Ui, budget: &mut Budget) -> Option<HistoryAction
                                         .size(10.0)
                                         .color(date_color),
                                 );
+                                if let Some(account) = expense
+                                    .account_id
+                                    .and_then(|id| budget.accounts.iter().find(|a| a.id == id))
+                                {
+                                    ui.label(
+                                        RichText::new(format!("· {}", account.name))
+                                            .size(10.0)
+                                            .color(date_color),
+                                    );
+                                }
+
+                                // Tag chips - click to filter the list by that tag
+                                for tag in &expense.tags {
+                                    let chip = egui::Label::new(
+                                        RichText::new(format!(" #{} ", tag))
+                                            .size(10.0)
+                                            .color(if is_active { Color32::from_rgb(79, 70, 229) } else { date_color })
+                                            .background_color(Color32::from_rgb(238, 242, 255)),
+                                    )
+                                    .sense(egui::Sense::click());
+                                    if ui.add(chip).on_hover_text("Filter by this tag").clicked() {
+                                        action = Some(HistoryAction::FilterByTag(Some(tag.clone())));
+                                    }
+                                }
                             });
                         });
 
+                        // Receipt thumbnails (images) or paperclips (other files)
+                        for attachment in &expense.attachments {
+                            let response = match attachment_thumbnail(ui.ctx(), attachment) {
+                                Some(texture) => ui.add(
+                                    egui::Image::new(&texture)
+                                        .fit_to_exact_size(Vec2::splat(THUMBNAIL_SIZE))
+                                        .rounding(Rounding::same(4.0))
+                                        .sense(egui::Sense::click()),
+                                ),
+                                None => ui.add(
+                                    egui::Label::new(RichText::new("📎").size(16.0))
+                                        .sense(egui::Sense::click()),
+                                ),
+                            };
+                            let response = response.on_hover_text(format!("Open {}", attachment.file_name));
+                            if response.clicked() {
+                                action = Some(HistoryAction::OpenAttachment(attachment.clone()));
+                            }
+                            response.context_menu(|ui| {
+                                if ui.button("Remove attachment").clicked() {
+                                    action = Some(HistoryAction::RemoveAttachment(expense_id, attachment.hash.clone()));
+                                    ui.close_menu();
+                                }
+                            });
+                        }
+
                         ui.with_layout(
                             egui::Layout::right_to_left(egui::Align::Center),
                             |ui| {
@@ -206,7 +472,63 @@ pub fn render_expenses(ui: &mut Ui, budget: &mut Budget) -> Option<HistoryAction
                             },
                         );
                     });
+
+                    // Split lines (indented under the parent)
+                    if is_expanded && expense.is_split() {
+                        ui.add_space(4.0);
+                        for line in &expense.splits {
+                            let line_color = budget.get_category_color(&line.category);
+                            ui.horizontal(|ui| {
+                                ui.add_space(40.0);
+                                let color = if is_active {
+                                    Color32::from_rgb(line_color[0], line_color[1], line_color[2])
+                                } else {
+                                    Color32::from_rgb(200, 200, 200)
+                                };
+                                let (rect, _) = ui.allocate_exact_size(Vec2::new(3.0, 16.0), egui::Sense::hover());
+                                ui.painter().rect_filled(rect, Rounding::same(1.5), color);
+                                ui.label(
+                                    RichText::new(&line.category)
+                                        .size(11.0)
+                                        .color(text_color),
+                                );
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    ui.add_space(60.0);
+                                    ui.label(
+                                        RichText::new(format!("${:.2}", line.amount))
+                                            .size(11.0)
+                                            .color(text_color),
+                                    );
+                                });
+                            });
+                        }
+                    }
                 });
+
+            // Files dragged from the OS onto this row are attached to it
+            let (files_hovering, dropped, pointer) = ui.input(|i| {
+                (
+                    !i.raw.hovered_files.is_empty(),
+                    i.raw.dropped_files.iter().filter_map(|f| f.path.clone()).collect::<Vec<_>>(),
+                    i.pointer.hover_pos(),
+                )
+            });
+            if pointer.is_some_and(|p| row.response.rect.contains(p)) {
+                if files_hovering {
+                    ui.painter().rect_stroke(
+                        row.response.rect,
+                        Rounding::same(10.0),
+                        Stroke::new(2.0, Color32::from_rgb(99, 102, 241)),
+                    );
+                }
+                if !dropped.is_empty() {
+                    action = Some(HistoryAction::AttachFiles(expense_id, dropped));
+                }
+            }
+
+            if toggle_expanded {
+                ui.data_mut(|d| d.insert_temp(expand_id, !is_expanded));
+            }
         }
     }
 
@@ -214,7 +536,7 @@ pub fn render_expenses(ui: &mut Ui, budget: &mut Budget) -> Option<HistoryAction
 }
 
 /// Render the available balance bar - separate from expenses so it can be pinned at bottom
-pub fn render_balance_bar(ui: &mut Ui, budget: &Budget) {
+pub fn render_balance_bar(ui: &mut Ui, budget: &Budget, forecast: &Forecast) {
     let income = budget.total_income();
     let balance = budget.remaining_balance();
 
@@ -296,9 +618,33 @@ pub fn render_balance_bar(ui: &mut Ui, budget: &Budget) {
         );
     }
 
+    // Projected end-of-month position: the part of the fill expected to be spent
+    // is dimmed and marked with a tick
+    if income > 0.0 {
+        let projected = (forecast.projected_balance / income).clamp(0.0, 1.0);
+        let projected_x = bar_rect.left() + bar_width * projected as f32;
+        if projected < percentage {
+            let mut spend_rect = bar_rect;
+            spend_rect.set_left(projected_x);
+            spend_rect.set_right(bar_rect.left() + bar_width * percentage as f32);
+            ui.painter().rect_filled(
+                spend_rect,
+                Rounding::same(5.0),
+                Color32::from_white_alpha(140),
+            );
+        }
+        ui.painter().line_segment(
+            [
+                egui::pos2(projected_x, bar_rect.top() - 3.0),
+                egui::pos2(projected_x, bar_rect.bottom() + 3.0),
+            ],
+            Stroke::new(2.0, Color32::from_rgb(75, 85, 99)),
+        );
+    }
+
     ui.add_space(6.0);
 
-    // Percentage indicator
+    // Percentage indicator and projection
     ui.horizontal(|ui| {
         let pct_text = format!("{:.0}% remaining", percentage * 100.0);
         ui.label(
@@ -306,5 +652,51 @@ pub fn render_balance_bar(ui: &mut Ui, budget: &Budget) {
                 .size(12.0)
                 .color(Color32::from_rgb(156, 163, 175)),
         );
+
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let projection = match forecast.run_out_date {
+                Some(date) => RichText::new(format!("Runs out {}", date.format("%b %d")))
+                    .color(Color32::from_rgb(220, 38, 38)),
+                None => {
+                    let sign = if forecast.projected_balance < 0.0 { "-" } else { "" };
+                    RichText::new(format!(
+                        "Projected {}: {}${:.2}",
+                        forecast.period_end.format("%b %d"),
+                        sign,
+                        forecast.projected_balance.abs()
+                    ))
+                    .color(Color32::from_rgb(107, 114, 128))
+                }
+            };
+            ui.label(projection.size(12.0)).on_hover_text(format!(
+                "Spending ${:.2}/day so far, ${:.2} in recurring presets and ${:.2} scheduled income still to come",
+                forecast.daily_pace, forecast.upcoming_recurring, forecast.upcoming_income
+            ));
+        });
     });
 }
+
+/// Thumbnail texture for an image attachment, decoded once and cached in egui memory
+fn attachment_thumbnail(ctx: &egui::Context, attachment: &Attachment) -> Option<TextureHandle> {
+    if !attachment.is_image() {
+        return None;
+    }
+
+    let cache_id = egui::Id::new(("attachment_thumbnail", &attachment.hash));
+    if let Some(cached) = ctx.data(|d| d.get_temp::<Option<TextureHandle>>(cache_id)) {
+        return cached;
+    }
+
+    // Failed loads are cached too, so a missing file isn't re-read every frame
+    let texture = get_attachment_path(attachment)
+        .and_then(|path| std::fs::read(path).ok())
+        .and_then(|bytes| image::load_from_memory(&bytes).ok())
+        .map(|image| {
+            let thumb = image.thumbnail(64, 64).to_rgba8();
+            let size = [thumb.width() as usize, thumb.height() as usize];
+            let color_image = egui::ColorImage::from_rgba_unmultiplied(size, &thumb.into_raw());
+            ctx.load_texture(format!("attachment-{}", attachment.hash), color_image, Default::default())
+        });
+    ctx.data_mut(|d| d.insert_temp(cache_id, texture.clone()));
+    texture
+}
@@ -0,0 +1,267 @@
+use egui::{Color32, Margin, RichText, Rounding, Stroke, Vec2};
+
+use crate::models::{combine_summaries, ProfileMeta, ProfileSummary};
+
+/// Read-only window adding the selected profiles together, for households
+/// that keep a profile per person
+pub struct HouseholdView {
+    pub is_open: bool,
+    /// Profile ids in the household
+    selected: Vec<String>,
+    members: Vec<ProfileSummary>,
+}
+
+impl Default for HouseholdView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HouseholdView {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            selected: Vec::new(),
+            members: Vec::new(),
+        }
+    }
+
+    /// Open with the saved household selection
+    pub fn open(&mut self, selected: Vec<String>) {
+        self.is_open = true;
+        self.selected = selected;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.members.clear();
+    }
+
+    /// Profile ids in the household
+    pub fn selected(&self) -> &[String] {
+        &self.selected
+    }
+
+    /// Summaries for the selected profiles, in the same order
+    pub fn set_members(&mut self, members: Vec<ProfileSummary>) {
+        self.members = members;
+    }
+
+    /// Returns true when the selection changed and the summaries need reloading
+    pub fn render(&mut self, ctx: &egui::Context, profiles: &[ProfileMeta]) -> bool {
+        let mut changed = false;
+
+        if !self.is_open {
+            return changed;
+        }
+
+        // Forget deleted and archived profiles
+        let before = self.selected.len();
+        self.selected.retain(|id| profiles.iter().any(|p| p.id == *id && !p.is_archived()));
+        changed |= self.selected.len() != before;
+
+        egui::Window::new("Household")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([560.0, 600.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new("Household")
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.label(
+                        RichText::new("Read-only totals of the selected profiles. Switch to a profile to edit it.")
+                            .size(12.0)
+                            .color(Color32::from_rgb(107, 114, 128)),
+                    );
+                    ui.add_space(8.0);
+
+                    // Member picker
+                    ui.horizontal_wrapped(|ui| {
+                        for profile in profiles.iter().filter(|p| !p.is_archived()) {
+                            let mut is_selected = self.selected.contains(&profile.id);
+                            if ui.checkbox(&mut is_selected, &profile.name).changed() {
+                                if is_selected {
+                                    self.selected.push(profile.id.clone());
+                                } else {
+                                    self.selected.retain(|id| *id != profile.id);
+                                }
+                                changed = true;
+                            }
+                        }
+                    });
+
+                    ui.add_space(12.0);
+
+                    if self.members.is_empty() {
+                        ui.vertical_centered(|ui| {
+                            ui.add_space(60.0);
+                            ui.label(
+                                RichText::new("Pick the profiles that make up the household")
+                                    .size(14.0)
+                                    .color(Color32::from_rgb(156, 163, 175)),
+                            );
+                        });
+                        return;
+                    }
+
+                    let total = combine_summaries("Household".to_string(), &self.members);
+                    render_totals(ui, &total);
+                    ui.add_space(16.0);
+
+                    egui::ScrollArea::vertical()
+                        .max_height(360.0)
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            render_members(ui, &self.members, total.expenses);
+                            ui.add_space(16.0);
+                            render_categories(ui, &self.members, &total);
+                        });
+                });
+            });
+
+        changed
+    }
+}
+
+fn format_amount(amount: f64) -> String {
+    let sign = if amount < -0.005 { "-" } else { "" };
+    format!("{}${:.2}", sign, amount.abs())
+}
+
+/// Combined income, spending and balance cards
+fn render_totals(ui: &mut egui::Ui, total: &ProfileSummary) {
+    let balance_color = if total.balance < 0.0 {
+        Color32::from_rgb(220, 38, 38)
+    } else {
+        Color32::from_rgb(5, 150, 105)
+    };
+    let cards = [
+        ("Combined Income", total.income, Color32::from_rgb(17, 24, 39)),
+        ("Combined Expenses", total.expenses, Color32::from_rgb(17, 24, 39)),
+        ("Combined Balance", total.balance, balance_color),
+    ];
+
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing = Vec2::new(8.0, 8.0);
+        let width = (ui.available_width() - 16.0) / 3.0;
+        for (label, amount, color) in cards {
+            egui::Frame::none()
+                .fill(Color32::from_rgb(249, 250, 251))
+                .rounding(Rounding::same(12.0))
+                .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+                .inner_margin(Margin::same(12.0))
+                .show(ui, |ui| {
+                    ui.set_width(width - 24.0);
+                    ui.label(RichText::new(label).size(11.0).color(Color32::from_rgb(107, 114, 128)));
+                    ui.label(RichText::new(format_amount(amount)).size(18.0).color(color).strong());
+                });
+        }
+    });
+}
+
+/// One row per profile with its share of the household's spending
+fn render_members(ui: &mut egui::Ui, members: &[ProfileSummary], total_expenses: f64) {
+    let muted = Color32::from_rgb(107, 114, 128);
+    let dark = Color32::from_rgb(17, 24, 39);
+
+    ui.label(RichText::new("By member").size(13.0).color(Color32::from_rgb(100, 100, 110)).strong());
+    ui.add_space(4.0);
+
+    for member in members {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(&member.name).size(13.0).color(dark).strong());
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let balance_color = if member.balance < 0.0 { Color32::from_rgb(220, 38, 38) } else { dark };
+                ui.label(RichText::new(format_amount(member.balance)).size(12.0).color(balance_color).strong());
+                ui.label(RichText::new("left").size(11.0).color(muted));
+                ui.label(RichText::new(format!("{} spent  ·", format_amount(member.expenses))).size(11.0).color(muted));
+                ui.label(RichText::new(format!("{} in  ·", format_amount(member.income))).size(11.0).color(muted));
+            });
+        });
+
+        let fraction = if total_expenses > 0.0 { (member.expenses / total_expenses) as f32 } else { 0.0 };
+        let (bar_rect, response) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 6.0), egui::Sense::hover());
+        ui.painter().rect_filled(bar_rect, Rounding::same(3.0), Color32::from_rgb(243, 244, 246));
+        let mut fill_rect = bar_rect;
+        fill_rect.set_right(bar_rect.left() + bar_rect.width() * fraction);
+        ui.painter().rect_filled(fill_rect, Rounding::same(3.0), Color32::from_rgb(99, 102, 241));
+        response.on_hover_text(format!("{:.0}% of household spending", fraction * 100.0));
+        ui.add_space(6.0);
+    }
+}
+
+/// Spending per category, one column per member plus the household total
+fn render_categories(ui: &mut egui::Ui, members: &[ProfileSummary], total: &ProfileSummary) {
+    let muted = Color32::from_rgb(107, 114, 128);
+    let dark = Color32::from_rgb(17, 24, 39);
+
+    ui.label(RichText::new("By category").size(13.0).color(Color32::from_rgb(100, 100, 110)).strong());
+    ui.add_space(4.0);
+
+    if total.categories.is_empty() {
+        ui.label(RichText::new("No spending yet").size(12.0).color(Color32::from_rgb(156, 163, 175)));
+        return;
+    }
+
+    egui::Grid::new("household_category_grid")
+        .num_columns(members.len() + 2)
+        .striped(true)
+        .spacing(Vec2::new(16.0, 6.0))
+        .show(ui, |ui| {
+            ui.label("");
+            for member in members {
+                ui.label(RichText::new(&member.name).size(11.0).color(muted));
+            }
+            ui.label(RichText::new("Total").size(11.0).color(dark).strong());
+            ui.end_row();
+
+            for (category, amount) in &total.categories {
+                ui.label(RichText::new(category).size(12.0).color(muted));
+                for member in members {
+                    ui.label(
+                        RichText::new(format_amount(member.category_total(category)))
+                            .size(12.0)
+                            .color(Color32::from_rgb(55, 65, 81)),
+                    );
+                }
+                ui.label(RichText::new(format_amount(*amount)).size(12.0).color(dark).strong());
+                ui.end_row();
+            }
+        });
+}
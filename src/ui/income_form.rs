@@ -1,10 +1,18 @@
 use egui::{Color32, Key, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
 
+use super::amount_input::{evaluate_amount, render_amount_preview};
+use crate::models::ScheduledIncome;
+
 #[derive(Default)]
 pub struct IncomeForm {
     pub is_open: bool,
     pub amount: String,
     request_focus: bool,
+    /// Income expected later in the month (used by the forecast)
+    scheduled: Vec<ScheduledIncome>,
+    new_day: String,
+    new_amount: String,
+    new_label: String,
 }
 
 impl IncomeForm {
@@ -13,13 +21,21 @@ impl IncomeForm {
             is_open: false,
             amount: String::new(),
             request_focus: false,
+            scheduled: Vec::new(),
+            new_day: String::new(),
+            new_amount: String::new(),
+            new_label: String::new(),
         }
     }
 
-    /// Open the form with the current income value
-    pub fn open(&mut self, current_income: f64) {
+    /// Open the form with the current income value and schedule
+    pub fn open(&mut self, current_income: f64, scheduled: &[ScheduledIncome]) {
         self.is_open = true;
         self.request_focus = true;
+        self.scheduled = scheduled.to_vec();
+        self.new_day.clear();
+        self.new_amount.clear();
+        self.new_label.clear();
         if current_income > 0.0 {
             self.amount = format!("{:.2}", current_income);
         } else {
@@ -32,8 +48,8 @@ impl IncomeForm {
         self.request_focus = false;
     }
 
-    /// Returns Some(new_income_amount) if saved, None otherwise
-    pub fn render(&mut self, ctx: &egui::Context) -> Option<f64> {
+    /// Returns Some((new_income_amount, schedule)) if saved, None otherwise
+    pub fn render(&mut self, ctx: &egui::Context) -> Option<(f64, Vec<ScheduledIncome>)> {
         let mut result = None;
 
         if !self.is_open {
@@ -46,7 +62,7 @@ impl IncomeForm {
             .collapsible(false)
             .resizable(false)
             .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-            .fixed_size([340.0, 220.0])
+            .fixed_size([340.0, 330.0 + self.scheduled.len() as f32 * 26.0])
             .frame(egui::Frame::none()
                 .fill(Color32::WHITE)
                 .rounding(Rounding::same(20.0))
@@ -104,11 +120,12 @@ impl IncomeForm {
                             ui.horizontal(|ui| {
                                 ui.label(RichText::new("$").size(20.0).color(Color32::from_rgb(107, 114, 128)));
                                 let text_edit = TextEdit::singleline(&mut self.amount)
-                                    .desired_width(220.0)
+                                    .desired_width(200.0)
                                     .hint_text("0.00")
                                     .frame(false)
                                     .font(egui::TextStyle::Heading);
                                 let response = ui.add(text_edit);
+                                render_amount_preview(ui, &self.amount);
 
                                 // Request focus when form first opens
                                 if self.request_focus {
@@ -118,9 +135,9 @@ impl IncomeForm {
 
                                 // Handle Enter key to save
                                 if response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
-                                    if let Ok(amount) = self.amount.parse::<f64>() {
+                                    if let Some(amount) = evaluate_amount(&self.amount) {
                                         if amount >= 0.0 {
-                                            result = Some(amount);
+                                            result = Some((amount, self.scheduled.clone()));
                                             should_close = true;
                                         }
                                     }
@@ -129,6 +146,15 @@ impl IncomeForm {
                         });
                 });
 
+                ui.add_space(16.0);
+
+                // Scheduled income (e.g. a second paycheck) for the forecast
+                ui.vertical(|ui| {
+                    ui.label(RichText::new("Expected Later Each Month").size(13.0).color(label_color).strong());
+                    ui.add_space(6.0);
+                    self.render_schedule(ui);
+                });
+
                 ui.add_space(20.0);
 
                 // Modern buttons row
@@ -158,9 +184,9 @@ impl IncomeForm {
                     .min_size(Vec2::new(140.0, 44.0));
 
                     if ui.add(save_btn).clicked() {
-                        if let Ok(amount) = self.amount.parse::<f64>() {
+                        if let Some(amount) = evaluate_amount(&self.amount) {
                             if amount >= 0.0 {
-                                result = Some(amount);
+                                result = Some((amount, self.scheduled.clone()));
                                 should_close = true;
                             }
                         }
@@ -174,4 +200,67 @@ impl IncomeForm {
 
         result
     }
+
+    fn render_schedule(&mut self, ui: &mut egui::Ui) {
+        let mut to_remove: Option<usize> = None;
+        ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+
+        for (idx, entry) in self.scheduled.iter().enumerate() {
+            ui.horizontal(|ui| {
+                let label = if entry.label.is_empty() { "Income" } else { entry.label.as_str() };
+                ui.label(
+                    RichText::new(format!("Day {} · {}", entry.day, label))
+                        .size(12.0)
+                        .color(Color32::from_rgb(55, 65, 81)),
+                );
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let remove_btn = egui::Button::new(
+                        RichText::new("X").size(10.0).color(Color32::from_rgb(156, 163, 175)),
+                    )
+                    .fill(Color32::TRANSPARENT)
+                    .stroke(Stroke::NONE);
+                    if ui.add(remove_btn).clicked() {
+                        to_remove = Some(idx);
+                    }
+                    ui.label(
+                        RichText::new(format!("+${:.2}", entry.amount))
+                            .size(12.0)
+                            .color(Color32::from_rgb(5, 150, 105)),
+                    );
+                });
+            });
+        }
+        if let Some(idx) = to_remove {
+            self.scheduled.remove(idx);
+        }
+
+        ui.horizontal(|ui| {
+            ui.add(TextEdit::singleline(&mut self.new_day).desired_width(32.0).hint_text("Day"));
+            ui.add(TextEdit::singleline(&mut self.new_amount).desired_width(70.0).hint_text("0.00"));
+            ui.add(TextEdit::singleline(&mut self.new_label).desired_width(100.0).hint_text("Paycheck"));
+
+            let day = self.new_day.trim().parse::<u32>().ok().filter(|d| (1..=31).contains(d));
+            let amount = evaluate_amount(&self.new_amount).filter(|a| *a > 0.0);
+            let can_add = day.is_some() && amount.is_some();
+            let add_btn = egui::Button::new(
+                RichText::new("+")
+                    .size(14.0)
+                    .color(if can_add { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+            )
+            .fill(if can_add { Color32::from_rgb(16, 185, 129) } else { Color32::from_rgb(220, 220, 225) })
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(28.0, 24.0));
+
+            if ui.add(add_btn).clicked()
+                && let (Some(day), Some(amount)) = (day, amount)
+            {
+                self.scheduled.push(ScheduledIncome::new(day, amount, self.new_label.trim().to_string()));
+                self.scheduled.sort_by_key(|s| s.day);
+                self.new_day.clear();
+                self.new_amount.clear();
+                self.new_label.clear();
+            }
+        });
+    }
 }
@@ -1,21 +1,54 @@
+pub mod accounts_panel;
+pub mod amount_input;
+pub mod archived_view;
+pub mod bundle_dialog;
 pub mod calculator;
+pub mod calendar_view;
 pub mod category_manager;
+pub mod command_palette;
 pub mod dashboard;
+pub mod debts_panel;
+pub mod envelope_panel;
 pub mod expense_form;
+pub mod goals_panel;
 pub mod history;
+pub mod household_view;
 pub mod income_form;
+pub mod move_expenses;
+pub mod payees_panel;
 pub mod preset_panel;
+pub mod profile_compare;
 pub mod profile_manager;
 pub mod profile_selector;
+pub mod rules_panel;
+pub mod shortcut_editor;
+pub mod tag_report;
 pub mod template_manager;
+pub mod template_preview;
 
-pub use calculator::Calculator;
+pub use accounts_panel::{AccountAction, AccountsPanel};
+pub use archived_view::ArchivedView;
+pub use bundle_dialog::{BundleAction, BundleDialog};
+pub use calculator::{Calculator, CalculatorAction};
+pub use calendar_view::{CalendarAction, CalendarView};
 pub use category_manager::{CategoryAction, CategoryManager};
+pub use command_palette::{CommandPalette, PaletteCommand, PaletteEntry};
 pub use dashboard::render_dashboard;
+pub use debts_panel::{DebtAction, DebtsPanel};
+pub use envelope_panel::{EnvelopeAction, EnvelopePanel};
 pub use expense_form::ExpenseForm;
+pub use goals_panel::{GoalAction, GoalsPanel};
 pub use history::{render_balance_bar, render_expenses, render_expenses_header, HistoryAction};
+pub use household_view::HouseholdView;
 pub use income_form::IncomeForm;
+pub use move_expenses::{MoveExpensesAction, MoveExpensesDialog};
+pub use payees_panel::{PayeeAction, PayeesPanel};
 pub use preset_panel::{PresetAction, PresetPanel};
+pub use profile_compare::ProfileCompare;
 pub use profile_manager::{ProfileAction, ProfileManager};
 pub use profile_selector::{ProfileSelector, ProfileSelectorAction};
+pub use rules_panel::{RuleAction, RulesPanel};
+pub use shortcut_editor::{is_binding_pressed, KeymapAction, ShortcutEditor};
+pub use tag_report::TagReport;
 pub use template_manager::{TemplateAction, TemplateManager};
+pub use template_preview::{TemplatePreview, TemplatePreviewAction};
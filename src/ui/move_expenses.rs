@@ -0,0 +1,203 @@
+use egui::{Color32, Margin, RichText, Rounding, Stroke, Vec2};
+use uuid::Uuid;
+
+use crate::models::ProfileMeta;
+
+/// Actions that can be returned from the move/copy dialog
+pub enum MoveExpensesAction {
+    /// Move expenses (ids) from the current profile to another (profile_id)
+    Move(Vec<Uuid>, String),
+    /// Copy expenses (ids) to another profile (profile_id)
+    Copy(Vec<Uuid>, String),
+}
+
+/// Dialog for moving or copying expenses logged in the wrong profile
+pub struct MoveExpensesDialog {
+    pub is_open: bool,
+    expense_ids: Vec<Uuid>,
+    target_id: Option<String>,
+    /// Outcome of the last move or copy, shown until the dialog closes
+    result: Option<Result<String, String>>,
+}
+
+impl Default for MoveExpensesDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MoveExpensesDialog {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            expense_ids: Vec::new(),
+            target_id: None,
+            result: None,
+        }
+    }
+
+    pub fn open(&mut self, expense_ids: Vec<Uuid>) {
+        self.is_open = true;
+        self.expense_ids = expense_ids;
+        self.target_id = None;
+        self.result = None;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.expense_ids.clear();
+        self.result = None;
+    }
+
+    /// Show what the move or copy did instead of the profile picker
+    pub fn show_result(&mut self, result: Result<String, String>) {
+        self.result = Some(result);
+    }
+
+    pub fn render(
+        &mut self,
+        ctx: &egui::Context,
+        profiles: &[ProfileMeta],
+        current_profile_id: &str,
+    ) -> Option<MoveExpensesAction> {
+        let mut action = None;
+
+        if !self.is_open {
+            return action;
+        }
+
+        let count = self.expense_ids.len();
+        let title = if count == 1 {
+            "Move or Copy Expense".to_string()
+        } else {
+            format!("Move or Copy {} Expenses", count)
+        };
+
+        egui::Window::new("Move or Copy Expenses")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([360.0, 0.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(title)
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(12.0);
+
+                    if let Some(result) = &self.result {
+                        let (text, color) = match result {
+                            Ok(text) => (text, Color32::from_rgb(5, 150, 105)),
+                            Err(text) => (text, Color32::from_rgb(220, 38, 38)),
+                        };
+                        ui.label(RichText::new(text).size(13.0).color(color));
+                        return;
+                    }
+
+                    // Archived profiles are read-only
+                    let others: Vec<&ProfileMeta> = profiles
+                        .iter()
+                        .filter(|p| p.id != current_profile_id && !p.is_archived())
+                        .collect();
+                    if others.is_empty() {
+                        ui.label(
+                            RichText::new("Create another profile first")
+                                .size(13.0)
+                                .color(Color32::from_rgb(156, 163, 175)),
+                        );
+                        return;
+                    }
+
+                    ui.label(
+                        RichText::new("To profile")
+                            .size(12.0)
+                            .color(Color32::from_rgb(107, 114, 128)),
+                    );
+                    ui.add_space(4.0);
+                    for profile in others {
+                        let is_selected = self.target_id.as_ref() == Some(&profile.id);
+                        if ui.radio(is_selected, &profile.name).clicked() {
+                            self.target_id = Some(profile.id.clone());
+                        }
+                    }
+
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new("Expenses the profile already has are skipped.")
+                            .size(11.0)
+                            .color(Color32::from_rgb(156, 163, 175)),
+                    );
+                    ui.add_space(12.0);
+
+                    let enabled = self.target_id.is_some();
+                    ui.horizontal(|ui| {
+                        let move_btn = egui::Button::new(
+                            RichText::new("Move")
+                                .size(12.0)
+                                .color(if enabled { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+                        )
+                        .fill(if enabled { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+                        .stroke(Stroke::NONE)
+                        .rounding(Rounding::same(6.0))
+                        .min_size(Vec2::new(70.0, 28.0));
+
+                        let copy_btn = egui::Button::new(
+                            RichText::new("Copy")
+                                .size(12.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::NONE)
+                        .rounding(Rounding::same(6.0))
+                        .min_size(Vec2::new(70.0, 28.0));
+
+                        let move_clicked = ui.add_enabled(enabled, move_btn).clicked();
+                        let copy_clicked = ui.add_enabled(enabled, copy_btn).clicked();
+                        if let Some(target_id) = self.target_id.clone() {
+                            if move_clicked {
+                                action = Some(MoveExpensesAction::Move(self.expense_ids.clone(), target_id));
+                            } else if copy_clicked {
+                                action = Some(MoveExpensesAction::Copy(self.expense_ids.clone(), target_id));
+                            }
+                        }
+                    });
+                });
+            });
+
+        action
+    }
+}
@@ -0,0 +1,345 @@
+use egui::{Color32, ComboBox, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::models::{Payee, PayeeTotal};
+
+/// Actions that can be returned from the payees panel
+pub enum PayeeAction {
+    Create(String),
+    Delete(Uuid),
+    AddAlias(Uuid, String),
+    RemoveAlias(Uuid, String),
+    SetDefaultCategory(Uuid, Option<String>),
+    /// Rewrite the current profile's payees to their directory names
+    NormalizeExpenses,
+}
+
+#[derive(PartialEq)]
+enum Tab {
+    Directory,
+    Spending,
+}
+
+/// Window for the payee directory and the spending-by-payee report
+pub struct PayeesPanel {
+    pub is_open: bool,
+    tab: Tab,
+    new_name: String,
+    /// Alias being typed, per payee
+    alias_inputs: HashMap<Uuid, String>,
+    totals: Vec<PayeeTotal>,
+}
+
+impl Default for PayeesPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PayeesPanel {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            tab: Tab::Directory,
+            new_name: String::new(),
+            alias_inputs: HashMap::new(),
+            totals: Vec::new(),
+        }
+    }
+
+    /// Open with freshly computed spending totals
+    pub fn open(&mut self, totals: Vec<PayeeTotal>) {
+        self.is_open = true;
+        self.new_name.clear();
+        self.alias_inputs.clear();
+        self.totals = totals;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.totals.clear();
+    }
+
+    /// Replace the spending totals after the directory or expenses changed
+    pub fn set_totals(&mut self, totals: Vec<PayeeTotal>) {
+        self.totals = totals;
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, payees: &[Payee], categories: &[String]) -> Vec<PayeeAction> {
+        let mut actions: Vec<PayeeAction> = Vec::new();
+
+        if !self.is_open {
+            return actions;
+        }
+
+        egui::Window::new("Payees")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([460.0, 560.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new("Payees")
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        ui.selectable_value(&mut self.tab, Tab::Directory, "Directory");
+                        ui.selectable_value(&mut self.tab, Tab::Spending, "Spending by Payee");
+                    });
+                    ui.add_space(12.0);
+
+                    match self.tab {
+                        Tab::Directory => self.render_directory(ui, payees, categories, &mut actions),
+                        Tab::Spending => self.render_spending(ui),
+                    }
+                });
+            });
+
+        actions
+    }
+
+    fn render_directory(
+        &mut self,
+        ui: &mut egui::Ui,
+        payees: &[Payee],
+        categories: &[String],
+        actions: &mut Vec<PayeeAction>,
+    ) {
+        let muted = Color32::from_rgb(107, 114, 128);
+
+        ui.label(
+            RichText::new("Aliases match the start of an entered payee, so \"AMZN MKTP\" catches \"AMZN MKTP US*2K4\".")
+                .size(12.0)
+                .color(muted),
+        );
+        ui.add_space(8.0);
+
+        egui::ScrollArea::vertical()
+            .max_height(340.0)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.spacing_mut().item_spacing = Vec2::new(8.0, 8.0);
+
+                if payees.is_empty() {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(40.0);
+                        ui.label(
+                            RichText::new("No payees yet")
+                                .size(14.0)
+                                .color(Color32::from_rgb(156, 163, 175)),
+                        );
+                    });
+                }
+
+                for payee in payees {
+                    egui::Frame::none()
+                        .fill(Color32::from_rgb(249, 250, 251))
+                        .rounding(Rounding::same(12.0))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+                        .inner_margin(Margin::same(12.0))
+                        .show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    RichText::new(&payee.name)
+                                        .size(14.0)
+                                        .color(Color32::from_rgb(17, 24, 39))
+                                        .strong(),
+                                );
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    if ui.small_button("X").on_hover_text("Remove from directory").clicked() {
+                                        actions.push(PayeeAction::Delete(payee.id));
+                                    }
+
+                                    let selected = payee.default_category.clone().unwrap_or_else(|| "No default".to_string());
+                                    ComboBox::from_id_salt(("payee_category", payee.id))
+                                        .width(130.0)
+                                        .selected_text(RichText::new(selected).size(11.0))
+                                        .show_ui(ui, |ui| {
+                                            if ui.selectable_label(payee.default_category.is_none(), "No default").clicked() {
+                                                actions.push(PayeeAction::SetDefaultCategory(payee.id, None));
+                                            }
+                                            for category in categories {
+                                                let is_selected = payee.default_category.as_ref() == Some(category);
+                                                if ui.selectable_label(is_selected, category).clicked() {
+                                                    actions.push(PayeeAction::SetDefaultCategory(payee.id, Some(category.clone())));
+                                                }
+                                            }
+                                        });
+                                });
+                            });
+
+                            // Alias chips and input
+                            ui.horizontal_wrapped(|ui| {
+                                ui.spacing_mut().item_spacing = Vec2::new(4.0, 4.0);
+                                for alias in &payee.aliases {
+                                    let chip = egui::Button::new(
+                                        RichText::new(format!("{}  ×", alias)).size(11.0).color(muted),
+                                    )
+                                    .fill(Color32::from_rgb(243, 244, 246))
+                                    .stroke(Stroke::NONE)
+                                    .rounding(Rounding::same(10.0));
+                                    if ui.add(chip).on_hover_text("Remove alias").clicked() {
+                                        actions.push(PayeeAction::RemoveAlias(payee.id, alias.clone()));
+                                    }
+                                }
+
+                                let input = self.alias_inputs.entry(payee.id).or_default();
+                                let response = ui.add(
+                                    TextEdit::singleline(input)
+                                        .desired_width(120.0)
+                                        .hint_text("Add alias..."),
+                                );
+                                if response.lost_focus()
+                                    && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                                    && !input.trim().is_empty()
+                                {
+                                    actions.push(PayeeAction::AddAlias(payee.id, input.trim().to_string()));
+                                    input.clear();
+                                }
+                            });
+                        });
+                }
+            });
+
+        ui.add_space(12.0);
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                TextEdit::singleline(&mut self.new_name)
+                    .desired_width(200.0)
+                    .hint_text("New payee name"),
+            );
+            let name = self.new_name.trim();
+            let can_add = !name.is_empty() && !payees.iter().any(|p| p.name.eq_ignore_ascii_case(name));
+            let add_btn = egui::Button::new(
+                RichText::new("Add")
+                    .size(12.0)
+                    .color(if can_add { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+            )
+            .fill(if can_add { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(50.0, 26.0));
+
+            let submitted = ui.add(add_btn).clicked()
+                || (response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)));
+            if submitted && can_add {
+                actions.push(PayeeAction::Create(name.to_string()));
+                self.new_name.clear();
+            }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .small_button("Clean up payees")
+                    .on_hover_text("Rename payees in this profile's expenses to their directory names")
+                    .clicked()
+                {
+                    actions.push(PayeeAction::NormalizeExpenses);
+                }
+            });
+        });
+    }
+
+    fn render_spending(&self, ui: &mut egui::Ui) {
+        ui.label(
+            RichText::new("Active expenses across all profiles, grouped by directory name.")
+                .size(12.0)
+                .color(Color32::from_rgb(107, 114, 128)),
+        );
+        ui.add_space(8.0);
+
+        egui::ScrollArea::vertical()
+            .max_height(400.0)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.spacing_mut().item_spacing = Vec2::new(8.0, 8.0);
+
+                if self.totals.is_empty() {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(40.0);
+                        ui.label(
+                            RichText::new("No expenses with a payee yet")
+                                .size(14.0)
+                                .color(Color32::from_rgb(156, 163, 175)),
+                        );
+                    });
+                }
+
+                let max = self.totals.first().map_or(0.0, |t| t.total);
+                for total in &self.totals {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(&total.payee)
+                                .size(13.0)
+                                .color(Color32::from_rgb(17, 24, 39))
+                                .strong(),
+                        );
+                        ui.label(
+                            RichText::new(format!("{} expenses", total.count))
+                                .size(11.0)
+                                .color(Color32::from_rgb(156, 163, 175)),
+                        );
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.label(
+                                RichText::new(format!("${:.2}", total.total))
+                                    .size(13.0)
+                                    .color(Color32::from_rgb(17, 24, 39))
+                                    .strong(),
+                            );
+                        });
+                    })
+                    .response
+                    .on_hover_ui(|ui| {
+                        for (profile_name, amount) in &total.by_profile {
+                            ui.label(format!("{}: ${:.2}", profile_name, amount));
+                        }
+                    });
+
+                    // Share of the largest payee
+                    let fraction = if max > 0.0 { (total.total / max) as f32 } else { 0.0 };
+                    let (bar_rect, _) = ui.allocate_exact_size(Vec2::new(ui.available_width(), 6.0), egui::Sense::hover());
+                    ui.painter().rect_filled(bar_rect, Rounding::same(3.0), Color32::from_rgb(243, 244, 246));
+                    let mut fill_rect = bar_rect;
+                    fill_rect.set_right(bar_rect.left() + bar_rect.width() * fraction);
+                    ui.painter().rect_filled(fill_rect, Rounding::same(3.0), Color32::from_rgb(99, 102, 241));
+                }
+            });
+    }
+}
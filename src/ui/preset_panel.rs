@@ -2,11 +2,14 @@ use egui::{Color32, ComboBox, Margin, Pos2, RichText, Rounding, Stroke, TextEdit
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::models::{CategoryColor, ExpensePreset};
+use crate::models::{CategoryColor, ExpensePreset, PresetOverrides, Scope};
+use super::amount_input::{evaluate_amount, render_amount_preview};
 
 pub enum PresetAction {
-    Create(ExpensePreset),
+    Create(ExpensePreset, Scope),
     Delete(Uuid),
+    /// Hide or show a shared preset in the current profile
+    SetHidden(Uuid, bool),
     #[allow(dead_code)]
     AddToExpenses(Uuid), // Add preset as expense (via drag - kept for potential future use)
 }
@@ -24,6 +27,8 @@ pub struct PresetPanel {
     new_category: String,
     new_description: String,
     new_day: String,
+    /// Save the new preset to the current profile instead of the shared set
+    new_profile_only: bool,
 }
 
 impl Default for PresetPanel {
@@ -45,6 +50,7 @@ impl PresetPanel {
             new_category: String::new(),
             new_description: String::new(),
             new_day: String::new(),
+            new_profile_only: false,
         }
     }
 
@@ -71,6 +77,7 @@ impl PresetPanel {
         self.new_category.clear();
         self.new_description.clear();
         self.new_day.clear();
+        self.new_profile_only = false;
     }
 
     /// Returns true if currently dragging a preset (and moved enough to confirm)
@@ -106,12 +113,14 @@ impl PresetPanel {
         self.new_day.clear();
     }
 
+    /// `presets` are the shared presets; `overrides` adds the profile's own and hides some shared ones
     pub fn render(
         &mut self,
         ctx: &egui::Context,
         presets: &[ExpensePreset],
         categories: &[String],
         category_colors: &HashMap<String, CategoryColor>,
+        overrides: &PresetOverrides,
     ) -> Vec<PresetAction> {
         let mut actions: Vec<PresetAction> = Vec::new();
 
@@ -119,6 +128,14 @@ impl PresetPanel {
             return actions;
         }
 
+        let visible: Vec<(&ExpensePreset, Scope)> = presets
+            .iter()
+            .filter(|p| !overrides.hidden.contains(&p.id))
+            .map(|p| (p, Scope::Shared))
+            .chain(overrides.presets.iter().map(|p| (p, Scope::Profile)))
+            .collect();
+        let hidden: Vec<&ExpensePreset> = presets.iter().filter(|p| overrides.hidden.contains(&p.id)).collect();
+
         // Check if we should confirm the drag (moved enough distance)
         if self.dragging_preset_id.is_some() && !self.drag_confirmed {
             if let (Some(start), Some(current)) = (self.drag_start_pos, ctx.pointer_latest_pos()) {
@@ -132,7 +149,7 @@ impl PresetPanel {
         // Render floating drag preview only if drag is confirmed
         if self.drag_confirmed {
             if let Some(preset_id) = self.dragging_preset_id {
-                if let Some(preset) = presets.iter().find(|p| p.id == preset_id) {
+                if let Some((preset, _)) = visible.iter().find(|(p, _)| p.id == preset_id) {
                     self.render_drag_preview(ctx, preset, category_colors);
                 }
             }
@@ -199,7 +216,7 @@ impl PresetPanel {
                         .show(ui, |ui| {
                             ui.spacing_mut().item_spacing = Vec2::new(6.0, 8.0);
 
-                            if presets.is_empty() && !self.show_new_form {
+                            if visible.is_empty() && !self.show_new_form {
                                 ui.vertical_centered(|ui| {
                                     ui.add_space(30.0);
                                     ui.label(
@@ -220,7 +237,7 @@ impl PresetPanel {
                             // Collect preset IDs to delete (can't modify while iterating)
                             let mut preset_to_delete: Option<Uuid> = None;
 
-                            for preset in presets {
+                            for &(preset, scope) in &visible {
                                 // Use category color
                                 let display_color = category_colors
                                     .get(&preset.category)
@@ -242,7 +259,8 @@ impl PresetPanel {
                                 // Allocate the full size for the preset item first for drag detection
                                 let item_id = ui.id().with(("preset_item", preset.id));
                                 let desired_size = Vec2::new(ui.available_width(), 56.0);
-                                let (item_rect, item_response) = ui.allocate_exact_size(desired_size, egui::Sense::drag());
+                                let (item_rect, item_response) =
+                                    ui.allocate_exact_size(desired_size, egui::Sense::click_and_drag());
 
                                 // Draw the frame background
                                 ui.painter().rect_filled(
@@ -285,6 +303,14 @@ impl PresetPanel {
                                                         .color(Color32::from_rgb(156, 163, 175)),
                                                 );
                                             }
+                                            if scope == Scope::Profile {
+                                                ui.label(
+                                                    RichText::new("Profile")
+                                                        .size(10.0)
+                                                        .color(Color32::from_rgb(99, 102, 241)),
+                                                )
+                                                .on_hover_text("Only shown in the current profile");
+                                            }
                                         });
 
                                         // Amount and category
@@ -335,6 +361,39 @@ impl PresetPanel {
                                         self.drag_start_pos = ctx.pointer_latest_pos();
                                     }
                                 }
+
+                                if scope == Scope::Shared {
+                                    item_response.context_menu(|ui| {
+                                        if ui.button("Hide in this profile").clicked() {
+                                            actions.push(PresetAction::SetHidden(preset_id, true));
+                                            ui.close_menu();
+                                        }
+                                    });
+                                }
+                            }
+
+                            // Shared presets this profile hides, so they can be brought back
+                            if !hidden.is_empty() {
+                                ui.add_space(4.0);
+                                ui.label(
+                                    RichText::new("Hidden in this profile")
+                                        .size(11.0)
+                                        .color(Color32::from_rgb(156, 163, 175)),
+                                );
+                                for preset in &hidden {
+                                    ui.horizontal(|ui| {
+                                        ui.label(
+                                            RichText::new(&preset.name)
+                                                .size(12.0)
+                                                .color(Color32::from_rgb(156, 163, 175)),
+                                        );
+                                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                            if ui.small_button("Show").clicked() {
+                                                actions.push(PresetAction::SetHidden(preset.id, false));
+                                            }
+                                        });
+                                    });
+                                }
                             }
 
                             // Handle delete after the loop
@@ -424,9 +483,10 @@ impl PresetPanel {
                         );
                         ui.add(
                             TextEdit::singleline(&mut self.new_amount)
-                                .desired_width(ui.available_width() - 20.0)
+                                .desired_width(ui.available_width() - 80.0)
                                 .hint_text("0.00"),
                         );
+                        render_amount_preview(ui, &self.new_amount);
                     });
                     ui.add_space(6.0);
 
@@ -473,6 +533,9 @@ impl PresetPanel {
                                 .color(Color32::from_rgb(156, 163, 175)),
                         );
                     });
+                    ui.add_space(6.0);
+
+                    ui.checkbox(&mut self.new_profile_only, "Only in this profile");
                     ui.add_space(10.0);
 
                     // Buttons
@@ -495,7 +558,7 @@ impl PresetPanel {
 
                         let can_save = !self.new_name.trim().is_empty()
                             && !self.new_amount.is_empty()
-                            && self.new_amount.parse::<f64>().map(|v| v > 0.0).unwrap_or(false)
+                            && evaluate_amount(&self.new_amount).is_some_and(|v| v > 0.0)
                             && !self.new_category.is_empty();
 
                         let save_btn = egui::Button::new(
@@ -510,7 +573,7 @@ impl PresetPanel {
                         .min_size(Vec2::new(70.0, 32.0));
 
                         if ui.add(save_btn).clicked() && can_save {
-                            if let Ok(amount) = self.new_amount.parse::<f64>() {
+                            if let Some(amount) = evaluate_amount(&self.new_amount) {
                                 let mut preset = ExpensePreset::new(
                                     self.new_name.trim().to_string(),
                                     amount,
@@ -523,7 +586,8 @@ impl PresetPanel {
                                         preset = preset.with_day(day);
                                     }
                                 }
-                                actions.push(PresetAction::Create(preset));
+                                let scope = if self.new_profile_only { Scope::Profile } else { Scope::Shared };
+                                actions.push(PresetAction::Create(preset, scope));
                                 self.clear_form();
                             }
                         }
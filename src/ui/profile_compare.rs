@@ -0,0 +1,225 @@
+use egui::{Color32, Margin, RichText, Rounding, Stroke, Vec2};
+
+use crate::models::{category_union, ProfileMeta, ProfileSummary};
+
+/// Window putting two or more profiles side by side. The first selected
+/// profile is the baseline the others are compared against.
+pub struct ProfileCompare {
+    pub is_open: bool,
+    /// Profile ids in selection order
+    selected: Vec<String>,
+    summaries: Vec<ProfileSummary>,
+}
+
+impl Default for ProfileCompare {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProfileCompare {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            selected: Vec::new(),
+            summaries: Vec::new(),
+        }
+    }
+
+    /// Open with the current profile as the baseline
+    pub fn open(&mut self, current_profile_id: &str) {
+        self.is_open = true;
+        if !self.selected.iter().any(|id| id == current_profile_id) {
+            self.selected.insert(0, current_profile_id.to_string());
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.summaries.clear();
+    }
+
+    /// Selected profile ids, baseline first
+    pub fn selected(&self) -> &[String] {
+        &self.selected
+    }
+
+    /// Summaries for the selected profiles, in the same order
+    pub fn set_summaries(&mut self, summaries: Vec<ProfileSummary>) {
+        self.summaries = summaries;
+    }
+
+    /// Returns true when the selection changed and the summaries need reloading
+    pub fn render(&mut self, ctx: &egui::Context, profiles: &[ProfileMeta]) -> bool {
+        let mut changed = false;
+
+        if !self.is_open {
+            return changed;
+        }
+
+        // Forget deleted and archived profiles
+        let before = self.selected.len();
+        self.selected.retain(|id| profiles.iter().any(|p| p.id == *id && !p.is_archived()));
+        changed |= self.selected.len() != before;
+
+        let width = (260.0 + 130.0 * self.summaries.len() as f32).clamp(520.0, 900.0);
+
+        egui::Window::new("Compare Profiles")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([width, 560.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new("Compare Profiles")
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(8.0);
+
+                    // Profile picker
+                    ui.horizontal_wrapped(|ui| {
+                        for profile in profiles.iter().filter(|p| !p.is_archived()) {
+                            let mut is_selected = self.selected.contains(&profile.id);
+                            if ui.checkbox(&mut is_selected, &profile.name).changed() {
+                                if is_selected {
+                                    self.selected.push(profile.id.clone());
+                                } else {
+                                    self.selected.retain(|id| *id != profile.id);
+                                }
+                                changed = true;
+                            }
+                        }
+                    });
+
+                    ui.add_space(12.0);
+
+                    if self.summaries.len() < 2 {
+                        ui.vertical_centered(|ui| {
+                            ui.add_space(60.0);
+                            ui.label(
+                                RichText::new("Pick at least two profiles to compare")
+                                    .size(14.0)
+                                    .color(Color32::from_rgb(156, 163, 175)),
+                            );
+                        });
+                        return;
+                    }
+
+                    ui.label(
+                        RichText::new(format!(
+                            "Differences are against {}, the first profile picked.",
+                            self.summaries[0].name
+                        ))
+                        .size(12.0)
+                        .color(Color32::from_rgb(107, 114, 128)),
+                    );
+                    ui.add_space(8.0);
+
+                    egui::ScrollArea::vertical()
+                        .max_height(400.0)
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            render_table(ui, &self.summaries);
+                        });
+                });
+            });
+
+        changed
+    }
+}
+
+fn render_table(ui: &mut egui::Ui, summaries: &[ProfileSummary]) {
+    let muted = Color32::from_rgb(107, 114, 128);
+    let dark = Color32::from_rgb(17, 24, 39);
+
+    egui::Grid::new("profile_compare_grid")
+        .num_columns(summaries.len() + 1)
+        .striped(true)
+        .spacing(Vec2::new(18.0, 8.0))
+        .min_col_width(110.0)
+        .show(ui, |ui| {
+            ui.label("");
+            for summary in summaries {
+                ui.label(RichText::new(&summary.name).size(13.0).color(dark).strong());
+            }
+            ui.end_row();
+
+            // (label, values, whether more is better)
+            let rows = [
+                ("Income", summaries.iter().map(|s| s.income).collect(), true),
+                ("Expenses", summaries.iter().map(|s| s.expenses).collect(), false),
+                ("Balance", summaries.iter().map(|s| s.balance).collect(), true),
+            ];
+            for (label, values, more_is_better) in rows {
+                ui.label(RichText::new(label).size(12.0).color(dark).strong());
+                render_values(ui, values, more_is_better);
+                ui.end_row();
+            }
+
+            ui.label(RichText::new("By category").size(11.0).color(muted));
+            ui.end_row();
+
+            for category in category_union(summaries) {
+                ui.label(RichText::new(&category).size(12.0).color(muted));
+                render_values(ui, summaries.iter().map(|s| s.category_total(&category)).collect(), false);
+                ui.end_row();
+            }
+        });
+}
+
+/// One cell per profile: the amount, and for all but the baseline the
+/// difference from it, green when it's an improvement
+fn render_values(ui: &mut egui::Ui, values: Vec<f64>, more_is_better: bool) {
+    let baseline = values.first().copied().unwrap_or(0.0);
+    for (index, value) in values.iter().enumerate() {
+        ui.horizontal(|ui| {
+            let sign = if *value < -0.005 { "-" } else { "" };
+            ui.label(
+                RichText::new(format!("{}${:.2}", sign, value.abs()))
+                    .size(12.0)
+                    .color(Color32::from_rgb(55, 65, 81)),
+            );
+            let diff = value - baseline;
+            if index > 0 && diff.abs() >= 0.005 {
+                let better = (diff > 0.0) == more_is_better;
+                let color = if better { Color32::from_rgb(5, 150, 105) } else { Color32::from_rgb(220, 38, 38) };
+                let sign = if diff > 0.0 { "+" } else { "-" };
+                ui.label(RichText::new(format!("{}${:.2}", sign, diff.abs())).size(10.0).color(color));
+            }
+        });
+    }
+}
@@ -13,8 +13,24 @@ pub enum ProfileAction {
     Rename(String, String),
     /// Delete a profile
     Delete(String),
+    /// Hide a profile from the selector and make it read-only
+    Archive(String),
+    /// Bring an archived profile back
+    Restore(String),
     /// Switch to a profile
     Switch(String),
+    /// Look at an archived profile without restoring it
+    View(String),
+    /// Open the side-by-side comparison
+    Compare,
+    /// Open the combined household view
+    Household,
+    /// Merge a profile into another and delete it (source_id, target_id)
+    Merge(String, String),
+    /// Save a profile to a bundle file
+    Export(String),
+    /// Create a profile from a bundle file
+    Import,
 }
 
 /// Modal window for managing budget profiles
@@ -24,6 +40,10 @@ pub struct ProfileManager {
     renaming_profile_id: Option<String>,
     renaming_name: String,
     confirm_delete_id: Option<String>,
+    merging_profile_id: Option<String>,
+    merge_target_id: Option<String>,
+    /// Outcome of the last merge
+    notice: Option<String>,
 }
 
 impl ProfileManager {
@@ -34,6 +54,9 @@ impl ProfileManager {
             renaming_profile_id: None,
             renaming_name: String::new(),
             confirm_delete_id: None,
+            merging_profile_id: None,
+            merge_target_id: None,
+            notice: None,
         }
     }
 
@@ -52,6 +75,14 @@ impl ProfileManager {
         self.renaming_profile_id = None;
         self.renaming_name.clear();
         self.confirm_delete_id = None;
+        self.merging_profile_id = None;
+        self.merge_target_id = None;
+        self.notice = None;
+    }
+
+    /// Show a message under the header, e.g. what a merge did
+    pub fn set_notice(&mut self, notice: String) {
+        self.notice = Some(notice);
     }
 
     /// Render the profile manager modal
@@ -72,7 +103,7 @@ impl ProfileManager {
             .collapsible(false)
             .resizable(false)
             .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-            .fixed_size([420.0, 500.0])
+            .fixed_size([520.0, 500.0])
             .frame(
                 egui::Frame::none()
                     .fill(Color32::WHITE)
@@ -113,6 +144,15 @@ impl ProfileManager {
                     });
                 });
 
+                if let Some(notice) = &self.notice {
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new(notice)
+                            .size(12.0)
+                            .color(Color32::from_rgb(5, 150, 105)),
+                    );
+                }
+
                 ui.add_space(20.0);
 
                 // Create new profile section
@@ -169,22 +209,72 @@ impl ProfileManager {
                 ui.add_space(24.0);
 
                 // Existing profiles list
-                ui.label(
-                    RichText::new("Your Profiles")
-                        .size(13.0)
-                        .color(Color32::from_rgb(100, 100, 110))
-                        .strong(),
-                );
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new("Your Profiles")
+                            .size(13.0)
+                            .color(Color32::from_rgb(100, 100, 110))
+                            .strong(),
+                    );
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let import_btn = egui::Button::new(
+                            RichText::new("Import…")
+                                .size(11.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::NONE)
+                        .rounding(Rounding::same(6.0))
+                        .min_size(Vec2::new(60.0, 28.0));
+
+                        if ui.add(import_btn).on_hover_text("Create a profile from a bundle file").clicked() {
+                            actions.push(ProfileAction::Import);
+                        }
+
+                        if profiles.len() > 1 {
+                            let compare_btn = egui::Button::new(
+                                RichText::new("Compare")
+                                    .size(11.0)
+                                    .color(Color32::from_rgb(99, 102, 241)),
+                            )
+                            .fill(Color32::from_rgb(238, 242, 255))
+                            .stroke(Stroke::NONE)
+                            .rounding(Rounding::same(6.0))
+                            .min_size(Vec2::new(70.0, 28.0));
+
+                            if ui.add(compare_btn).on_hover_text("Put profiles side by side").clicked() {
+                                actions.push(ProfileAction::Compare);
+                            }
+
+                            let household_btn = egui::Button::new(
+                                RichText::new("Household")
+                                    .size(11.0)
+                                    .color(Color32::from_rgb(99, 102, 241)),
+                            )
+                            .fill(Color32::from_rgb(238, 242, 255))
+                            .stroke(Stroke::NONE)
+                            .rounding(Rounding::same(6.0))
+                            .min_size(Vec2::new(80.0, 28.0));
+
+                            if ui.add(household_btn).on_hover_text("Add profiles together").clicked() {
+                                actions.push(ProfileAction::Household);
+                            }
+                        }
+                    });
+                });
                 ui.add_space(8.0);
 
                 egui::ScrollArea::vertical()
                     .max_height(300.0)
                     .show(ui, |ui| {
-                        for profile in profiles {
+                        let visible_count = profiles.iter().filter(|p| !p.is_archived()).count();
+                        for profile in profiles.iter().filter(|p| !p.is_archived()) {
                             let is_current = profile.id == current_profile_id;
                             let is_renaming = self.renaming_profile_id.as_ref() == Some(&profile.id);
                             let is_confirming_delete =
                                 self.confirm_delete_id.as_ref() == Some(&profile.id);
+                            let is_merging = self.merging_profile_id.as_ref() == Some(&profile.id);
 
                             egui::Frame::none()
                                 .fill(if is_current {
@@ -212,14 +302,58 @@ impl ProfileManager {
                                             &profile.name,
                                             &mut actions,
                                         );
+                                    } else if is_merging {
+                                        self.render_merge_mode(ui, profile, profiles, &mut actions);
                                     } else {
                                         self.render_profile_row(
                                             ui,
                                             profile,
                                             is_current,
-                                            profiles.len(),
+                                            visible_count,
+                                            &mut actions,
+                                        );
+                                    }
+                                });
+
+                            ui.add_space(8.0);
+                        }
+
+                        // Archived profiles: read-only, restorable, and the only ones that can be deleted
+                        let archived: Vec<&ProfileMeta> = profiles.iter().filter(|p| p.is_archived()).collect();
+                        if !archived.is_empty() {
+                            ui.add_space(12.0);
+                            ui.label(
+                                RichText::new("Archived")
+                                    .size(13.0)
+                                    .color(Color32::from_rgb(100, 100, 110))
+                                    .strong(),
+                            );
+                            ui.label(
+                                RichText::new("Hidden from the profile switcher and read-only until restored.")
+                                    .size(11.0)
+                                    .color(Color32::from_rgb(156, 163, 175)),
+                            );
+                            ui.add_space(8.0);
+                        }
+                        for profile in archived {
+                            let is_confirming_delete =
+                                self.confirm_delete_id.as_ref() == Some(&profile.id);
+
+                            egui::Frame::none()
+                                .fill(Color32::from_rgb(243, 244, 246))
+                                .rounding(Rounding::same(12.0))
+                                .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+                                .inner_margin(Margin::same(14.0))
+                                .show(ui, |ui| {
+                                    if is_confirming_delete {
+                                        self.render_delete_confirm(
+                                            ui,
+                                            &profile.id,
+                                            &profile.name,
                                             &mut actions,
                                         );
+                                    } else {
+                                        self.render_archived_row(ui, profile, &mut actions);
                                     }
                                 });
 
@@ -267,25 +401,59 @@ impl ProfileManager {
             });
 
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                // Delete button (only if not current and more than 1 profile)
+                // Archive button (only if not current and more than 1 profile)
                 if !is_current && total_profiles > 1 {
-                    let del_btn = egui::Button::new(
-                        RichText::new("Delete")
+                    let archive_btn = egui::Button::new(
+                        RichText::new("Archive")
                             .size(11.0)
-                            .color(Color32::from_rgb(220, 38, 38)),
+                            .color(Color32::from_rgb(107, 114, 128)),
                     )
-                    .fill(Color32::from_rgb(254, 242, 242))
+                    .fill(Color32::from_rgb(243, 244, 246))
+                    .stroke(Stroke::NONE)
+                    .rounding(Rounding::same(6.0))
+                    .min_size(Vec2::new(55.0, 28.0));
+
+                    if ui.add(archive_btn).on_hover_text("Hide from the profile switcher").clicked() {
+                        actions.push(ProfileAction::Archive(profile.id.clone()));
+                    }
+
+                    ui.add_space(4.0);
+
+                    let merge_btn = egui::Button::new(
+                        RichText::new("Merge")
+                            .size(11.0)
+                            .color(Color32::from_rgb(99, 102, 241)),
+                    )
+                    .fill(Color32::from_rgb(238, 242, 255))
                     .stroke(Stroke::NONE)
                     .rounding(Rounding::same(6.0))
                     .min_size(Vec2::new(50.0, 28.0));
 
-                    if ui.add(del_btn).clicked() {
-                        self.confirm_delete_id = Some(profile.id.clone());
+                    if ui.add(merge_btn).on_hover_text("Merge into another profile").clicked() {
+                        self.merging_profile_id = Some(profile.id.clone());
+                        self.merge_target_id = None;
                     }
 
                     ui.add_space(4.0);
                 }
 
+                // Export button
+                let export_btn = egui::Button::new(
+                    RichText::new("Export")
+                        .size(11.0)
+                        .color(Color32::from_rgb(99, 102, 241)),
+                )
+                .fill(Color32::from_rgb(238, 242, 255))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(6.0))
+                .min_size(Vec2::new(50.0, 28.0));
+
+                if ui.add(export_btn).on_hover_text("Save to a bundle file").clicked() {
+                    actions.push(ProfileAction::Export(profile.id.clone()));
+                }
+
+                ui.add_space(4.0);
+
                 // Rename button
                 let rename_btn = egui::Button::new(
                     RichText::new("Rename")
@@ -342,6 +510,90 @@ impl ProfileManager {
         });
     }
 
+    fn render_archived_row(&mut self, ui: &mut egui::Ui, profile: &ProfileMeta, actions: &mut Vec<ProfileAction>) {
+        ui.horizontal(|ui| {
+            ui.vertical(|ui| {
+                ui.label(
+                    RichText::new(&profile.name)
+                        .size(14.0)
+                        .color(Color32::from_rgb(107, 114, 128))
+                        .strong(),
+                );
+                if let Some(archived_at) = profile.archived_at {
+                    ui.label(
+                        RichText::new(format!("Archived: {}", archived_at.format("%b %d, %Y")))
+                            .size(11.0)
+                            .color(Color32::from_rgb(156, 163, 175)),
+                    );
+                }
+            });
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let del_btn = egui::Button::new(
+                    RichText::new("Delete")
+                        .size(11.0)
+                        .color(Color32::from_rgb(220, 38, 38)),
+                )
+                .fill(Color32::from_rgb(254, 242, 242))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(6.0))
+                .min_size(Vec2::new(50.0, 28.0));
+
+                if ui.add(del_btn).clicked() {
+                    self.confirm_delete_id = Some(profile.id.clone());
+                }
+
+                ui.add_space(4.0);
+
+                let export_btn = egui::Button::new(
+                    RichText::new("Export")
+                        .size(11.0)
+                        .color(Color32::from_rgb(99, 102, 241)),
+                )
+                .fill(Color32::from_rgb(238, 242, 255))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(6.0))
+                .min_size(Vec2::new(50.0, 28.0));
+
+                if ui.add(export_btn).on_hover_text("Save to a bundle file").clicked() {
+                    actions.push(ProfileAction::Export(profile.id.clone()));
+                }
+
+                ui.add_space(4.0);
+
+                let restore_btn = egui::Button::new(
+                    RichText::new("Restore")
+                        .size(11.0)
+                        .color(Color32::WHITE),
+                )
+                .fill(Color32::from_rgb(99, 102, 241))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(6.0))
+                .min_size(Vec2::new(60.0, 28.0));
+
+                if ui.add(restore_btn).clicked() {
+                    actions.push(ProfileAction::Restore(profile.id.clone()));
+                }
+
+                ui.add_space(4.0);
+
+                let view_btn = egui::Button::new(
+                    RichText::new("View")
+                        .size(11.0)
+                        .color(Color32::from_rgb(99, 102, 241)),
+                )
+                .fill(Color32::from_rgb(238, 242, 255))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(6.0))
+                .min_size(Vec2::new(50.0, 28.0));
+
+                if ui.add(view_btn).on_hover_text("Look at it without restoring").clicked() {
+                    actions.push(ProfileAction::View(profile.id.clone()));
+                }
+            });
+        });
+    }
+
     fn render_rename_mode(
         &mut self,
         ui: &mut egui::Ui,
@@ -402,6 +654,81 @@ impl ProfileManager {
         });
     }
 
+    fn render_merge_mode(
+        &mut self,
+        ui: &mut egui::Ui,
+        profile: &ProfileMeta,
+        profiles: &[ProfileMeta],
+        actions: &mut Vec<ProfileAction>,
+    ) {
+        ui.vertical(|ui| {
+            ui.label(
+                RichText::new(format!("Merge \"{}\" into", profile.name))
+                    .size(13.0)
+                    .color(Color32::from_rgb(17, 24, 39))
+                    .strong(),
+            );
+            ui.add_space(4.0);
+            ui.horizontal_wrapped(|ui| {
+                for other in profiles.iter().filter(|p| p.id != profile.id && !p.is_archived()) {
+                    let is_selected = self.merge_target_id.as_ref() == Some(&other.id);
+                    if ui.radio(is_selected, &other.name).clicked() {
+                        self.merge_target_id = Some(other.id.clone());
+                    }
+                }
+            });
+            ui.add_space(4.0);
+            ui.label(
+                RichText::new(format!(
+                    "Income, expenses, goals, debts and accounts are added to the chosen profile, \
+                     skipping duplicates. \"{}\" is then deleted.",
+                    profile.name
+                ))
+                .size(11.0)
+                .color(Color32::from_rgb(156, 163, 175)),
+            );
+            ui.add_space(8.0);
+
+            ui.horizontal(|ui| {
+                let can_merge = self.merge_target_id.is_some();
+                let confirm_btn = egui::Button::new(
+                    RichText::new("Merge")
+                        .size(11.0)
+                        .color(if can_merge { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+                )
+                .fill(if can_merge { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(6.0))
+                .min_size(Vec2::new(60.0, 28.0));
+
+                if ui.add(confirm_btn).clicked()
+                    && let Some(target_id) = self.merge_target_id.take()
+                {
+                    actions.push(ProfileAction::Merge(profile.id.clone(), target_id));
+                    self.merging_profile_id = None;
+                }
+
+                ui.add_space(8.0);
+
+                // Cancel button
+                let cancel_btn = egui::Button::new(
+                    RichText::new("Cancel")
+                        .size(11.0)
+                        .color(Color32::from_rgb(107, 114, 128)),
+                )
+                .fill(Color32::from_rgb(243, 244, 246))
+                .stroke(Stroke::NONE)
+                .rounding(Rounding::same(6.0))
+                .min_size(Vec2::new(55.0, 28.0));
+
+                if ui.add(cancel_btn).clicked() {
+                    self.merging_profile_id = None;
+                    self.merge_target_id = None;
+                }
+            });
+        });
+    }
+
     fn render_delete_confirm(
         &mut self,
         ui: &mut egui::Ui,
@@ -1,4 +1,4 @@
-use egui::{Color32, RichText, Rounding, Stroke, Vec2};
+use egui::{Color32, Pos2, Rect, RichText, Rounding, Stroke, Vec2};
 
 use crate::models::ProfileMeta;
 
@@ -14,23 +14,41 @@ pub enum ProfileSelectorAction {
 /// A dropdown selector for switching between budget profiles
 pub struct ProfileSelector {
     popup_open: bool,
+    /// Profile chips from the last frame a preset was dragged, used to find
+    /// which profile it was dropped on
+    drop_rects: Vec<(String, Rect)>,
 }
 
 impl ProfileSelector {
     pub fn new() -> Self {
-        Self { popup_open: false }
+        Self {
+            popup_open: false,
+            drop_rects: Vec::new(),
+        }
+    }
+
+    /// Profile under a screen position, if a preset drag was over a profile chip
+    pub fn profile_at(&self, pos: Pos2) -> Option<&str> {
+        self.drop_rects
+            .iter()
+            .find(|(_, rect)| rect.contains(pos))
+            .map(|(id, _)| id.as_str())
     }
 
     /// Render the profile selector dropdown
-    /// Returns an action if the user interacted with it
+    /// Returns an action if the user interacted with it.
+    /// While `dragging_preset` is set the profile list stays open as drop targets.
     pub fn render(
         &mut self,
         ui: &mut egui::Ui,
         profiles: &[ProfileMeta],
         current_profile_id: &str,
         width: f32,
+        shortcut_hint: &str,
+        dragging_preset: bool,
     ) -> Option<ProfileSelectorAction> {
         let mut action: Option<ProfileSelectorAction> = None;
+        self.drop_rects.clear();
 
         // Find current profile name
         let current_name = profiles
@@ -54,14 +72,15 @@ impl ProfileSelector {
         .min_size(Vec2::new(width, 50.0));
 
         let response = ui.add(button)
-            .on_hover_text("P: Switch profile | ⌘P: Manage profiles");
+            .on_hover_text(shortcut_hint);
 
         if response.clicked() {
             self.popup_open = !self.popup_open;
         }
 
         // Show popup below the button
-        if self.popup_open {
+        if self.popup_open || dragging_preset {
+            let pointer = ui.ctx().pointer_latest_pos();
             let popup_pos = response.rect.left_bottom();
 
             egui::Area::new(popup_id)
@@ -82,8 +101,8 @@ impl ProfileSelector {
                         .show(ui, |ui| {
                             ui.set_width(width - 16.0);
 
-                            // List all profiles
-                            for profile in profiles {
+                            // List all profiles except archived ones
+                            for profile in profiles.iter().filter(|p| !p.is_archived()) {
                                 let is_selected = profile.id == current_profile_id;
 
                                 let item_response = ui.add(
@@ -106,6 +125,18 @@ impl ProfileSelector {
                                     .min_size(Vec2::new(width - 32.0, 36.0)),
                                 );
 
+                                if dragging_preset {
+                                    let rect = item_response.rect;
+                                    if pointer.is_some_and(|p| rect.contains(p)) {
+                                        ui.painter().rect_stroke(
+                                            rect,
+                                            Rounding::same(8.0),
+                                            Stroke::new(2.0, Color32::from_rgb(99, 102, 241)),
+                                        );
+                                    }
+                                    self.drop_rects.push((profile.id.clone(), rect));
+                                }
+
                                 if item_response.clicked() && !is_selected {
                                     action = Some(ProfileSelectorAction::SwitchProfile(
                                         profile.id.clone(),
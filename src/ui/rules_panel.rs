@@ -0,0 +1,474 @@
+use egui::{Color32, ComboBox, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use uuid::Uuid;
+
+use super::amount_input::evaluate_amount;
+use crate::models::{Rule, RuleActions, RuleConditions, TextMatch};
+
+/// Actions that can be returned from the rules panel
+pub enum RuleAction {
+    /// Add a new rule or replace the one with the same id
+    Save(Rule),
+    Delete(Uuid),
+    SetEnabled(Uuid, bool),
+    /// Move a rule earlier (true) or later in the run order
+    Move(Uuid, bool),
+    /// Run the enabled rules over the current profile's expenses
+    ApplyAll,
+}
+
+/// Editable copy of a rule
+struct RuleDraft {
+    /// Rule being edited; None for a new rule
+    id: Option<Uuid>,
+    enabled: bool,
+    name: String,
+    use_regex: bool,
+    pattern: String,
+    min_amount: String,
+    max_amount: String,
+    payee: String,
+    /// Empty means "don't change the category"
+    category: String,
+    tag: String,
+    mark_inactive: bool,
+}
+
+impl RuleDraft {
+    fn new() -> Self {
+        Self {
+            id: None,
+            enabled: true,
+            name: String::new(),
+            use_regex: false,
+            pattern: String::new(),
+            min_amount: String::new(),
+            max_amount: String::new(),
+            payee: String::new(),
+            category: String::new(),
+            tag: String::new(),
+            mark_inactive: false,
+        }
+    }
+
+    fn from_rule(rule: &Rule, existing: bool) -> Self {
+        let c = &rule.conditions;
+        let a = &rule.actions;
+        Self {
+            id: existing.then_some(rule.id),
+            enabled: rule.enabled,
+            name: rule.name.clone(),
+            use_regex: matches!(c.description, Some(TextMatch::Regex(_))),
+            pattern: c.description.as_ref().map(|m| m.pattern().to_string()).unwrap_or_default(),
+            min_amount: c.min_amount.map(|a| format!("{:.2}", a)).unwrap_or_default(),
+            max_amount: c.max_amount.map(|a| format!("{:.2}", a)).unwrap_or_default(),
+            payee: c.payee.clone().unwrap_or_default(),
+            category: a.set_category.clone().unwrap_or_default(),
+            tag: a.add_tag.clone().unwrap_or_default(),
+            mark_inactive: a.mark_inactive,
+        }
+    }
+
+    fn description_match(&self) -> Option<TextMatch> {
+        let pattern = self.pattern.trim();
+        if pattern.is_empty() {
+            None
+        } else if self.use_regex {
+            Some(TextMatch::Regex(pattern.to_string()))
+        } else {
+            Some(TextMatch::Contains(pattern.to_string()))
+        }
+    }
+
+    /// The rule, or why it can't be saved yet
+    fn to_rule(&self) -> Result<Rule, String> {
+        let parse_amount = |text: &str| -> Result<Option<f64>, String> {
+            if text.trim().is_empty() {
+                return Ok(None);
+            }
+            evaluate_amount(text).map(Some).ok_or_else(|| format!("\"{}\" is not an amount", text.trim()))
+        };
+
+        if self.name.trim().is_empty() {
+            return Err("Give the rule a name".to_string());
+        }
+        let description = self.description_match();
+        if let Some(error) = description.as_ref().and_then(TextMatch::error) {
+            return Err(error);
+        }
+        let conditions = RuleConditions {
+            description,
+            min_amount: parse_amount(&self.min_amount)?,
+            max_amount: parse_amount(&self.max_amount)?,
+            payee: Some(self.payee.trim().to_string()).filter(|p| !p.is_empty()),
+        };
+        if conditions.is_empty() {
+            return Err("Add at least one condition".to_string());
+        }
+        let actions = RuleActions {
+            set_category: Some(self.category.clone()).filter(|c| !c.is_empty()),
+            add_tag: Some(self.tag.trim().to_string()).filter(|t| !t.is_empty()),
+            mark_inactive: self.mark_inactive,
+        };
+        if actions.is_empty() {
+            return Err("Add at least one action".to_string());
+        }
+
+        let mut rule = Rule::new(self.name.trim().to_string(), conditions, actions);
+        rule.enabled = self.enabled;
+        if let Some(id) = self.id {
+            rule.id = id;
+        }
+        Ok(rule)
+    }
+}
+
+/// Window for managing auto-categorization rules
+pub struct RulesPanel {
+    pub is_open: bool,
+    draft: RuleDraft,
+    /// Outcome of the last "apply" run, shown under the list
+    last_result: Option<String>,
+}
+
+impl Default for RulesPanel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RulesPanel {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            draft: RuleDraft::new(),
+            last_result: None,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.draft = RuleDraft::new();
+        self.last_result = None;
+    }
+
+    /// Open with a new, unsaved rule filled in (e.g. from an expense)
+    pub fn open_with(&mut self, rule: &Rule) {
+        self.open();
+        self.draft = RuleDraft::from_rule(rule, false);
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Report how many expenses the last apply run changed
+    pub fn set_applied(&mut self, changed: usize) {
+        self.last_result = Some(match changed {
+            0 => "No expenses changed".to_string(),
+            1 => "1 expense updated".to_string(),
+            n => format!("{} expenses updated", n),
+        });
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, rules: &[Rule], categories: &[String]) -> Vec<RuleAction> {
+        let mut actions: Vec<RuleAction> = Vec::new();
+
+        if !self.is_open {
+            return actions;
+        }
+
+        egui::Window::new("Rules")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([480.0, 620.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new("Auto-Categorization Rules")
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(4.0);
+                    ui.label(
+                        RichText::new("Rules run top to bottom, so a later rule's category wins.")
+                            .size(12.0)
+                            .color(Color32::from_rgb(107, 114, 128)),
+                    );
+                    ui.add_space(12.0);
+
+                    egui::ScrollArea::vertical()
+                        .max_height(200.0)
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            ui.spacing_mut().item_spacing = Vec2::new(8.0, 6.0);
+
+                            if rules.is_empty() {
+                                ui.vertical_centered(|ui| {
+                                    ui.add_space(30.0);
+                                    ui.label(
+                                        RichText::new("No rules yet")
+                                            .size(14.0)
+                                            .color(Color32::from_rgb(156, 163, 175)),
+                                    );
+                                    ui.label(
+                                        RichText::new("Right-click an expense to create one from it.")
+                                            .size(12.0)
+                                            .color(Color32::from_rgb(180, 180, 190)),
+                                    );
+                                });
+                            }
+
+                            for (index, rule) in rules.iter().enumerate() {
+                                self.render_rule(ui, rule, index == 0, index + 1 == rules.len(), &mut actions);
+                            }
+                        });
+
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        let apply_btn = egui::Button::new(
+                            RichText::new("Apply to this profile")
+                                .size(12.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                        .rounding(Rounding::same(6.0));
+                        if ui
+                            .add_enabled(rules.iter().any(|r| r.enabled), apply_btn)
+                            .on_hover_text("Run the enabled rules over every expense in the current profile")
+                            .clicked()
+                        {
+                            actions.push(RuleAction::ApplyAll);
+                        }
+                        if let Some(result) = &self.last_result {
+                            ui.label(RichText::new(result).size(11.0).color(Color32::from_rgb(107, 114, 128)));
+                        }
+                    });
+
+                    ui.add_space(12.0);
+                    ui.add(egui::Separator::default().spacing(8.0));
+                    self.render_editor(ui, categories, &mut actions);
+                });
+            });
+
+        actions
+    }
+
+    fn render_rule(&mut self, ui: &mut egui::Ui, rule: &Rule, is_first: bool, is_last: bool, actions: &mut Vec<RuleAction>) {
+        let is_editing = self.draft.id == Some(rule.id);
+
+        egui::Frame::none()
+            .fill(if is_editing { Color32::from_rgb(238, 242, 255) } else { Color32::from_rgb(249, 250, 251) })
+            .rounding(Rounding::same(10.0))
+            .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+            .inner_margin(Margin::symmetric(12.0, 8.0))
+            .show(ui, |ui| {
+                ui.set_width(ui.available_width());
+                ui.horizontal(|ui| {
+                    let mut enabled = rule.enabled;
+                    if ui.checkbox(&mut enabled, "").on_hover_text("Enabled").changed() {
+                        actions.push(RuleAction::SetEnabled(rule.id, enabled));
+                    }
+                    ui.vertical(|ui| {
+                        let name_color = if rule.enabled { Color32::from_rgb(17, 24, 39) } else { Color32::from_rgb(156, 163, 175) };
+                        ui.label(RichText::new(&rule.name).size(13.0).color(name_color).strong());
+                        ui.label(
+                            RichText::new(rule_summary(rule))
+                                .size(11.0)
+                                .color(Color32::from_rgb(107, 114, 128)),
+                        );
+                    });
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("X").on_hover_text("Delete rule").clicked() {
+                            actions.push(RuleAction::Delete(rule.id));
+                            if is_editing {
+                                self.draft = RuleDraft::new();
+                            }
+                        }
+                        if ui.small_button("Edit").clicked() {
+                            self.draft = RuleDraft::from_rule(rule, true);
+                        }
+                        if ui.add_enabled(!is_last, egui::Button::new("↓").small()).clicked() {
+                            actions.push(RuleAction::Move(rule.id, false));
+                        }
+                        if ui.add_enabled(!is_first, egui::Button::new("↑").small()).clicked() {
+                            actions.push(RuleAction::Move(rule.id, true));
+                        }
+                    });
+                });
+            });
+    }
+
+    fn render_editor(&mut self, ui: &mut egui::Ui, categories: &[String], actions: &mut Vec<RuleAction>) {
+        let label_color = Color32::from_rgb(100, 100, 110);
+        let draft = &mut self.draft;
+
+        ui.label(
+            RichText::new(if draft.id.is_some() { "Edit Rule" } else { "New Rule" })
+                .size(12.0)
+                .color(label_color),
+        );
+        ui.add_space(4.0);
+        ui.add(TextEdit::singleline(&mut draft.name).desired_width(300.0).hint_text("Rule name"));
+
+        ui.add_space(8.0);
+        ui.label(RichText::new("When").size(12.0).color(label_color).strong());
+        egui::Grid::new("rule_conditions")
+            .num_columns(2)
+            .spacing(Vec2::new(10.0, 6.0))
+            .show(ui, |ui| {
+                ui.label(RichText::new("Description").size(12.0));
+                ui.horizontal(|ui| {
+                    ComboBox::from_id_salt("rule_match_kind")
+                        .width(80.0)
+                        .selected_text(if draft.use_regex { "matches" } else { "contains" })
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut draft.use_regex, false, "contains");
+                            ui.selectable_value(&mut draft.use_regex, true, "matches");
+                        });
+                    let hint = if draft.use_regex { "^netflix|hulu" } else { "netflix" };
+                    ui.add(TextEdit::singleline(&mut draft.pattern).desired_width(180.0).hint_text(hint));
+                });
+                ui.end_row();
+
+                ui.label(RichText::new("Amount").size(12.0));
+                ui.horizontal(|ui| {
+                    ui.add(TextEdit::singleline(&mut draft.min_amount).desired_width(70.0).hint_text("min"));
+                    ui.label("to");
+                    ui.add(TextEdit::singleline(&mut draft.max_amount).desired_width(70.0).hint_text("max"));
+                });
+                ui.end_row();
+
+                ui.label(RichText::new("Payee").size(12.0));
+                ui.add(TextEdit::singleline(&mut draft.payee).desired_width(180.0).hint_text("Any payee"));
+                ui.end_row();
+            });
+
+        ui.add_space(8.0);
+        ui.label(RichText::new("Then").size(12.0).color(label_color).strong());
+        egui::Grid::new("rule_actions")
+            .num_columns(2)
+            .spacing(Vec2::new(10.0, 6.0))
+            .show(ui, |ui| {
+                ui.label(RichText::new("Category").size(12.0));
+                let selected = if draft.category.is_empty() { "Don't change".to_string() } else { draft.category.clone() };
+                ComboBox::from_id_salt("rule_category")
+                    .width(180.0)
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut draft.category, String::new(), "Don't change");
+                        for category in categories {
+                            ui.selectable_value(&mut draft.category, category.clone(), category);
+                        }
+                    });
+                ui.end_row();
+
+                ui.label(RichText::new("Add tag").size(12.0));
+                ui.add(TextEdit::singleline(&mut draft.tag).desired_width(180.0).hint_text("none"));
+                ui.end_row();
+
+                ui.label("");
+                ui.checkbox(&mut draft.mark_inactive, RichText::new("Mark inactive").size(12.0));
+                ui.end_row();
+            });
+
+        ui.add_space(10.0);
+        let result = self.draft.to_rule();
+        ui.horizontal(|ui| {
+            let can_save = result.is_ok();
+            let save_btn = egui::Button::new(
+                RichText::new(if self.draft.id.is_some() { "Save Rule" } else { "Add Rule" })
+                    .size(12.0)
+                    .color(if can_save { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+            )
+            .fill(if can_save { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+            .stroke(Stroke::NONE)
+            .rounding(Rounding::same(6.0))
+            .min_size(Vec2::new(80.0, 26.0));
+
+            if ui.add(save_btn).clicked()
+                && let Ok(rule) = &result
+            {
+                actions.push(RuleAction::Save(rule.clone()));
+                self.draft = RuleDraft::new();
+            }
+
+            if self.draft.id.is_some() && ui.small_button("Cancel").clicked() {
+                self.draft = RuleDraft::new();
+            }
+
+            if let Err(message) = &result
+                && !self.draft.name.is_empty()
+            {
+                ui.label(RichText::new(message).size(11.0).color(Color32::from_rgb(220, 38, 38)));
+            }
+        });
+    }
+}
+
+/// One-line description of a rule's conditions and actions
+fn rule_summary(rule: &Rule) -> String {
+    let c = &rule.conditions;
+    let mut when: Vec<String> = Vec::new();
+    match &c.description {
+        Some(TextMatch::Contains(text)) => when.push(format!("description contains \"{}\"", text)),
+        Some(TextMatch::Regex(pattern)) => when.push(format!("description matches /{}/", pattern)),
+        None => {}
+    }
+    match (c.min_amount, c.max_amount) {
+        (Some(min), Some(max)) => when.push(format!("${:.2}–${:.2}", min, max)),
+        (Some(min), None) => when.push(format!("≥ ${:.2}", min)),
+        (None, Some(max)) => when.push(format!("≤ ${:.2}", max)),
+        (None, None) => {}
+    }
+    if let Some(payee) = &c.payee {
+        when.push(format!("payee {}", payee));
+    }
+
+    let a = &rule.actions;
+    let mut then: Vec<String> = Vec::new();
+    if let Some(category) = &a.set_category {
+        then.push(category.clone());
+    }
+    if let Some(tag) = &a.add_tag {
+        then.push(format!("#{}", tag));
+    }
+    if a.mark_inactive {
+        then.push("inactive".to_string());
+    }
+
+    format!("{} → {}", when.join(", "), then.join(", "))
+}
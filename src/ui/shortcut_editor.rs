@@ -0,0 +1,198 @@
+use egui::{Color32, Key, Margin, RichText, Rounding, Stroke, Vec2};
+
+use crate::models::{KeyBinding, Keymap, ShortcutAction};
+
+/// Actions that can be returned from the shortcut editor
+pub enum KeymapAction {
+    /// Bind an action to a new key
+    Rebind(ShortcutAction, KeyBinding),
+    /// Restore all default bindings
+    ResetAll,
+}
+
+/// Returns true if the binding was pressed this frame (modifier must match exactly,
+/// so "P" and "⌘P" can be bound to different actions)
+pub fn is_binding_pressed(input: &egui::InputState, binding: &KeyBinding) -> bool {
+    let Some(key) = Key::from_name(&binding.key) else {
+        return false;
+    };
+    input.key_pressed(key) && input.modifiers.command == binding.command
+}
+
+/// Modal window for remapping keyboard shortcuts
+pub struct ShortcutEditor {
+    pub is_open: bool,
+    /// Action currently waiting for a key press
+    capturing: Option<ShortcutAction>,
+}
+
+impl Default for ShortcutEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShortcutEditor {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            capturing: None,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.capturing = None;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.capturing = None;
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context, keymap: &Keymap) -> Vec<KeymapAction> {
+        let mut actions: Vec<KeymapAction> = Vec::new();
+
+        if !self.is_open {
+            return actions;
+        }
+
+        // Capture the next key press for the selected action (Escape cancels)
+        if let Some(action) = self.capturing {
+            let pressed = ctx.input(|i| {
+                i.events.iter().find_map(|event| match event {
+                    egui::Event::Key { key, pressed: true, modifiers, .. } => Some((*key, *modifiers)),
+                    _ => None,
+                })
+            });
+            if let Some((key, modifiers)) = pressed {
+                if key != Key::Escape {
+                    actions.push(KeymapAction::Rebind(
+                        action,
+                        KeyBinding::new(key.name(), modifiers.command),
+                    ));
+                }
+                self.capturing = None;
+            }
+        }
+
+        egui::Window::new("Keyboard Shortcuts")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([380.0, 460.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new("Keyboard Shortcuts")
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new("Click a shortcut, then press the new key. Hold ⌘/Ctrl to include it.")
+                            .size(12.0)
+                            .color(Color32::from_rgb(107, 114, 128)),
+                    );
+                    ui.add_space(16.0);
+
+                    ui.spacing_mut().item_spacing = Vec2::new(8.0, 6.0);
+
+                    for action in ShortcutAction::ALL {
+                        let is_capturing = self.capturing == Some(*action);
+
+                        egui::Frame::none()
+                            .fill(Color32::from_rgb(249, 250, 251))
+                            .rounding(Rounding::same(8.0))
+                            .inner_margin(Margin::symmetric(12.0, 6.0))
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    ui.label(
+                                        RichText::new(action.label())
+                                            .size(13.0)
+                                            .color(Color32::from_rgb(50, 50, 60)),
+                                    );
+
+                                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                        let text = if is_capturing {
+                                            "Press a key...".to_string()
+                                        } else {
+                                            keymap.binding(*action).label()
+                                        };
+                                        let key_btn = egui::Button::new(
+                                            RichText::new(text)
+                                                .size(12.0)
+                                                .color(Color32::from_rgb(99, 102, 241))
+                                                .strong(),
+                                        )
+                                        .fill(if is_capturing {
+                                            Color32::from_rgb(224, 231, 255)
+                                        } else {
+                                            Color32::from_rgb(238, 242, 255)
+                                        })
+                                        .stroke(Stroke::new(1.0, Color32::from_rgb(199, 210, 254)))
+                                        .rounding(Rounding::same(6.0))
+                                        .min_size(Vec2::new(90.0, 26.0));
+
+                                        if ui.add(key_btn).clicked() {
+                                            self.capturing = if is_capturing { None } else { Some(*action) };
+                                        }
+                                    });
+                                });
+                            });
+                    }
+
+                    ui.add_space(16.0);
+
+                    let reset_btn = egui::Button::new(
+                        RichText::new("Reset to Defaults")
+                            .size(12.0)
+                            .color(Color32::from_rgb(107, 114, 128)),
+                    )
+                    .fill(Color32::from_rgb(243, 244, 246))
+                    .stroke(Stroke::NONE)
+                    .rounding(Rounding::same(8.0))
+                    .min_size(Vec2::new(140.0, 32.0));
+
+                    if ui.add(reset_btn).clicked() {
+                        actions.push(KeymapAction::ResetAll);
+                        self.capturing = None;
+                    }
+                });
+            });
+
+        actions
+    }
+}
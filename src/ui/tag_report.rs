@@ -0,0 +1,176 @@
+use egui::{Color32, Margin, RichText, Rounding, Stroke, Vec2};
+
+use crate::models::TagTotal;
+
+/// Read-only window listing spending per tag across all profiles
+pub struct TagReport {
+    pub is_open: bool,
+    totals: Vec<TagTotal>,
+}
+
+impl Default for TagReport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TagReport {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            totals: Vec::new(),
+        }
+    }
+
+    /// Open the report with freshly computed totals
+    pub fn open(&mut self, totals: Vec<TagTotal>) {
+        self.is_open = true;
+        self.totals = totals;
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.totals.clear();
+    }
+
+    /// Returns the tag to filter the expense list by, if one was clicked
+    pub fn render(&mut self, ctx: &egui::Context) -> Option<String> {
+        if !self.is_open {
+            return None;
+        }
+
+        let mut filter_tag: Option<String> = None;
+
+        egui::Window::new("Tag Report")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([420.0, 480.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new("Spending by Tag")
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new("Active expenses across all profiles. An expense counts toward every tag it has.")
+                            .size(12.0)
+                            .color(Color32::from_rgb(107, 114, 128)),
+                    );
+                    ui.add_space(16.0);
+
+                    egui::ScrollArea::vertical()
+                        .max_height(360.0)
+                        .show(ui, |ui| {
+                            ui.spacing_mut().item_spacing = Vec2::new(8.0, 8.0);
+
+                            if self.totals.is_empty() {
+                                ui.vertical_centered(|ui| {
+                                    ui.add_space(40.0);
+                                    ui.label(
+                                        RichText::new("No tagged expenses yet")
+                                            .size(14.0)
+                                            .color(Color32::from_rgb(156, 163, 175)),
+                                    );
+                                    ui.add_space(40.0);
+                                });
+                            }
+
+                            for total in &self.totals {
+                                egui::Frame::none()
+                                    .fill(Color32::from_rgb(249, 250, 251))
+                                    .rounding(Rounding::same(12.0))
+                                    .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
+                                    .inner_margin(Margin::same(14.0))
+                                    .show(ui, |ui| {
+                                        ui.horizontal(|ui| {
+                                            let tag_label = egui::Label::new(
+                                                RichText::new(format!("#{}", total.tag))
+                                                    .size(14.0)
+                                                    .color(Color32::from_rgb(79, 70, 229))
+                                                    .strong(),
+                                            )
+                                            .sense(egui::Sense::click());
+                                            if ui.add(tag_label).on_hover_text("Filter expenses by this tag").clicked() {
+                                                filter_tag = Some(total.tag.clone());
+                                            }
+                                            ui.label(
+                                                RichText::new(format!("{} expenses", total.count))
+                                                    .size(11.0)
+                                                    .color(Color32::from_rgb(156, 163, 175)),
+                                            );
+                                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                                ui.label(
+                                                    RichText::new(format!("${:.2}", total.total))
+                                                        .size(14.0)
+                                                        .color(Color32::from_rgb(17, 24, 39))
+                                                        .strong(),
+                                                );
+                                            });
+                                        });
+
+                                        // Per-profile breakdown
+                                        for (profile_name, amount) in &total.by_profile {
+                                            ui.horizontal(|ui| {
+                                                ui.add_space(12.0);
+                                                ui.label(
+                                                    RichText::new(profile_name)
+                                                        .size(11.0)
+                                                        .color(Color32::from_rgb(107, 114, 128)),
+                                                );
+                                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                                    ui.label(
+                                                        RichText::new(format!("${:.2}", amount))
+                                                            .size(11.0)
+                                                            .color(Color32::from_rgb(107, 114, 128)),
+                                                    );
+                                                });
+                                            });
+                                        }
+                                    });
+                            }
+                        });
+                });
+            });
+
+        if filter_tag.is_some() {
+            self.close();
+        }
+
+        filter_tag
+    }
+}
@@ -1,9 +1,13 @@
-use chrono::NaiveDate;
-use egui::{Color32, ComboBox, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use chrono::{Datelike, NaiveDate};
+use egui::{Color32, ComboBox, Margin, Pos2, Rect, RichText, Rounding, Stroke, TextEdit, Vec2};
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::models::{CategoryColor, Expense, Template};
+use crate::models::{
+    is_valid_variable_name, substitute_variables, CategoryColor, Expense, ExpenseParams, RelativeDate, SplitLine,
+    Template, TemplateVariable, INCOME_VARIABLE,
+};
+use super::amount_input::{evaluate_amount, render_amount_preview};
 
 pub enum TemplateAction {
     Save(String),
@@ -12,6 +16,8 @@ pub enum TemplateAction {
     Delete(Uuid),
     Rename(Uuid, String),
     UpdateExpenses(Uuid, Vec<Expense>),
+    /// Replace the date rules, amount formulas and variables
+    UpdateParams(Uuid, HashMap<Uuid, ExpenseParams>, Vec<TemplateVariable>),
 }
 
 /// Editing state for a single expense in the template editor
@@ -21,32 +27,60 @@ struct EditingExpense {
     category: String,
     description: String,
     date: NaiveDate,
+    /// Where the expense lands in the month the template is applied to
+    date_rule: RelativeDate,
+    /// Amount formula using variables; empty uses the amount as is
+    formula: String,
     active: bool,
+    /// The expense being edited. Fields without an editor here (split lines,
+    /// tags, ...) carry over from it unchanged.
+    original: Option<Expense>,
 }
 
 impl EditingExpense {
-    fn from_expense(expense: &Expense) -> Self {
+    fn from_expense(expense: &Expense, template: &Template) -> Self {
         Self {
             id: expense.id,
             amount: format!("{:.2}", expense.amount),
             category: expense.category.clone(),
             description: expense.description.clone(),
             date: expense.date,
+            date_rule: template.date_rule(expense),
+            formula: template.amount_formula(expense).unwrap_or_default().to_string(),
             active: expense.active,
+            original: Some(expense.clone()),
+        }
+    }
+
+    /// Split lines of the original expense (amount and category aren't editable for these)
+    fn splits(&self) -> &[SplitLine] {
+        self.original.as_ref().map(|e| e.splits.as_slice()).unwrap_or(&[])
+    }
+
+    /// Rules that differ from the defaults (same day of the month, fixed amount)
+    fn params(&self) -> ExpenseParams {
+        let formula = self.formula.trim();
+        ExpenseParams {
+            date: (self.date_rule != RelativeDate::Day(self.date.day())).then_some(self.date_rule),
+            amount: (!formula.is_empty() && self.splits().is_empty()).then(|| formula.to_string()),
         }
     }
 
     fn to_expense(&self) -> Option<Expense> {
-        let amount: f64 = self.amount.parse().ok()?;
+        let amount = evaluate_amount(&self.amount)?;
         if amount <= 0.0 {
             return None;
         }
-        let mut expense = Expense::new(
-            amount,
-            self.category.clone(),
-            self.description.clone(),
-            self.date,
-        );
+        let mut expense = match &self.original {
+            Some(original) => original.clone(),
+            None => Expense::new(amount, self.category.clone(), self.description.clone(), self.date),
+        };
+        if !expense.is_split() {
+            expense.amount = amount;
+            expense.category = self.category.clone();
+        }
+        expense.description = self.description.clone();
+        expense.date = self.date;
         expense.id = self.id; // Keep original ID
         expense.active = self.active;
         Some(expense)
@@ -63,10 +97,14 @@ pub struct TemplateManager {
     editing_template_id: Option<Uuid>,
     editing_template_name: String,
     editing_expenses: Vec<EditingExpense>,
+    /// Template variables as (name, value text)
+    editing_variables: Vec<(String, String)>,
     // New expense being added in edit mode
     new_expense_amount: String,
     new_expense_category: String,
     new_expense_description: String,
+    /// Template cards from the last frame, used to find where a preset was dropped
+    drop_rects: Vec<(Uuid, Rect)>,
 }
 
 impl Default for TemplateManager {
@@ -85,12 +123,19 @@ impl TemplateManager {
             editing_template_id: None,
             editing_template_name: String::new(),
             editing_expenses: Vec::new(),
+            editing_variables: Vec::new(),
             new_expense_amount: String::new(),
             new_expense_category: String::new(),
             new_expense_description: String::new(),
+            drop_rects: Vec::new(),
         }
     }
 
+    /// Template under a screen position, if the template list is showing
+    pub fn template_at(&self, pos: Pos2) -> Option<Uuid> {
+        self.drop_rects.iter().find(|(_, rect)| rect.contains(pos)).map(|(id, _)| *id)
+    }
+
     pub fn open(&mut self) {
         self.is_open = true;
         self.new_template_name.clear();
@@ -111,6 +156,7 @@ impl TemplateManager {
         self.editing_template_id = None;
         self.editing_template_name.clear();
         self.editing_expenses.clear();
+        self.editing_variables.clear();
         self.new_expense_amount.clear();
         self.new_expense_category.clear();
         self.new_expense_description.clear();
@@ -119,7 +165,16 @@ impl TemplateManager {
     fn enter_edit_mode(&mut self, template: &Template) {
         self.editing_template_id = Some(template.id);
         self.editing_template_name = template.name.clone();
-        self.editing_expenses = template.expenses.iter().map(EditingExpense::from_expense).collect();
+        self.editing_expenses = template
+            .expenses
+            .iter()
+            .map(|e| EditingExpense::from_expense(e, template))
+            .collect();
+        self.editing_variables = template
+            .variables
+            .iter()
+            .map(|v| (v.name.clone(), format!("{:.2}", v.value)))
+            .collect();
         self.new_expense_amount.clear();
         self.new_expense_category.clear();
         self.new_expense_description.clear();
@@ -132,8 +187,10 @@ impl TemplateManager {
         current_expense_count: usize,
         categories: &[String],
         category_colors: &HashMap<String, CategoryColor>,
+        dragging_preset: bool,
     ) -> Vec<TemplateAction> {
         let mut actions: Vec<TemplateAction> = Vec::new();
+        self.drop_rects.clear();
 
         if !self.is_open {
             return actions;
@@ -278,10 +335,11 @@ impl TemplateManager {
                                     ui.add_space(40.0);
                                 });
                             } else {
+                                let pointer = ui.ctx().pointer_latest_pos();
                                 for template in templates {
                                     let is_renaming = self.renaming_template_id == Some(template.id);
 
-                                    egui::Frame::none()
+                                    let card = egui::Frame::none()
                                         .fill(Color32::from_rgb(249, 250, 251))
                                         .rounding(Rounding::same(12.0))
                                         .stroke(Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
@@ -430,6 +488,19 @@ impl TemplateManager {
                                                 }
                                             });
                                         });
+
+                                    // Dropping a dragged preset here adds it to the template
+                                    if dragging_preset {
+                                        let rect = card.response.rect.intersect(ui.clip_rect());
+                                        if pointer.is_some_and(|p| rect.contains(p)) {
+                                            ui.painter().rect_stroke(
+                                                rect,
+                                                Rounding::same(12.0),
+                                                Stroke::new(2.0, Color32::from_rgb(99, 102, 241)),
+                                            );
+                                        }
+                                        self.drop_rects.push((template.id, rect));
+                                    }
                                 }
                             }
                         });
@@ -456,7 +527,7 @@ impl TemplateManager {
             .collapsible(false)
             .resizable(false)
             .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-            .fixed_size([500.0, 600.0])
+            .fixed_size([540.0, 680.0])
             .frame(
                 egui::Frame::none()
                     .fill(Color32::WHITE)
@@ -507,11 +578,13 @@ impl TemplateManager {
                     ui.add_space(4.0);
                     ui.add(
                         TextEdit::singleline(&mut self.editing_template_name)
-                            .desired_width(450.0)
+                            .desired_width(490.0)
                             .hint_text("Template name"),
                     );
 
-                    ui.add_space(20.0);
+                    ui.add_space(16.0);
+                    self.render_variables(ui);
+                    ui.add_space(16.0);
 
                     // Add new expense section
                     ui.label(
@@ -534,6 +607,7 @@ impl TemplateManager {
                                         .desired_width(70.0)
                                         .hint_text("0.00"),
                                 );
+                                render_amount_preview(ui, &self.new_expense_amount);
 
                                 ui.add_space(8.0);
 
@@ -572,7 +646,7 @@ impl TemplateManager {
 
                                 // Add button
                                 let can_add = !self.new_expense_amount.is_empty()
-                                    && self.new_expense_amount.parse::<f64>().map(|v| v > 0.0).unwrap_or(false)
+                                    && evaluate_amount(&self.new_expense_amount).is_some_and(|v| v > 0.0)
                                     && !self.new_expense_category.is_empty();
 
                                 let add_btn = egui::Button::new(
@@ -586,14 +660,18 @@ impl TemplateManager {
                                 .min_size(Vec2::new(32.0, 28.0));
 
                                 if ui.add(add_btn).clicked() && can_add {
-                                    if let Ok(amount) = self.new_expense_amount.parse::<f64>() {
+                                    if let Some(amount) = evaluate_amount(&self.new_expense_amount) {
+                                        let today = chrono::Local::now().date_naive();
                                         let new_expense = EditingExpense {
                                             id: Uuid::new_v4(),
                                             amount: format!("{:.2}", amount),
                                             category: self.new_expense_category.clone(),
                                             description: self.new_expense_description.clone(),
-                                            date: chrono::Local::now().date_naive(),
+                                            date: today,
+                                            date_rule: RelativeDate::Day(today.day()),
+                                            formula: String::new(),
                                             active: true,
+                                            original: None,
                                         };
                                         self.editing_expenses.push(new_expense);
                                         self.new_expense_amount.clear();
@@ -613,8 +691,16 @@ impl TemplateManager {
                     );
                     ui.add_space(8.0);
 
+                    // Names formulas can use, to flag typos
+                    let known_variables: HashMap<String, f64> = self
+                        .editing_variables
+                        .iter()
+                        .map(|(name, _)| (name.trim().to_string(), 1.0))
+                        .chain([(INCOME_VARIABLE.to_string(), 1.0)])
+                        .collect();
+
                     egui::ScrollArea::vertical()
-                        .max_height(300.0)
+                        .max_height(260.0)
                         .show(ui, |ui| {
                             ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
 
@@ -654,22 +740,37 @@ impl TemplateManager {
                                                 // Active checkbox
                                                 ui.checkbox(&mut expense.active, "");
 
-                                                // Amount field
-                                                ui.label(RichText::new("$").size(12.0).color(Color32::from_rgb(107, 114, 128)));
-                                                ui.add(
-                                                    TextEdit::singleline(&mut expense.amount)
-                                                        .desired_width(60.0),
-                                                );
-
-                                                // Category dropdown
-                                                ComboBox::from_id_salt(format!("edit_expense_cat_{}", idx))
-                                                    .width(100.0)
-                                                    .selected_text(&expense.category)
-                                                    .show_ui(ui, |ui| {
-                                                        for cat in categories {
-                                                            ui.selectable_value(&mut expense.category, cat.clone(), cat);
-                                                        }
-                                                    });
+                                                if expense.splits().is_empty() {
+                                                    // Amount field
+                                                    ui.label(RichText::new("$").size(12.0).color(Color32::from_rgb(107, 114, 128)));
+                                                    ui.add(
+                                                        TextEdit::singleline(&mut expense.amount)
+                                                            .desired_width(60.0),
+                                                    );
+                                                    render_amount_preview(ui, &expense.amount);
+
+                                                    // Category dropdown
+                                                    ComboBox::from_id_salt(format!("edit_expense_cat_{}", idx))
+                                                        .width(100.0)
+                                                        .selected_text(&expense.category)
+                                                        .show_ui(ui, |ui| {
+                                                            for cat in categories {
+                                                                ui.selectable_value(&mut expense.category, cat.clone(), cat);
+                                                            }
+                                                        });
+                                                } else {
+                                                    // Split expenses keep their lines; only note and active state are editable here
+                                                    let lines: Vec<String> = expense.splits()
+                                                        .iter()
+                                                        .map(|l| format!("{}: ${:.2}", l.category, l.amount))
+                                                        .collect();
+                                                    ui.label(
+                                                        RichText::new(format!("${} · Split ({})", expense.amount, expense.splits().len()))
+                                                            .size(12.0)
+                                                            .color(Color32::from_rgb(55, 65, 81)),
+                                                    )
+                                                    .on_hover_text(lines.join("\n"));
+                                                }
 
                                                 // Description
                                                 ui.add(
@@ -698,6 +799,29 @@ impl TemplateManager {
                                                     },
                                                 );
                                             });
+
+                                            // When it lands and how the amount is worked out
+                                            ui.horizontal(|ui| {
+                                                ui.add_space(24.0);
+                                                render_date_rule(ui, idx, &mut expense.date_rule);
+
+                                                if expense.splits().is_empty() {
+                                                    ui.add_space(8.0);
+                                                    ui.add(
+                                                        TextEdit::singleline(&mut expense.formula)
+                                                            .desired_width(150.0)
+                                                            .hint_text("Formula, e.g. rent"),
+                                                    )
+                                                    .on_hover_text("Worked out when the template is applied, e.g. \"rent\" or \"income × 10%\"");
+                                                    if let Err(name) = substitute_variables(&expense.formula, &known_variables) {
+                                                        ui.label(
+                                                            RichText::new(format!("No variable \"{}\"", name))
+                                                                .size(11.0)
+                                                                .color(Color32::from_rgb(220, 38, 38)),
+                                                        );
+                                                    }
+                                                }
+                                            });
                                         });
                                 }
                             }
@@ -714,7 +838,7 @@ impl TemplateManager {
                     let total: f64 = self.editing_expenses
                         .iter()
                         .filter(|e| e.active)
-                        .filter_map(|e| e.amount.parse::<f64>().ok())
+                        .filter_map(|e| evaluate_amount(&e.amount))
                         .sum();
 
                     ui.horizontal(|ui| {
@@ -786,9 +910,99 @@ impl TemplateManager {
                 .collect();
 
             actions.push(TemplateAction::UpdateExpenses(template_id, expenses));
+
+            let params = self.editing_expenses.iter().map(|e| (e.id, e.params())).collect();
+            actions.push(TemplateAction::UpdateParams(template_id, params, self.variables()));
             self.clear_edit_mode();
         }
 
         actions
     }
 }
+
+impl TemplateManager {
+    /// Named amounts the template asks for when applied
+    fn render_variables(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new("Variables")
+                    .size(12.0)
+                    .color(Color32::from_rgb(100, 100, 110)),
+            );
+            ui.label(
+                RichText::new(format!("Use in amount formulas, along with {}", INCOME_VARIABLE))
+                    .size(11.0)
+                    .color(Color32::from_rgb(156, 163, 175)),
+            );
+        });
+        ui.add_space(4.0);
+
+        let mut to_remove: Option<usize> = None;
+        for (idx, (name, value)) in self.editing_variables.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                ui.add(TextEdit::singleline(name).desired_width(120.0).hint_text("Name, e.g. rent"));
+                ui.label(RichText::new("$").size(12.0).color(Color32::from_rgb(107, 114, 128)));
+                ui.add(TextEdit::singleline(value).desired_width(80.0).hint_text("0.00"));
+                render_amount_preview(ui, value);
+                let name = name.trim();
+                if !name.is_empty() && (!is_valid_variable_name(name) || name.eq_ignore_ascii_case(INCOME_VARIABLE)) {
+                    ui.label(
+                        RichText::new("Use letters, digits and _")
+                            .size(11.0)
+                            .color(Color32::from_rgb(220, 38, 38)),
+                    );
+                }
+                if ui.small_button("X").clicked() {
+                    to_remove = Some(idx);
+                }
+            });
+        }
+        if let Some(idx) = to_remove {
+            self.editing_variables.remove(idx);
+        }
+
+        if ui.small_button("+ Variable").clicked() {
+            self.editing_variables.push((String::new(), String::new()));
+        }
+    }
+
+    /// Variables with a usable name and amount, first one wins for repeated names
+    fn variables(&self) -> Vec<TemplateVariable> {
+        let mut variables: Vec<TemplateVariable> = Vec::new();
+        for (name, value) in &self.editing_variables {
+            let name = name.trim();
+            let taken = name.eq_ignore_ascii_case(INCOME_VARIABLE)
+                || variables.iter().any(|v| v.name.eq_ignore_ascii_case(name));
+            if let Some(value) = evaluate_amount(value)
+                && is_valid_variable_name(name)
+                && !taken
+            {
+                variables.push(TemplateVariable { name: name.to_string(), value });
+            }
+        }
+        variables
+    }
+}
+
+/// Picker for where an expense lands in the target month
+fn render_date_rule(ui: &mut egui::Ui, idx: usize, rule: &mut RelativeDate) {
+    let day = match rule {
+        RelativeDate::Day(day) => *day,
+        _ => 1,
+    };
+    ComboBox::from_id_salt(format!("edit_expense_date_{}", idx))
+        .width(130.0)
+        .selected_text(match *rule {
+            RelativeDate::Day(_) => "Day of month".to_string(),
+            other => other.label(),
+        })
+        .show_ui(ui, |ui| {
+            ui.selectable_value(rule, RelativeDate::Day(day), "Day of month");
+            for choice in [RelativeDate::LastDay, RelativeDate::FirstBusinessDay, RelativeDate::LastBusinessDay] {
+                ui.selectable_value(rule, choice, choice.label());
+            }
+        });
+    if let RelativeDate::Day(day) = rule {
+        ui.add(egui::DragValue::new(day).range(1..=31));
+    }
+}
@@ -0,0 +1,347 @@
+use chrono::{Datelike, Local, Months, NaiveDate};
+use egui::{Color32, Margin, RichText, Rounding, Stroke, TextEdit, Vec2};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use super::amount_input::{evaluate_amount, render_amount_preview};
+use crate::models::{substitute_variables, Expense, RelativeDate, Template, INCOME_VARIABLE};
+
+/// Actions that can be returned from the template preview
+pub enum TemplatePreviewAction {
+    /// Add the filled-in expenses to the current ones
+    Append(Vec<Expense>),
+    /// Replace the current expenses with the filled-in ones
+    Replace(Vec<Expense>),
+}
+
+/// A template expense as it would be added, or why its amount can't be worked out
+struct PreviewRow {
+    /// Id of the expense in the template
+    source_id: Uuid,
+    rule: RelativeDate,
+    expense: Expense,
+    formula: Option<String>,
+    amount: Result<f64, String>,
+}
+
+/// Dialog shown before a template is applied: pick the month, fill in the
+/// variables and check the dates and amounts it works out to
+pub struct TemplatePreview {
+    pub is_open: bool,
+    template: Option<Template>,
+    replace: bool,
+    /// First day of the target month
+    month: NaiveDate,
+    income: f64,
+    /// Variable inputs as (name, amount text), starting from the template's values
+    values: Vec<(String, String)>,
+    /// Template expenses left out this time
+    excluded: HashSet<Uuid>,
+}
+
+impl Default for TemplatePreview {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TemplatePreview {
+    pub fn new() -> Self {
+        Self {
+            is_open: false,
+            template: None,
+            replace: false,
+            month: Local::now().date_naive(),
+            income: 0.0,
+            values: Vec::new(),
+            excluded: HashSet::new(),
+        }
+    }
+
+    /// Preview `template` for the current month. `replace` loads it in place of the
+    /// current expenses; `income` is the value of the built-in income variable.
+    pub fn open(&mut self, template: Template, replace: bool, income: f64) {
+        let today = Local::now().date_naive();
+        self.is_open = true;
+        self.replace = replace;
+        self.month = today.with_day(1).unwrap_or(today);
+        self.income = income;
+        self.values = template
+            .variables
+            .iter()
+            .map(|v| (v.name.clone(), format!("{:.2}", v.value)))
+            .collect();
+        self.excluded.clear();
+        self.template = Some(template);
+    }
+
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.template = None;
+        self.values.clear();
+        self.excluded.clear();
+    }
+
+    fn shift_month(&mut self, forward: bool) {
+        let shifted = if forward {
+            self.month.checked_add_months(Months::new(1))
+        } else {
+            self.month.checked_sub_months(Months::new(1))
+        };
+        if let Some(month) = shifted {
+            self.month = month;
+        }
+    }
+
+    /// Every template expense with its date in the target month and its amount worked out
+    fn rows(&self) -> Vec<PreviewRow> {
+        let Some(template) = &self.template else {
+            return Vec::new();
+        };
+
+        let mut values: HashMap<String, f64> = HashMap::new();
+        values.insert(INCOME_VARIABLE.to_string(), self.income);
+        for (name, text) in &self.values {
+            if let Some(value) = evaluate_amount(text) {
+                values.insert(name.clone(), value);
+            }
+        }
+
+        template
+            .expenses
+            .iter()
+            .map(|original| {
+                let rule = template.date_rule(original);
+                let mut expense = original.copy_as_new();
+                expense.date = rule.resolve(self.month);
+                // Split lines keep their own amounts
+                let formula = template.amount_formula(original).filter(|_| !original.is_split());
+                let amount = match formula {
+                    None => Ok(expense.amount),
+                    Some(formula) => match substitute_variables(formula, &values) {
+                        Err(name) if self.values.iter().any(|(n, _)| n.eq_ignore_ascii_case(&name)) => {
+                            Err(format!("Enter an amount for {}", name))
+                        }
+                        Err(name) => Err(format!("Unknown variable \"{}\"", name)),
+                        Ok(expression) => match evaluate_amount(&expression) {
+                            Some(amount) if amount > 0.0 => Ok(amount),
+                            Some(_) => Err("Works out to $0.00 or less".to_string()),
+                            None => Err("Can't work out this formula".to_string()),
+                        },
+                    },
+                };
+                if let Ok(amount) = amount {
+                    expense.amount = amount;
+                }
+                PreviewRow {
+                    source_id: original.id,
+                    rule,
+                    expense,
+                    formula: formula.map(str::to_string),
+                    amount,
+                }
+            })
+            .collect()
+    }
+
+    pub fn render(&mut self, ctx: &egui::Context) -> Option<TemplatePreviewAction> {
+        let mut action = None;
+
+        if !self.is_open {
+            return action;
+        }
+        let Some(name) = self.template.as_ref().map(|t| t.name.clone()) else {
+            return action;
+        };
+
+        let muted = Color32::from_rgb(107, 114, 128);
+        let dark = Color32::from_rgb(17, 24, 39);
+        let error = Color32::from_rgb(220, 38, 38);
+
+        egui::Window::new("Apply Template")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .fixed_size([480.0, 0.0])
+            .frame(
+                egui::Frame::none()
+                    .fill(Color32::WHITE)
+                    .rounding(Rounding::same(16.0))
+                    .stroke(Stroke::new(1.0, Color32::from_rgb(220, 220, 230)))
+                    .inner_margin(Margin::same(24.0))
+                    .shadow(egui::epaint::Shadow {
+                        spread: 8.0,
+                        blur: 20.0,
+                        color: Color32::from_black_alpha(25),
+                        offset: [0.0, 4.0].into(),
+                    }),
+            )
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    // Header
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(format!("Apply \"{}\"", name))
+                                .size(18.0)
+                                .color(Color32::from_rgb(30, 30, 40))
+                                .strong(),
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let close_btn = egui::Button::new(
+                                RichText::new("X")
+                                    .size(16.0)
+                                    .color(Color32::from_rgb(120, 120, 130)),
+                            )
+                            .fill(Color32::TRANSPARENT)
+                            .stroke(Stroke::NONE);
+
+                            if ui.add(close_btn).clicked() {
+                                self.close();
+                            }
+                        });
+                    });
+
+                    if self.replace {
+                        ui.label(
+                            RichText::new("These expenses will replace your current ones.")
+                                .size(12.0)
+                                .color(muted),
+                        );
+                    }
+                    ui.add_space(12.0);
+
+                    // Target month
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("Month").size(12.0).color(muted));
+                        if ui.small_button("<").clicked() {
+                            self.shift_month(false);
+                        }
+                        ui.label(RichText::new(self.month.format("%B %Y").to_string()).size(13.0).color(dark).strong());
+                        if ui.small_button(">").clicked() {
+                            self.shift_month(true);
+                        }
+                    });
+                    ui.add_space(8.0);
+
+                    // Variables
+                    egui::Grid::new("template_preview_variables")
+                        .num_columns(2)
+                        .spacing(Vec2::new(12.0, 6.0))
+                        .show(ui, |ui| {
+                            ui.label(RichText::new(INCOME_VARIABLE).size(12.0).color(muted));
+                            ui.label(RichText::new(format!("${:.2}", self.income)).size(12.0).color(dark))
+                                .on_hover_text("This profile's income");
+                            ui.end_row();
+
+                            for (name, text) in &mut self.values {
+                                ui.label(RichText::new(name.as_str()).size(12.0).color(muted));
+                                ui.horizontal(|ui| {
+                                    ui.add(TextEdit::singleline(text).desired_width(90.0).hint_text("0.00"));
+                                    render_amount_preview(ui, text);
+                                });
+                                ui.end_row();
+                            }
+                        });
+                    ui.add_space(12.0);
+
+                    let rows = self.rows();
+                    egui::ScrollArea::vertical().max_height(280.0).show(ui, |ui| {
+                        ui.spacing_mut().item_spacing = Vec2::new(8.0, 6.0);
+                        for row in &rows {
+                            let mut included = !self.excluded.contains(&row.source_id);
+                            ui.horizontal(|ui| {
+                                if ui.checkbox(&mut included, "").changed() {
+                                    if included {
+                                        self.excluded.remove(&row.source_id);
+                                    } else {
+                                        self.excluded.insert(row.source_id);
+                                    }
+                                }
+                                ui.label(
+                                    RichText::new(row.expense.date.format("%a %b %d").to_string())
+                                        .size(12.0)
+                                        .color(muted),
+                                )
+                                .on_hover_text(row.rule.label());
+                                ui.vertical(|ui| {
+                                    let title = if row.expense.description.is_empty() {
+                                        &row.expense.category
+                                    } else {
+                                        &row.expense.description
+                                    };
+                                    ui.label(RichText::new(title).size(13.0).color(dark));
+                                    if let Some(formula) = &row.formula {
+                                        ui.label(RichText::new(format!("= {}", formula)).size(11.0).color(muted));
+                                    }
+                                });
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    match &row.amount {
+                                        Ok(amount) => ui.label(
+                                            RichText::new(format!("${:.2}", amount))
+                                                .size(13.0)
+                                                .color(if included { dark } else { muted })
+                                                .strong(),
+                                        ),
+                                        Err(message) => ui.label(RichText::new(message).size(11.0).color(error)),
+                                    };
+                                });
+                            });
+                        }
+                    });
+
+                    let included: Vec<&PreviewRow> =
+                        rows.iter().filter(|r| !self.excluded.contains(&r.source_id)).collect();
+                    let valid = !included.is_empty() && included.iter().all(|r| r.amount.is_ok());
+                    let total: f64 = included.iter().filter_map(|r| r.amount.as_ref().ok()).sum();
+
+                    ui.add_space(12.0);
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("Total:").size(14.0).color(muted));
+                        ui.label(RichText::new(format!("${:.2}", total)).size(14.0).color(dark).strong());
+                    });
+                    ui.add_space(12.0);
+
+                    ui.horizontal(|ui| {
+                        let cancel_btn = egui::Button::new(
+                            RichText::new("Cancel")
+                                .size(12.0)
+                                .color(Color32::from_rgb(99, 102, 241)),
+                        )
+                        .fill(Color32::from_rgb(238, 242, 255))
+                        .stroke(Stroke::NONE)
+                        .rounding(Rounding::same(6.0))
+                        .min_size(Vec2::new(70.0, 28.0));
+                        if ui.add(cancel_btn).clicked() {
+                            self.close();
+                        }
+
+                        let label = match (self.replace, included.len()) {
+                            (true, _) => "Replace expenses".to_string(),
+                            (false, 1) => "Add 1 expense".to_string(),
+                            (false, count) => format!("Add {} expenses", count),
+                        };
+                        let apply_btn = egui::Button::new(
+                            RichText::new(label)
+                                .size(12.0)
+                                .color(if valid { Color32::WHITE } else { Color32::from_rgb(180, 180, 180) }),
+                        )
+                        .fill(if valid { Color32::from_rgb(99, 102, 241) } else { Color32::from_rgb(220, 220, 225) })
+                        .stroke(Stroke::NONE)
+                        .rounding(Rounding::same(6.0))
+                        .min_size(Vec2::new(120.0, 28.0));
+                        if ui.add_enabled(valid, apply_btn).clicked() {
+                            let expenses: Vec<Expense> = included.iter().map(|r| r.expense.clone()).collect();
+                            action = Some(if self.replace {
+                                TemplatePreviewAction::Replace(expenses)
+                            } else {
+                                TemplatePreviewAction::Append(expenses)
+                            });
+                        }
+                    });
+                });
+            });
+
+        action
+    }
+}